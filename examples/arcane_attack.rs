@@ -6,7 +6,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin::default())
-        .add_plugins(BevyProcAudPlugin)
+        .add_plugins(BevyProcAudPlugin::default())
         .insert_resource(Settings {
             intensity: 0.8,
             pitch: 1.0,
@@ -34,13 +34,20 @@ fn ui_system(
     mut commands: Commands,
     mut contexts: EguiContexts,
     mut settings: ResMut<Settings>,
+    mut playing: Query<&mut ArcaneAttack>,
 ) -> Result {
     let ctx = contexts.ctx_mut()?;
     egui::Window::new("Arcane Attack").show(ctx, |ui| {
         ui.add(egui::Slider::new(&mut settings.intensity, 0.0..=1.0).text("Intensity"));
         ui.add(egui::Slider::new(&mut settings.pitch, 0.3..=3.0).logarithmic(true).text("Pitch"));
         ui.add(egui::Slider::new(&mut settings.reverb, 0.0..=1.0).text("Reverb"));
-        ui.add(egui::Slider::new(&mut settings.lowpass, 200.0..=20_000.0).logarithmic(true).text("Lowpass"));
+        let lowpass = ui.add(egui::Slider::new(&mut settings.lowpass, 200.0..=20_000.0).logarithmic(true).text("Lowpass"));
+        // The lowpass also sweeps sounds that are already playing.
+        if lowpass.changed() {
+            for mut attack in &mut playing {
+                attack.lowpass = settings.lowpass;
+            }
+        }
         ui.separator();
         if ui.button("Standard").clicked() {
             commands.spawn(ArcaneAttack {
@@ -48,6 +55,7 @@ fn ui_system(
                 pitch_shift: settings.pitch,
                 reverb_mix: settings.reverb,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
         if ui.button("Deep (0.5x)").clicked() {
@@ -56,6 +64,7 @@ fn ui_system(
                 pitch_shift: 0.5,
                 reverb_mix: settings.reverb,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
         if ui.button("High (2x)").clicked() {
@@ -64,6 +73,7 @@ fn ui_system(
                 pitch_shift: 2.0,
                 reverb_mix: settings.reverb,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
         if ui.button("Dry (no reverb)").clicked() {
@@ -72,6 +82,7 @@ fn ui_system(
                 pitch_shift: settings.pitch,
                 reverb_mix: 0.0,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
     });
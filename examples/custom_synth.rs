@@ -6,7 +6,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin::default())
-        .add_plugins(BevyProcAudPlugin)
+        .add_plugins(BevyProcAudPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(EguiPrimaryContextPass, ui_system)
         .run();
@@ -14,22 +14,17 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
-    commands.spawn((
-        Synth,
-        OscillatorType::Saw,
-        Frequency(440.0),
-        Amplitude(0.3),
-        LowPass {
-            cutoff_hz: 2000.0,
-            resonance: 2.0,
-        },
-        Reverb {
+    SynthBundle::saw(440.0)
+        .amplitude(0.3)
+        .low_pass(2000.0, 2.0)
+        .reverb(Reverb {
             room_size: 0.5,
             decay_time: 1.5,
             damping: 0.3,
             mix: 0.3,
-        },
-    ));
+            ..default()
+        })
+        .spawn(&mut commands);
 }
 
 fn ui_system(
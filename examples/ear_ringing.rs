@@ -6,7 +6,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin::default())
-        .add_plugins(BevyProcAudPlugin)
+        .add_plugins(BevyProcAudPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(EguiPrimaryContextPass, ui_system)
         .run();
@@ -14,7 +14,10 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
-    commands.spawn(EarRinging { intensity: 0.3 });
+    commands.spawn(EarRinging {
+        intensity: 0.3,
+        reverb_mix: 0.0,
+    });
 }
 
 fn ui_system(mut contexts: EguiContexts, mut query: Query<&mut EarRinging>) -> Result {
@@ -22,6 +25,7 @@ fn ui_system(mut contexts: EguiContexts, mut query: Query<&mut EarRinging>) -> R
     egui::Window::new("Ear Ringing Controls").show(ctx, |ui| {
         for mut er in &mut query {
             ui.add(egui::Slider::new(&mut er.intensity, 0.0..=1.0).text("Intensity"));
+            ui.add(egui::Slider::new(&mut er.reverb_mix, 0.0..=1.0).text("Reverb Mix"));
         }
     });
     Ok(())
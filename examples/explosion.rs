@@ -6,7 +6,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin::default())
-        .add_plugins(BevyProcAudPlugin)
+        .add_plugins(BevyProcAudPlugin::default())
         .insert_resource(Settings {
             intensity: 0.8,
             pitch: 1.0,
@@ -48,6 +48,7 @@ fn ui_system(
                 pitch_shift: 0.5,
                 reverb_mix: settings.reverb,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
         if ui.button("Standard Explosion").clicked() {
@@ -56,6 +57,7 @@ fn ui_system(
                 pitch_shift: settings.pitch,
                 reverb_mix: settings.reverb,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
         if ui.button("Fireball (2x)").clicked() {
@@ -64,6 +66,7 @@ fn ui_system(
                 pitch_shift: 2.0,
                 reverb_mix: settings.reverb,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
         if ui.button("Small Fireball (3x)").clicked() {
@@ -72,6 +75,7 @@ fn ui_system(
                 pitch_shift: 3.0,
                 reverb_mix: settings.reverb,
                 lowpass: settings.lowpass,
+                ..default()
             });
         }
     });
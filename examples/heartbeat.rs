@@ -6,7 +6,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin::default())
-        .add_plugins(BevyProcAudPlugin)
+        .add_plugins(BevyProcAudPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(EguiPrimaryContextPass, ui_system)
         .run();
@@ -18,6 +18,9 @@ fn setup(mut commands: Commands) {
         heart_rate: 72.0,
         arrhythmic_strength: 0.0,
         intensity: 0.5,
+        breath_rate: 14.0,
+        state: HeartbeatState::Normal,
+        reverb_mix: 0.0,
     });
 }
 
@@ -31,6 +34,13 @@ fn ui_system(mut contexts: EguiContexts, mut query: Query<&mut Heartbeat>) -> Re
                     .text("Arrhythmia"),
             );
             ui.add(egui::Slider::new(&mut hb.intensity, 0.0..=1.0).text("Intensity"));
+            ui.add(egui::Slider::new(&mut hb.breath_rate, 0.0..=40.0).text("Breath Rate (BPM)"));
+            ui.add(egui::Slider::new(&mut hb.reverb_mix, 0.0..=1.0).text("Reverb Mix"));
+            ui.horizontal(|ui| {
+                for state in HeartbeatState::ALL {
+                    ui.selectable_value(&mut hb.state, state, format!("{state:?}"));
+                }
+            });
         }
     });
     Ok(())
@@ -6,7 +6,7 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(EguiPlugin::default())
-        .add_plugins(BevyProcAudPlugin)
+        .add_plugins(BevyProcAudPlugin::default())
         .insert_resource(Settings { intensity: 0.8 })
         .add_systems(Startup, setup)
         .add_systems(EguiPrimaryContextPass, ui_system)
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Order an [`Arpeggiator`] walks its notes in.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArpMode {
+    #[default]
+    Up,
+    Down,
+    /// Up then back down, without repeating the top and bottom notes.
+    UpDown,
+    /// A random note each step.
+    Random,
+}
+
+impl ArpMode {
+    /// Index into `len` notes for step number `step` (counting from 0).
+    /// `Random` draws from `rng`.
+    pub fn index(self, step: usize, len: usize, rng: &mut impl Rng) -> usize {
+        if len <= 1 {
+            return 0;
+        }
+        match self {
+            Self::Up => step % len,
+            Self::Down => len - 1 - step % len,
+            Self::UpDown => {
+                let period = 2 * len - 2;
+                let position = step % period;
+                if position < len {
+                    position
+                } else {
+                    period - position
+                }
+            }
+            Self::Random => rng.random_range(0..len),
+        }
+    }
+}
+
+/// Plays `notes` (Hz) one after another on a `Synth`, `rate_hz` steps per
+/// second, in the order set by `mode`. Read every frame, so all fields can be
+/// changed live.
+///
+/// On a [`Polyphony`](crate::components::polyphony::Polyphony) synth each
+/// step releases the previous note and starts the next through `NoteEvents`,
+/// retriggering the voice envelope; otherwise it retunes the synth's
+/// frequency.
+#[derive(Component, Debug, Clone)]
+#[require(ArpClock)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arpeggiator {
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_util::finite_list")
+    )]
+    pub notes: Vec<f32>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde_util::finite")
+    )]
+    pub rate_hz: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mode: ArpMode,
+}
+
+impl Default for Arpeggiator {
+    fn default() -> Self {
+        Self {
+            notes: Vec::new(),
+            rate_hz: 8.0,
+            mode: ArpMode::Up,
+        }
+    }
+}
+
+/// Playback position of an [`Arpeggiator`]. Added automatically.
+#[derive(Component, Default, Debug, Clone)]
+pub struct ArpClock {
+    /// Seconds since the last step.
+    pub(crate) elapsed: f32,
+    /// Steps taken so far.
+    pub(crate) step: usize,
+    /// Note sounding since the last step.
+    pub(crate) playing: Option<f32>,
+}
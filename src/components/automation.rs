@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+
+/// What an [`Automation`] does after its last keyframe.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LoopMode {
+    /// Hold the last keyframe's value.
+    #[default]
+    Once,
+    /// Jump back to the start and play again.
+    Loop,
+    /// Play backwards to the start, then forwards again.
+    PingPong,
+}
+
+/// How an [`Automation`] moves between keyframes.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Straight line between keyframes.
+    #[default]
+    Linear,
+    /// Smoothstep: slow out of each keyframe and slow into the next.
+    Ease,
+}
+
+/// Drives a synth parameter along keyframes over time.
+///
+/// Each frame the keyframes are sampled at the time since the component was
+/// added and the result is written to the `SynthParams` handle named
+/// `target` (see [`NamedParam`](crate::components::synth::NamedParam) for
+/// the names). Keyframes are `(seconds, value)` pairs sorted by time.
+///
+/// ```ignore
+/// // Filter sweep from 200 Hz to 4 kHz and back, every 4 seconds.
+/// SynthBundle::saw(110.0)
+///     .low_pass(200.0, 2.0)
+///     .spawn(&mut commands)
+///     .insert(Automation {
+///         target: "filter_cutoff".into(),
+///         keyframes: vec![(0.0, 200.0), (2.0, 4000.0)],
+///         loop_mode: LoopMode::PingPong,
+///         interpolation: Interpolation::Ease,
+///     });
+/// ```
+#[derive(Component, Debug, Clone, Default)]
+#[require(AutomationClock)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Automation {
+    pub target: String,
+    pub keyframes: Vec<(f32, f32)>,
+    pub loop_mode: LoopMode,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub interpolation: Interpolation,
+}
+
+impl Automation {
+    /// Value at `t` seconds, or `None` without keyframes.
+    pub fn sample(&self, t: f32) -> Option<f32> {
+        let (first, last) = (self.keyframes.first()?, self.keyframes.last()?);
+        let start = first.0;
+        let length = last.0 - start;
+
+        let t = if length <= 0.0 {
+            start
+        } else {
+            let local = (t - start).max(0.0);
+            start
+                + match self.loop_mode {
+                    LoopMode::Once => local.min(length),
+                    LoopMode::Loop => local % length,
+                    LoopMode::PingPong => {
+                        let cycle = local % (2.0 * length);
+                        if cycle > length {
+                            2.0 * length - cycle
+                        } else {
+                            cycle
+                        }
+                    }
+                }
+        };
+
+        let next = self.keyframes.partition_point(|(time, _)| *time <= t);
+        if next == 0 {
+            return Some(first.1);
+        }
+        if next == self.keyframes.len() {
+            return Some(last.1);
+        }
+        let (t0, v0) = self.keyframes[next - 1];
+        let (t1, v1) = self.keyframes[next];
+        let x = (t - t0) / (t1 - t0);
+        let x = match self.interpolation {
+            Interpolation::Linear => x,
+            Interpolation::Ease => x * x * (3.0 - 2.0 * x),
+        };
+        Some(v0 + (v1 - v0) * x)
+    }
+}
+
+/// Time since an [`Automation`] started, advanced by `automation_system`.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct AutomationClock {
+    pub elapsed: f32,
+}
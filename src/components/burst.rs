@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::components::sequencer::PresetTrigger;
+
+/// Plays `count` copies of a one-shot preset, staggered at random over
+/// `spread_secs`, for crowd and debris effects: a `Burst` of `GlassBreak`
+/// sounds like a shatter rather than one pane. The first copy plays at once.
+///
+/// Each copy is a separate preset entity with a random `PlaybackRate` of up
+/// to ±`pitch_jitter` (0.1 = ±10%, clamped to 0.0–0.5), so pitch and length
+/// vary together. Copies share the preset's values, seed included. The
+/// `Burst` entity is despawned once the last copy has been spawned.
+///
+/// ```ignore
+/// commands.spawn(Burst {
+///     preset: PresetTrigger::GlassBreak(GlassBreak::default()),
+///     count: 6,
+///     ..default()
+/// });
+/// ```
+#[derive(Component, Debug, Clone)]
+#[require(BurstClock)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Burst {
+    pub preset: PresetTrigger,
+    pub count: u8,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub spread_secs: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch_jitter: f32,
+}
+
+impl Default for Burst {
+    fn default() -> Self {
+        Self {
+            preset: PresetTrigger::GlassBreak(default()),
+            count: 4,
+            spread_secs: 0.15,
+            pitch_jitter: 0.1,
+        }
+    }
+}
+
+impl Burst {
+    /// Spawn times for the copies drawn from `rng`, in seconds from the
+    /// start: sorted, the first at 0.0 and the rest within `spread_secs`.
+    pub fn schedule(&self, rng: &mut impl Rng) -> Vec<f32> {
+        let spread = self.spread_secs.max(0.0);
+        let mut times: Vec<f32> = (0..self.count)
+            .map(|i| {
+                if i == 0 {
+                    0.0
+                } else {
+                    rng.random::<f32>() * spread
+                }
+            })
+            .collect();
+        times.sort_by(f32::total_cmp);
+        times
+    }
+
+    /// A playback rate for one copy, drawn from `rng`.
+    pub(crate) fn rate(&self, rng: &mut impl Rng) -> f32 {
+        let jitter = self.pitch_jitter.clamp(0.0, 0.5);
+        1.0 + jitter * (rng.random::<f32>() * 2.0 - 1.0)
+    }
+}
+
+/// Spawn times of a [`Burst`] and how far through them it is. Added
+/// automatically.
+#[derive(Component, Default, Debug, Clone)]
+pub struct BurstClock {
+    /// Seconds since the burst started.
+    pub(crate) elapsed: f32,
+    /// Drawn from `Burst::schedule` on the burst's first frame.
+    pub(crate) times: Option<Vec<f32>>,
+    /// Copies spawned so far.
+    pub(crate) spawned: usize,
+}
@@ -1,13 +1,32 @@
 use bevy::prelude::*;
 
+use crate::dsp::convolution::ImpulseResponse;
+
 /// Reverb effect. Attach to a `Synth` entity.
 #[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Reverb {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub room_size: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub decay_time: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub damping: f32,
     /// Wet/dry mix (0.0 = fully dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub mix: f32,
+    /// Stereo width of the reverb return (0.0 = mono, 1.0 = as generated,
+    /// up to 2.0 = exaggerated).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub width: f32,
+    /// Balance of discrete early reflections against the diffuse tail
+    /// (0.0 = tail only, 1.0 = early reflections only). Early reflections
+    /// give a sense of the room's walls without a long wash.
+    #[cfg_attr(feature = "serde", serde(default, deserialize_with = "crate::serde_util::finite"))]
+    pub early_late_mix: f32,
 }
 
 impl Default for Reverb {
@@ -17,17 +36,71 @@ impl Default for Reverb {
             decay_time: 1.5,
             damping: 0.3,
             mix: 0.3,
+            width: 1.0,
+            early_late_mix: 0.0,
         }
     }
 }
 
-/// Delay effect. Attach to a `Synth` entity.
+/// Convolution reverb: convolves the synth's output with a recorded
+/// [`ImpulseResponse`], for the sound of a real space. Attach to a `Synth`
+/// entity; it takes the place of `Reverb`.
+///
+/// Until `ir` has loaded the synth uses its `Reverb`, or a default one if it
+/// has none, and it is rebuilt with the convolution once the response
+/// arrives. Not part of `SynthConfig`, since the handle comes from the
+/// `AssetServer`.
+#[derive(Component, Debug, Clone)]
+pub struct ConvolutionReverb {
+    pub ir: Handle<ImpulseResponse>,
+    /// Wet/dry mix (0.0 = fully dry, 1.0 = fully wet).
+    pub mix: f32,
+}
+
+impl ConvolutionReverb {
+    pub fn new(ir: Handle<ImpulseResponse>) -> Self {
+        Self { ir, mix: 0.3 }
+    }
+}
+
+/// Send a share of this source's output to the shared
+/// [`ReverbBus`](crate::dsp::reverb_bus::ReverbBus) (0.0 = none, 1.0 = full
+/// level). Cheaper than a per-entity reverb when many sources need one; on a
+/// `Synth` it replaces the `Reverb` component. Add it with the sound; the
+/// level is synced live.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReverbSend(
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub f32,
+);
+
+impl Default for ReverbSend {
+    fn default() -> Self {
+        Self(0.3)
+    }
+}
+
+/// Delay effect. Attach to a `Synth` entity; applied in stereo before
+/// reverb. `time_seconds` (up to 2s) and `feedback` (up to 0.95) are synced
+/// live. Set `sync` to time the echoes to a tempo instead.
 #[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delay {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub time_seconds: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub feedback: f32,
     /// Wet/dry mix (0.0 = fully dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub mix: f32,
+    /// Bounce the echoes between the left and right channels instead of
+    /// repeating each channel in place.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ping_pong: bool,
+    /// Derive the delay time from a tempo, replacing `time_seconds`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sync: Option<DelaySync>,
 }
 
 impl Default for Delay {
@@ -36,17 +109,111 @@ impl Default for Delay {
             time_seconds: 0.3,
             feedback: 0.4,
             mix: 0.3,
+            ping_pong: false,
+            sync: None,
+        }
+    }
+}
+
+/// Tempo sync for a [`Delay`]: each echo lands one `division` after the last
+/// at `bpm` beats (quarter notes) per minute. `bpm` is synced live, so the
+/// echoes can follow a changing tempo.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DelaySync {
+    pub division: NoteDivision,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub bpm: f32,
+}
+
+impl Default for DelaySync {
+    fn default() -> Self {
+        Self {
+            division: NoteDivision::default(),
+            bpm: 120.0,
+        }
+    }
+}
+
+/// Note length a tempo-synced [`Delay`] repeats at.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoteDivision {
+    Half,
+    #[default]
+    Quarter,
+    Eighth,
+    /// An eighth and a half: the classic syncopated echo.
+    DottedEighth,
+    /// Three to a beat.
+    EighthTriplet,
+    Sixteenth,
+}
+
+impl NoteDivision {
+    /// Length in beats (quarter notes).
+    pub fn beats(self) -> f32 {
+        match self {
+            Self::Half => 2.0,
+            Self::Quarter => 1.0,
+            Self::Eighth => 0.5,
+            Self::DottedEighth => 0.75,
+            Self::EighthTriplet => 1.0 / 3.0,
+            Self::Sixteenth => 0.25,
         }
     }
 }
 
-/// Distortion effect (soft-clip waveshaper). Attach to a `Synth` entity.
+/// Three-band tone control: a low shelf below `low_freq`, a bell between the
+/// two corners, and a high shelf above `high_freq` (Hz). Gains are in dB
+/// (0.0 = flat, clamped to ±24) and synced live. Applied in stereo after the
+/// synth's filters and effects, before delay. Attach to a `Synth` entity.
 #[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Eq3 {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub low_gain: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub mid_gain: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub high_gain: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub low_freq: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub high_freq: f32,
+}
+
+impl Default for Eq3 {
+    fn default() -> Self {
+        Self {
+            low_gain: 0.0,
+            mid_gain: 0.0,
+            high_gain: 0.0,
+            low_freq: 250.0,
+            high_freq: 4000.0,
+        }
+    }
+}
+
+/// Distortion effect: a waveshaper of the chosen [`DistortionShape`]. Attach
+/// to a `Synth` entity.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Distortion {
     /// Drive amount (1.0 = clean, higher = more distortion).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub drive: f32,
     /// Wet/dry mix (0.0 = fully dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub mix: f32,
+    /// Oversampling factor for the waveshaper (1 = off, 2, or 4; higher values
+    /// use 4). Reduces aliasing at high drive on bright sources, at 2–4x the
+    /// shaper's cost.
+    pub oversample: u8,
+    /// Nonlinearity the driven signal goes through.
+    pub shape: DistortionShape,
 }
 
 impl Default for Distortion {
@@ -54,6 +221,118 @@ impl Default for Distortion {
         Self {
             drive: 2.0,
             mix: 0.5,
+            oversample: 1,
+            shape: DistortionShape::SoftClip,
         }
     }
 }
+
+/// Transfer curve of a [`Distortion`], each with its own harmonic character.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DistortionShape {
+    /// `tanh` saturation: smooth, warm odd harmonics.
+    #[default]
+    SoftClip,
+    /// Flat-topped at ±1: harsh, buzzy odd harmonics.
+    HardClip,
+    /// Peaks past ±1 fold back down, adding dense, metallic overtones that
+    /// keep changing as the drive rises.
+    Foldback,
+    /// Cubic soft clip: gentler than `tanh` until it flattens at ±1.
+    Cubic,
+    /// Asymmetric, like a diode clipper: the negative half is squashed much
+    /// harder, adding even harmonics.
+    Diode,
+}
+
+impl DistortionShape {
+    /// Shape an already-driven sample. Every curve stays within ±1.
+    pub fn apply(self, x: f32) -> f32 {
+        match self {
+            Self::SoftClip => x.tanh(),
+            Self::HardClip => x.clamp(-1.0, 1.0),
+            Self::Foldback => 1.0 - ((x + 1.0).rem_euclid(4.0) - 2.0).abs(),
+            Self::Cubic => {
+                let x = x.clamp(-1.0, 1.0);
+                1.5 * x - 0.5 * x * x * x
+            }
+            Self::Diode => {
+                if x >= 0.0 {
+                    1.0 - (-x).exp()
+                } else {
+                    0.25 * (x.exp() - 1.0)
+                }
+            }
+        }
+    }
+}
+
+/// Ring modulation: multiplies the signal by a sine carrier at `freq_hz`,
+/// giving metallic, bell-like, and robotic sidebands. Attach to a `Synth`
+/// entity; applied after distortion. `freq_hz` is synced live, so it can be
+/// swept.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RingMod {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub freq_hz: f32,
+    /// Wet/dry mix (0.0 = fully dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub mix: f32,
+}
+
+impl Default for RingMod {
+    fn default() -> Self {
+        Self {
+            freq_hz: 300.0,
+            mix: 0.5,
+        }
+    }
+}
+
+/// Noise gate: mutes the synth while its level is below `threshold` (linear
+/// amplitude), fading in over `attack` and out over `release` seconds.
+/// Applied last, after reverb, so it cuts reverb and distortion tails and
+/// the hiss floor between notes. Attach to a `Synth` entity. `threshold` is
+/// synced live.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gate {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub threshold: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub attack: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub release: f32,
+}
+
+impl Default for Gate {
+    fn default() -> Self {
+        Self {
+            threshold: 0.02,
+            attack: 0.002,
+            release: 0.1,
+        }
+    }
+}
+
+/// Psychoacoustic bass: adds harmonics of the sub-bass content, so booms and
+/// kicks are still heard as low on small speakers that can't reproduce the
+/// fundamental. Attach to any procedural audio entity, e.g. an `Explosion`
+/// or a kick `Drum`. Applied to the output before panning.
+///
+/// Read when the graph is built; like `Pan`, it disables baking.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BassEnhancer {
+    /// Level of the added harmonics (0.0 = off, 1.0 = strong).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub amount: f32,
+}
+
+impl Default for BassEnhancer {
+    fn default() -> Self {
+        Self { amount: 0.5 }
+    }
+}
@@ -0,0 +1,73 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+/// Fade-in and fade-out ramps for any procedural audio entity.
+///
+/// The sound ramps up from silence over `in_secs` when it starts. Removing
+/// the component or adding a [`StopRequest`] ramps it down over `out_secs`
+/// and then despawns the entity, instead of cutting the sound off with a
+/// click. Durations of 0.0 switch
+/// instantly.
+///
+/// Read when the graph is built; like `Pan`, it disables baking.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fade {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub in_secs: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub out_secs: f32,
+}
+
+impl Default for Fade {
+    fn default() -> Self {
+        Self {
+            in_secs: 0.05,
+            out_secs: 0.1,
+        }
+    }
+}
+
+/// Crossfade instead of cutting over when a playing entity's graph is
+/// rebuilt: a reloaded `SynthConfig` or convolution response, a `Synth`
+/// re-inserted to pick up a new `OscillatorType`, or one continuous preset
+/// swapped for another on the same entity. The old and new graphs play
+/// together for `secs` while one fades into the other, then the old one is
+/// dropped.
+///
+/// Without it a rebuild restarts the sound on a new player.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crossfade {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub secs: f32,
+}
+
+impl Default for Crossfade {
+    fn default() -> Self {
+        Self { secs: 0.1 }
+    }
+}
+
+/// Stops a procedural audio entity gracefully, then despawns it.
+///
+/// With a [`Fade`] the sound ramps down over `out_secs`; without one it is
+/// muted through a short de-click ramp. Insert `StopRequest::default()` or use
+/// [`StopAudioExt::stop_audio`].
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct StopRequest {
+    /// Set once the pause stage of an entity without a fade has been muted.
+    pub(crate) muted: bool,
+}
+
+/// `EntityCommands` extension for [`StopRequest`].
+pub trait StopAudioExt {
+    /// Fade the entity's sound out and despawn it once silent.
+    fn stop_audio(&mut self) -> &mut Self;
+}
+
+impl StopAudioExt for EntityCommands<'_> {
+    fn stop_audio(&mut self) -> &mut Self {
+        self.insert(StopRequest::default())
+    }
+}
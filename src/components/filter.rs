@@ -2,8 +2,11 @@ use bevy::prelude::*;
 
 /// Low-pass filter. Attach to a `Synth` entity.
 #[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LowPass {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub cutoff_hz: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub resonance: f32,
 }
 
@@ -18,8 +21,11 @@ impl Default for LowPass {
 
 /// High-pass filter. Attach to a `Synth` entity.
 #[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HighPass {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub cutoff_hz: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub resonance: f32,
 }
 
@@ -34,8 +40,11 @@ impl Default for HighPass {
 
 /// Band-pass filter. Attach to a `Synth` entity.
 #[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BandPass {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub center_hz: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub bandwidth: f32,
 }
 
@@ -47,3 +56,93 @@ impl Default for BandPass {
         }
     }
 }
+
+/// Resonator: a narrow two-pole peak that rings at `freq_hz`, for plucked,
+/// struck, and metallic tones. Attach to a `Synth` entity; applied after the
+/// filter. Higher `q` means a narrower peak and a longer ring.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Resonator {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub freq_hz: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub q: f32,
+}
+
+impl Default for Resonator {
+    fn default() -> Self {
+        Self {
+            freq_hz: 440.0,
+            q: 10.0,
+        }
+    }
+}
+
+/// Second-order all-pass: passes every frequency at full level but shifts
+/// phase around `freq_hz`, over a band that narrows as `q` rises. A building
+/// block for custom diffusion, phasing, and reverb networks. Attach to a
+/// `Synth` entity; applied after the resonator.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AllPass {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub freq_hz: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub q: f32,
+}
+
+impl Default for AllPass {
+    fn default() -> Self {
+        Self {
+            freq_hz: 1000.0,
+            q: 0.7,
+        }
+    }
+}
+
+/// Vowel shape for a [`Formant`] filter.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Vowel {
+    #[default]
+    A,
+    E,
+    I,
+    O,
+    U,
+}
+
+impl Vowel {
+    /// Center frequency (Hz) and linear gain of the vowel's first three
+    /// formants.
+    pub fn formants(self) -> [(f32, f32); 3] {
+        match self {
+            Vowel::A => [(800.0, 1.0), (1150.0, 0.5), (2900.0, 0.1)],
+            Vowel::E => [(400.0, 1.0), (1600.0, 0.25), (2700.0, 0.1)],
+            Vowel::I => [(350.0, 1.0), (1700.0, 0.1), (2700.0, 0.06)],
+            Vowel::O => [(450.0, 1.0), (800.0, 0.3), (2830.0, 0.08)],
+            Vowel::U => [(325.0, 1.0), (700.0, 0.16), (2530.0, 0.03)],
+        }
+    }
+}
+
+/// Formant filter: three parallel band-passes tuned to a vowel, for
+/// talkbox and robot-voice tones. Attach to a `Synth` entity; applied to the
+/// oscillator before the other filters. `intensity` blends from the dry
+/// signal (0.0) to fully vowel-shaped (1.0).
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Formant {
+    pub vowel: Vowel,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+}
+
+impl Default for Formant {
+    fn default() -> Self {
+        Self {
+            vowel: Vowel::A,
+            intensity: 1.0,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+use crate::dsp::source::ProceduralAudio;
+
+/// Output level of a procedural audio entity, updated every frame.
+///
+/// Opt-in: add it next to any preset or `Synth` to drive VU meters,
+/// lip flaps, or other audio-reactive visuals. Stays at zero until the
+/// sound starts playing.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct AudioLevel {
+    /// Running RMS level (0.0 = silence, 1.0 = full scale), smoothed over ~50 ms.
+    pub rms: f32,
+    /// Recent peak level, with a ~300 ms release.
+    pub peak: f32,
+}
+
+/// Watch an entity's output for transients — sudden jumps in level such as
+/// an explosion's onset or a drum hit — and send a [`TransientDetected`]
+/// message for each. Works on any source, preset or `Synth`.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(TransientCount)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransientDetect {
+    /// How small a jump counts (0.0–1.0). Higher values also catch softer
+    /// onsets; 0.0 turns detection off. Applies live.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub sensitivity: f32,
+}
+
+impl Default for TransientDetect {
+    fn default() -> Self {
+        Self { sensitivity: 0.5 }
+    }
+}
+
+/// Onsets already reported for a [`TransientDetect`] entity. Added
+/// automatically.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct TransientCount {
+    /// Audio asset the count was read from. A rebuilt source gets a new
+    /// asset whose count starts again at zero.
+    pub(crate) source: AssetId<ProceduralAudio>,
+    pub(crate) seen: u32,
+}
+
+/// Sent when a [`TransientDetect`] entity's output jumps in level, for
+/// reacting to the sound itself, e.g. camera shake on an explosion's onset.
+/// Arrives a frame or so after the audio thread heard it.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct TransientDetected {
+    /// The entity whose sound jumped.
+    pub entity: Entity,
+}
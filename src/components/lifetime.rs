@@ -1,10 +1,14 @@
 use bevy::prelude::*;
 
+use crate::components::level::AudioLevel;
+
 /// Marks a one-shot audio entity for automatic despawn after a fixed duration.
 ///
-/// Inserted by build systems for one-shot presets (SwordSlash, BluntImpact,
-/// LightningZap, LightningStrike). The lifecycle system ticks the elapsed
-/// time and despawns the entity once it exceeds `duration`.
+/// Inserted by the build systems of every one-shot preset, including each
+/// copy a `Burst` spawns. The lifecycle system ticks the elapsed time and
+/// despawns the entity once it exceeds `duration`. Both are in the sound's
+/// own seconds: `elapsed` advances at the entity's `PlaybackRate`, so a slowed
+/// sound plays out its tail, and a [`Retrigger`] sets it back to zero.
 #[derive(Component)]
 pub struct OneShotLifetime {
     pub duration: f32,
@@ -19,3 +23,65 @@ impl OneShotLifetime {
         }
     }
 }
+
+/// Insert on a playing one-shot to replay it from the start without
+/// respawning: the graph restarts (or the baked buffer rewinds) and
+/// `OneShotLifetime` begins again. Removed once handled, so insert it again
+/// for every hit. Far cheaper than a fresh entity for rapid-fire sounds.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct Retrigger;
+
+/// Opt-in marker for one-shot presets: render the sound to PCM once and play
+/// the buffer back instead of running the DSP graph per voice.
+///
+/// Renders are interned in [`BakeCache`](crate::dsp::bake::BakeCache) by
+/// preset + params, so repeated identical spawns share a single buffer, and
+/// a single audio asset when they play on the same `MixBus` without a
+/// `PlaybackRate`, `Retrigger`, `ReverbSend`, `AudioLevel`, or
+/// `TransientDetect`. Changing the `MixBus` or `PlaybackRate` of a sound
+/// sharing an asset, or retriggering it, first gives it a copy of its own,
+/// so its sharers play on unchanged. For presets with a random default
+/// `seed`, give the spawns a fixed seed so they hit the same entry; spawns
+/// with a `Variation` never repeat, so they run live. The cache evicts the
+/// least recently used renders past its size budget.
+/// Continuous presets with live `ParamHandle`s (Heartbeat, EarRinging) ignore
+/// it; a baked `ArcaneAttack` or `Explosion` keeps its spawn-time `lowpass`.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct BakedOneShot;
+
+/// Despawn the entity once its output has stayed below `threshold` (RMS,
+/// 0.0–1.0) for `hold_secs`, instead of after a guessed duration. Adapts to
+/// long reverb tails and to sounds that die away early.
+///
+/// Only starts watching once the sound has first risen above `threshold`,
+/// so it won't despawn a source that hasn't started yet. Pulls in
+/// [`AudioLevel`] metering. Can be combined with `OneShotLifetime`; whichever
+/// fires first despawns.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(AudioLevel, SilenceTimer)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoStopOnSilence {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub threshold: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub hold_secs: f32,
+}
+
+impl Default for AutoStopOnSilence {
+    fn default() -> Self {
+        Self {
+            threshold: 0.001,
+            hold_secs: 0.5,
+        }
+    }
+}
+
+/// Silence tracking for an `AutoStopOnSilence` entity, advanced by
+/// `silence_stop_system`.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct SilenceTimer {
+    /// Whether the sound has been above the threshold yet.
+    pub(crate) heard: bool,
+    /// Seconds spent below the threshold since it was last above.
+    pub(crate) silent_for: f32,
+}
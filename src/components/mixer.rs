@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+/// Mixer bus a source plays through; its volume comes from the
+/// [`Mixer`](crate::config::Mixer) resource.
+///
+/// Presets require a default: ambiences (`Wind`, `Rain`, `Fire`) play on
+/// `Ambience`, `UiBlip` on `Ui`, and everything else on `Sfx`. Insert one at
+/// spawn to override it. Changes are synced live.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MixBus {
+    #[default]
+    Sfx,
+    Ambience,
+    Ui,
+    Music,
+}
+
+impl MixBus {
+    pub const ALL: [MixBus; 4] = [MixBus::Sfx, MixBus::Ambience, MixBus::Ui, MixBus::Music];
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+
+    pub(crate) fn from_index(index: usize) -> Self {
+        Self::ALL.get(index).copied().unwrap_or_default()
+    }
+}
+
+/// Duck this entity's output while another entity is loud, e.g. ambience
+/// under dialogue or music under a big explosion.
+///
+/// The `source` entity's output level (the same metering `AudioLevel`
+/// reads) drives a gain on this entity: the louder the source, the deeper
+/// the dip, by up to `amount`. Insert it before the sound starts; `source`
+/// and `amount` can change live. A despawned or silent source leaves the
+/// sound at full level.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SideChain {
+    /// Entity whose level ducks this one.
+    pub source: Entity,
+    /// Deepest dip (0.0–1.0): 0.5 halves the level while the source is
+    /// loud, 1.0 silences it.
+    pub amount: f32,
+}
+
+impl SideChain {
+    /// Duck by `amount` whenever `source` is loud.
+    pub fn new(source: Entity, amount: f32) -> Self {
+        Self { source, amount }
+    }
+}
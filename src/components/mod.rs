@@ -1,4 +1,15 @@
+pub mod arpeggiator;
+pub mod automation;
+pub mod burst;
 pub mod effect;
+pub mod fade;
 pub mod filter;
+pub mod level;
 pub mod lifetime;
+pub mod mixer;
+pub mod playback;
+pub mod polyphony;
+pub mod sequencer;
+pub mod spatial;
 pub mod synth;
+pub mod variation;
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+/// Pause control for procedural audio entities.
+///
+/// While `paused` is set the output is muted but the graph keeps running, so
+/// a `Synth` or `Heartbeat` resumes where it would have been instead of
+/// restarting. Can be added at any time; removing it resumes playback.
+/// Baked one-shots play from PCM and ignore it.
+#[derive(Component, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Playback {
+    pub paused: bool,
+}
+
+/// Playback speed of a procedural audio entity (1.0 = normal, 2.0 = twice as
+/// fast and an octave up). Pitch and timing change together, like a tape or
+/// sampler, for live graphs and baked one-shots alike. Clamped to 0.25–4.0;
+/// can be changed at any time and removing it returns to normal speed.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaybackRate(
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub f32,
+);
+
+impl Default for PlaybackRate {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl PlaybackRate {
+    /// The speed actually played, after clamping to 0.25–4.0.
+    pub fn speed(self) -> f32 {
+        self.0.clamp(0.25, 4.0)
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+/// Most voices a [`Polyphony`] synth can have.
+pub const MAX_VOICES: u8 = 32;
+
+/// Turns a `Synth` into a polyphonic instrument: instead of one oscillator at
+/// `Frequency`, the graph holds `max_voices` oscillators, each with its own
+/// attack/release envelope, summed into the synth's filters and effects.
+///
+/// Play it by pushing notes into the entity's [`NoteEvents`]. A note-on takes
+/// a free voice (the one released longest ago), or steals the oldest held
+/// note when all are busy. `Frequency` is ignored, and the voices are not
+/// scaled down, so lower `Amplitude` for dense chords. Read when the graph is
+/// built.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(NoteEvents, VoiceSlots)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyphony {
+    /// Number of voices, 1–32.
+    pub max_voices: u8,
+    /// Seconds for a voice to rise to its velocity.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub attack: f32,
+    /// Seconds for a released voice to fade out.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub release: f32,
+}
+
+impl Default for Polyphony {
+    fn default() -> Self {
+        Self {
+            max_voices: 8,
+            attack: 0.005,
+            release: 0.2,
+        }
+    }
+}
+
+impl Polyphony {
+    /// Voice count actually built.
+    pub fn voices(&self) -> usize {
+        self.max_voices.clamp(1, MAX_VOICES) as usize
+    }
+}
+
+/// A note played on a [`Polyphony`] synth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoteEvent {
+    /// Start a note at `frequency` (Hz) and `velocity` (0.0–1.0, the voice's
+    /// level).
+    On { frequency: f32, velocity: f32 },
+    /// Release every held note at `frequency`.
+    Off { frequency: f32 },
+}
+
+/// Queue of notes for a [`Polyphony`] synth, drained each frame once its
+/// graph is built. Added automatically with `Polyphony`.
+///
+/// ```ignore
+/// events.note_on(Frequency::from_midi(60).0, 0.8);
+/// ```
+#[derive(Component, Default, Debug, Clone)]
+pub struct NoteEvents(pub Vec<NoteEvent>);
+
+impl NoteEvents {
+    pub fn note_on(&mut self, frequency: f32, velocity: f32) {
+        self.0.push(NoteEvent::On {
+            frequency,
+            velocity,
+        });
+    }
+
+    pub fn note_off(&mut self, frequency: f32) {
+        self.0.push(NoteEvent::Off { frequency });
+    }
+}
+
+/// Which note each voice of a [`Polyphony`] synth is playing, for voice
+/// allocation.
+#[derive(Component, Default, Debug, Clone)]
+pub struct VoiceSlots {
+    pub(crate) slots: Vec<VoiceSlot>,
+    /// Counter stamping note-ons and releases, oldest lowest.
+    pub(crate) clock: u64,
+}
+
+#[derive(Default, Debug, Clone, Copy)]
+pub(crate) struct VoiceSlot {
+    pub(crate) frequency: f32,
+    pub(crate) held: bool,
+    /// `clock` when the note started, or when it was released.
+    pub(crate) since: u64,
+}
+
+impl VoiceSlots {
+    /// Slot for a new note among `count` voices: the free voice released
+    /// longest ago, else the oldest held one.
+    pub(crate) fn allocate(&mut self, count: usize) -> usize {
+        self.slots.resize(count, VoiceSlot::default());
+        let oldest = |held: bool| {
+            self.slots
+                .iter()
+                .enumerate()
+                .filter(|(_, slot)| slot.held == held)
+                .min_by_key(|(_, slot)| slot.since)
+                .map(|(index, _)| index)
+        };
+        oldest(false).or_else(|| oldest(true)).unwrap_or(0)
+    }
+
+    pub(crate) fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
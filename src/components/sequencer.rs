@@ -0,0 +1,124 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use crate::components::effect::NoteDivision;
+use crate::presets::bell::Bell;
+use crate::presets::blunt_impact::BluntImpact;
+use crate::presets::drum::{Drum, DrumKind};
+use crate::presets::footstep::Footstep;
+use crate::presets::glass_break::GlassBreak;
+use crate::presets::gunshot::Gunshot;
+use crate::presets::laser::Laser;
+use crate::presets::pickup::Pickup;
+use crate::presets::pluck::Pluck;
+use crate::presets::ui_blip::UiBlip;
+
+/// A one-shot preset a [`Sequencer`] step or a `Burst` plays.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PresetTrigger {
+    Drum(Drum),
+    Pluck(Pluck),
+    Bell(Bell),
+    UiBlip(UiBlip),
+    BluntImpact(BluntImpact),
+    Footstep(Footstep),
+    Gunshot(Gunshot),
+    Laser(Laser),
+    Pickup(Pickup),
+    GlassBreak(GlassBreak),
+}
+
+impl PresetTrigger {
+    pub fn kick() -> Self {
+        Self::drum(DrumKind::Kick)
+    }
+
+    pub fn snare() -> Self {
+        Self::drum(DrumKind::Snare)
+    }
+
+    pub fn hihat() -> Self {
+        Self::drum(DrumKind::HiHat)
+    }
+
+    fn drum(kind: DrumKind) -> Self {
+        Self::Drum(Drum {
+            kind,
+            ..default()
+        })
+    }
+
+    /// Spawn the preset as a new entity, which its build system then plays.
+    pub fn spawn<'a>(&self, commands: &'a mut Commands) -> EntityCommands<'a> {
+        match self {
+            Self::Drum(preset) => commands.spawn(preset.clone()),
+            Self::Pluck(preset) => commands.spawn(preset.clone()),
+            Self::Bell(preset) => commands.spawn(preset.clone()),
+            Self::UiBlip(preset) => commands.spawn(preset.clone()),
+            Self::BluntImpact(preset) => commands.spawn(preset.clone()),
+            Self::Footstep(preset) => commands.spawn(preset.clone()),
+            Self::Gunshot(preset) => commands.spawn(preset.clone()),
+            Self::Laser(preset) => commands.spawn(preset.clone()),
+            Self::Pickup(preset) => commands.spawn(preset.clone()),
+            Self::GlassBreak(preset) => commands.spawn(preset.clone()),
+        }
+    }
+}
+
+/// Step sequencer for one-shot presets: a rhythm machine. Every `division`
+/// at `bpm` beats per minute it advances one step and spawns that step's
+/// preset, if any; `None` is a rest. Loops back to the first step unless
+/// `once` is set.
+///
+/// Read every frame, so the pattern and tempo can be changed live. Every hit
+/// of a step spawns the same preset values, including its `seed`.
+///
+/// ```ignore
+/// commands.spawn(Sequencer {
+///     steps: vec![Some(PresetTrigger::kick()), None, Some(PresetTrigger::snare()), None],
+///     bpm: 120.0,
+///     ..default()
+/// });
+/// ```
+#[derive(Component, Debug, Clone)]
+#[require(SequencerClock)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sequencer {
+    pub steps: Vec<Option<PresetTrigger>>,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub bpm: f32,
+    /// Length of each step.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub division: NoteDivision,
+    /// Stop after the last step instead of looping.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub once: bool,
+}
+
+impl Default for Sequencer {
+    fn default() -> Self {
+        Self {
+            steps: Vec::new(),
+            bpm: 120.0,
+            division: NoteDivision::Quarter,
+            once: false,
+        }
+    }
+}
+
+impl Sequencer {
+    /// Length of one step in seconds at the current tempo.
+    pub fn step_secs(&self) -> f32 {
+        self.division.beats() * 60.0 / self.bpm.max(1.0)
+    }
+}
+
+/// Playback position of a [`Sequencer`]. Added automatically.
+#[derive(Component, Default, Debug, Clone)]
+pub struct SequencerClock {
+    /// Seconds since the last step.
+    pub(crate) elapsed: f32,
+    /// Steps played so far; the next step is `step % steps.len()`.
+    pub(crate) step: usize,
+}
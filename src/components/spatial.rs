@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+
+/// Positional sound source. Attach to any procedural audio entity.
+///
+/// Each frame the emitter's distance and direction from the `SpatialListener`
+/// are turned into a gain and an equal-power pan on the graph's output. At
+/// `max_distance` and beyond the source is silent.
+///
+/// `air_absorption` is read once when the graph is built; the other fields
+/// apply every frame.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Transform, DopplerShift)]
+pub struct SpatialEmitter {
+    /// Distance in world units at which the sound fades out completely.
+    pub max_distance: f32,
+    /// Attenuation curve exponent (1.0 = linear fade, >1 = falls off faster up close).
+    pub rolloff: f32,
+    /// Muffle distant sounds with a low-pass whose cutoff falls from 20 kHz
+    /// near the listener to about 2 kHz at `max_distance`.
+    pub air_absorption: bool,
+    /// Shift pitch with the emitter's and listener's relative velocity.
+    /// Only sources with a live frequency (`Synth`) are affected.
+    pub doppler: bool,
+}
+
+impl Default for SpatialEmitter {
+    fn default() -> Self {
+        Self {
+            max_distance: 50.0,
+            rolloff: 1.0,
+            air_absorption: false,
+            doppler: false,
+        }
+    }
+}
+
+/// Doppler pitch multiplier of a `SpatialEmitter`, updated every frame.
+///
+/// Velocity is estimated from the change in position since the previous
+/// frame, and the factor is clamped to 0.5–2.0 so teleports don't produce
+/// extreme jumps. Stays at 1.0 while `doppler` is off.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DopplerShift {
+    pub factor: f32,
+    pub(crate) previous_position: Option<Vec3>,
+}
+
+impl Default for DopplerShift {
+    fn default() -> Self {
+        Self {
+            factor: 1.0,
+            previous_position: None,
+        }
+    }
+}
+
+/// Stereo position for non-spatial sources (-1.0 = left, 0.0 = center, 1.0 = right).
+///
+/// Applies an equal-power pan to the graph's output. Much cheaper than a
+/// `SpatialEmitter` when a sound only needs placing left or right. Changes
+/// are synced live, so the pan can be automated.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct Pan(pub f32);
+
+/// How much geometry blocks a source from the listener (0.0 = clear, 1.0 =
+/// fully occluded), e.g. from a raycast against walls. Muffles the output
+/// with a low-pass falling from 20 kHz to 500 Hz and lowers it by up to
+/// 15 dB. Attach to any procedural audio entity.
+///
+/// Independent of distance, so it stacks with a `SpatialEmitter`'s falloff
+/// and air absorption. Changes are synced live and smoothed.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct Occlusion(pub f32);
@@ -1,13 +1,24 @@
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
+use std::sync::Arc;
+
+use crate::components::effect::{Delay, Distortion, Eq3, Gate, Reverb, RingMod};
+use crate::components::filter::{AllPass, BandPass, Formant, HighPass, LowPass, Resonator, Vowel};
+use crate::components::mixer::MixBus;
+use crate::components::polyphony::Polyphony;
+use crate::config::SynthConfig;
+use crate::pitch;
 
 /// Marker component that triggers DSP graph construction.
 /// Attach `OscillatorType`, `Frequency`, `Amplitude`, and optional filter/effect
 /// components to the same entity.
 #[derive(Component, Default, Debug, Clone, Copy)]
+#[require(MixBus = MixBus::Sfx)]
 pub struct Synth;
 
 /// Oscillator waveform type.
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OscillatorType {
     Sine,
     Saw,
@@ -24,7 +35,11 @@ impl Default for OscillatorType {
 
 /// Oscillator frequency in Hz.
 #[derive(Component, Debug, Clone, Copy)]
-pub struct Frequency(pub f32);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frequency(
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub f32,
+);
 
 impl Default for Frequency {
     fn default() -> Self {
@@ -32,12 +47,456 @@ impl Default for Frequency {
     }
 }
 
+impl Frequency {
+    /// Frequency of MIDI note `note` (69 = A4 = 440Hz).
+    pub fn from_midi(note: u8) -> Self {
+        Self(pitch::midi_to_hz(note))
+    }
+
+    /// Frequency of a note name such as `"C3"` or `"Bb4"`; see
+    /// [`pitch::note_name_to_midi`] for the accepted spellings.
+    pub fn from_note_name(name: &str) -> Option<Self> {
+        pitch::note_name_to_midi(name).map(Self::from_midi)
+    }
+
+    /// MIDI note number of this frequency, fractional between semitones.
+    pub fn to_midi(self) -> f32 {
+        pitch::hz_to_midi(self.0)
+    }
+}
+
+/// World axis a [`FrequencyFromTransform`] reads.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransformAxis {
+    X,
+    #[default]
+    Y,
+    Z,
+}
+
+impl TransformAxis {
+    /// `position`'s coordinate along this axis.
+    pub fn of(self, position: Vec3) -> f32 {
+        match self {
+            Self::X => position.x,
+            Self::Y => position.y,
+            Self::Z => position.z,
+        }
+    }
+}
+
+/// Drives a synth's `Frequency` from the entity's world position along
+/// `axis`, for sonifying movement or data: with the defaults, raising an
+/// object from 0 to 10 units sweeps it from 110Hz up to 880Hz.
+///
+/// Positions from `min_pos` to `max_pos` map onto `min_hz` to `max_hz`
+/// exponentially, so equal distances are equal musical intervals; positions
+/// outside the range hold the nearest end. Swap `min_pos` and `max_pos` to
+/// invert the mapping. Overwrites `Frequency` whenever the entity moves.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Frequency)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrequencyFromTransform {
+    pub axis: TransformAxis,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub min_hz: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub max_hz: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub min_pos: f32,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub max_pos: f32,
+}
+
+impl Default for FrequencyFromTransform {
+    fn default() -> Self {
+        Self {
+            axis: TransformAxis::Y,
+            min_hz: 110.0,
+            max_hz: 880.0,
+            min_pos: 0.0,
+            max_pos: 10.0,
+        }
+    }
+}
+
+impl FrequencyFromTransform {
+    /// Frequency in Hz for a world `position`.
+    pub fn frequency(&self, position: Vec3) -> f32 {
+        let span = self.max_pos - self.min_pos;
+        let t = if span.abs() > f32::EPSILON {
+            ((self.axis.of(position) - self.min_pos) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let (low, high) = (self.min_hz.max(1.0), self.max_hz.max(1.0));
+        low * (high / low).powf(t)
+    }
+}
+
 /// Output amplitude (0.0–1.0).
 #[derive(Component, Debug, Clone, Copy)]
-pub struct Amplitude(pub f32);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Amplitude(
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub f32,
+);
 
 impl Default for Amplitude {
     fn default() -> Self {
         Self(0.3)
     }
 }
+
+/// User-provided single-cycle waveform, replacing the synth's
+/// `OscillatorType`.
+///
+/// One period of samples, read at `Frequency` with linear interpolation and
+/// a wrapping phase, so any timbre can be loaded (e.g. from a WAV or drawn
+/// in an editor). Longer tables keep more harmonics. Read when the graph is
+/// built.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wavetable(
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "crate::serde_util::serialize_table",
+            deserialize_with = "crate::serde_util::finite_table"
+        )
+    )]
+    pub Arc<Vec<f32>>,
+);
+
+impl Wavetable {
+    pub fn new(samples: Vec<f32>) -> Self {
+        Self(Arc::new(samples))
+    }
+}
+
+/// Additive oscillator, replacing the synth's `OscillatorType` and any
+/// `Wavetable`: entry n of `partials` is the amplitude of harmonic n + 1 of
+/// `Frequency`, so `[1.0, 0.0, 0.33]` is a fundamental plus a quieter third
+/// harmonic. Good for drawbar-organ tones and other static spectra. The sum
+/// is normalized so it can't clip. Read when the graph is built.
+#[derive(Component, Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HarmonicOsc {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite_list"))]
+    pub partials: Vec<f32>,
+}
+
+/// Hard sync: the synth's waveform becomes a slave oscillator at `ratio`
+/// times `Frequency`, restarted every cycle of a silent master at
+/// `Frequency`. Sweeping `ratio` gives the classic sync-lead sound. Also
+/// syncs a `Wavetable` or `HarmonicOsc`; ignored for `Noise`. `ratio` is
+/// synced live.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OscSync {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub ratio: f32,
+}
+
+impl Default for OscSync {
+    fn default() -> Self {
+        Self { ratio: 2.0 }
+    }
+}
+
+/// True stereo: the synth's chain is built once per channel instead of one
+/// mono chain copied to both, and the two oscillators are detuned apart by
+/// up to ±10 cents at `spread` 1.0 (0.0 = identical pitch). Noise gets a
+/// different seed per side. Roughly doubles the synth's CPU cost, so mono
+/// stays the default. `spread` is synced live.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StereoSynth {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub spread: f32,
+}
+
+impl Default for StereoSynth {
+    fn default() -> Self {
+        Self { spread: 0.5 }
+    }
+}
+
+/// Sets a synth parameter by name, for tools, editors, and scripts that don't
+/// know the typed components.
+///
+/// The name is one of the `SynthParams` handles: `"frequency"`,
+/// `"amplitude"`, `"filter_cutoff"`, `"filter_resonance"`, `"resonator_freq"`,
+/// `"resonator_q"`, `"allpass_freq"`, `"allpass_q"`, `"formant_intensity"`,
+/// `"ring_mod_freq"`, `"osc_sync_ratio"`, `"stereo_spread"`, `"delay_time"`,
+/// `"delay_feedback"`, `"delay_bpm"`, `"gate_threshold"`, `"eq_low_gain"`,
+/// `"eq_mid_gain"`, or `"eq_high_gain"`. Changes are synced live; unknown
+/// names are logged and ignored.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NamedParam {
+    pub name: String,
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub value: f32,
+}
+
+/// Convenience builder: the `Synth` marker plus its core components.
+///
+/// Filters and effects are optional and only inserted when set through the
+/// builder methods, so this isn't a `Bundle` itself: spawn it with
+/// [`SynthBundle::spawn`] or add it to an entity with [`SynthBundle::insert`].
+/// The raw components can still be spawned directly.
+///
+/// ```ignore
+/// SynthBundle::saw(440.0).amplitude(0.3).low_pass(2000.0, 2.0).spawn(&mut commands);
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct SynthBundle {
+    pub synth: Synth,
+    pub oscillator: OscillatorType,
+    pub frequency: Frequency,
+    pub amplitude: Amplitude,
+    pub wavetable: Option<Wavetable>,
+    pub osc_sync: Option<OscSync>,
+    pub harmonic: Option<HarmonicOsc>,
+    pub stereo: Option<StereoSynth>,
+    pub polyphony: Option<Polyphony>,
+    pub formant: Option<Formant>,
+    pub low_pass: Option<LowPass>,
+    pub high_pass: Option<HighPass>,
+    pub band_pass: Option<BandPass>,
+    pub resonator: Option<Resonator>,
+    pub all_pass: Option<AllPass>,
+    pub reverb: Option<Reverb>,
+    pub delay: Option<Delay>,
+    pub distortion: Option<Distortion>,
+    pub ring_mod: Option<RingMod>,
+    pub gate: Option<Gate>,
+    pub eq: Option<Eq3>,
+}
+
+impl SynthBundle {
+    pub fn new(oscillator: OscillatorType, frequency: f32) -> Self {
+        Self {
+            oscillator,
+            frequency: Frequency(frequency),
+            ..default()
+        }
+    }
+
+    /// Spawn an entity with the synth's components.
+    pub fn spawn<'a>(self, commands: &'a mut Commands) -> EntityCommands<'a> {
+        let mut entity = commands.spawn_empty();
+        self.insert(&mut entity);
+        entity
+    }
+
+    /// Insert the synth's components on `entity`, with only the filters and
+    /// effects that are set.
+    pub fn insert(self, entity: &mut EntityCommands) {
+        entity.insert((self.synth, self.oscillator, self.frequency, self.amplitude));
+        if let Some(wavetable) = self.wavetable {
+            entity.insert(wavetable);
+        }
+        if let Some(osc_sync) = self.osc_sync {
+            entity.insert(osc_sync);
+        }
+        if let Some(harmonic) = self.harmonic {
+            entity.insert(harmonic);
+        }
+        if let Some(stereo) = self.stereo {
+            entity.insert(stereo);
+        }
+        if let Some(polyphony) = self.polyphony {
+            entity.insert(polyphony);
+        }
+        if let Some(formant) = self.formant {
+            entity.insert(formant);
+        }
+        if let Some(low_pass) = self.low_pass {
+            entity.insert(low_pass);
+        }
+        if let Some(high_pass) = self.high_pass {
+            entity.insert(high_pass);
+        }
+        if let Some(band_pass) = self.band_pass {
+            entity.insert(band_pass);
+        }
+        if let Some(resonator) = self.resonator {
+            entity.insert(resonator);
+        }
+        if let Some(all_pass) = self.all_pass {
+            entity.insert(all_pass);
+        }
+        if let Some(reverb) = self.reverb {
+            entity.insert(reverb);
+        }
+        if let Some(delay) = self.delay {
+            entity.insert(delay);
+        }
+        if let Some(distortion) = self.distortion {
+            entity.insert(distortion);
+        }
+        if let Some(ring_mod) = self.ring_mod {
+            entity.insert(ring_mod);
+        }
+        if let Some(gate) = self.gate {
+            entity.insert(gate);
+        }
+        if let Some(eq) = self.eq {
+            entity.insert(eq);
+        }
+    }
+
+    pub fn sine(frequency: f32) -> Self {
+        Self::new(OscillatorType::Sine, frequency)
+    }
+
+    pub fn saw(frequency: f32) -> Self {
+        Self::new(OscillatorType::Saw, frequency)
+    }
+
+    pub fn square(frequency: f32) -> Self {
+        Self::new(OscillatorType::Square, frequency)
+    }
+
+    pub fn triangle(frequency: f32) -> Self {
+        Self::new(OscillatorType::Triangle, frequency)
+    }
+
+    /// White noise source. Frequency is unused.
+    pub fn noise() -> Self {
+        Self::new(OscillatorType::Noise, Frequency::default().0)
+    }
+
+    pub fn amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = Amplitude(amplitude);
+        self
+    }
+
+    /// Play `samples` as a single-cycle wavetable instead of the stock
+    /// waveform.
+    pub fn wavetable(mut self, samples: Vec<f32>) -> Self {
+        self.wavetable = Some(Wavetable::new(samples));
+        self
+    }
+
+    pub fn osc_sync(mut self, ratio: f32) -> Self {
+        self.osc_sync = Some(OscSync { ratio });
+        self
+    }
+
+    /// Sum sines at the harmonics of the frequency, with `partials[n]` the
+    /// amplitude of harmonic n + 1, instead of the stock waveform.
+    pub fn harmonics(mut self, partials: Vec<f32>) -> Self {
+        self.harmonic = Some(HarmonicOsc { partials });
+        self
+    }
+
+    /// Build a separate chain per channel, detuned apart by `spread`
+    /// (0.0–1.0).
+    pub fn stereo(mut self, spread: f32) -> Self {
+        self.stereo = Some(StereoSynth { spread });
+        self
+    }
+
+    /// Play notes from `NoteEvents` on up to `max_voices` voices.
+    pub fn polyphony(mut self, max_voices: u8) -> Self {
+        self.polyphony = Some(Polyphony {
+            max_voices,
+            ..default()
+        });
+        self
+    }
+
+    pub fn formant(mut self, vowel: Vowel, intensity: f32) -> Self {
+        self.formant = Some(Formant { vowel, intensity });
+        self
+    }
+
+    pub fn low_pass(mut self, cutoff_hz: f32, resonance: f32) -> Self {
+        self.low_pass = Some(LowPass {
+            cutoff_hz,
+            resonance,
+        });
+        self
+    }
+
+    pub fn high_pass(mut self, cutoff_hz: f32, resonance: f32) -> Self {
+        self.high_pass = Some(HighPass {
+            cutoff_hz,
+            resonance,
+        });
+        self
+    }
+
+    pub fn band_pass(mut self, center_hz: f32, bandwidth: f32) -> Self {
+        self.band_pass = Some(BandPass {
+            center_hz,
+            bandwidth,
+        });
+        self
+    }
+
+    pub fn resonator(mut self, freq_hz: f32, q: f32) -> Self {
+        self.resonator = Some(Resonator { freq_hz, q });
+        self
+    }
+
+    pub fn all_pass(mut self, freq_hz: f32, q: f32) -> Self {
+        self.all_pass = Some(AllPass { freq_hz, q });
+        self
+    }
+
+    pub fn reverb(mut self, reverb: Reverb) -> Self {
+        self.reverb = Some(reverb);
+        self
+    }
+
+    pub fn delay(mut self, delay: Delay) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    pub fn distortion(mut self, drive: f32, mix: f32) -> Self {
+        self.distortion = Some(Distortion {
+            drive,
+            mix,
+            ..default()
+        });
+        self
+    }
+
+    pub fn ring_mod(mut self, freq_hz: f32, mix: f32) -> Self {
+        self.ring_mod = Some(RingMod { freq_hz, mix });
+        self
+    }
+
+    pub fn gate(mut self, threshold: f32, attack: f32, release: f32) -> Self {
+        self.gate = Some(Gate {
+            threshold,
+            attack,
+            release,
+        });
+        self
+    }
+
+    /// Three-band EQ with gains in dB at the default corner frequencies.
+    pub fn eq(mut self, low_gain: f32, mid_gain: f32, high_gain: f32) -> Self {
+        self.eq = Some(Eq3 {
+            low_gain,
+            mid_gain,
+            high_gain,
+            ..default()
+        });
+        self
+    }
+}
+
+/// Spawn a synth from a [`SynthConfig`](crate::config::SynthConfig) asset.
+///
+/// Once the asset loads, its components are inserted on this entity. When the
+/// asset changes (hot-reload), they are replaced and the graph is rebuilt.
+#[derive(Component, Debug, Clone)]
+pub struct SynthConfigHandle(pub Handle<SynthConfig>);
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+/// Per-instance randomization for one-shot presets: each spawn's pitch,
+/// decay, and layer gains are jittered by up to ±`0` (0.1 = ±10%), so a
+/// burst of identical `BluntImpact`s doesn't sound robotic. Clamped to
+/// 0.0–0.5.
+///
+/// Unlike a preset's `seed`, which pins down one waveform, every spawn draws
+/// a fresh variation. Add it with the preset; it is read once at build time.
+/// Baked one-shots with variation render per spawn instead of sharing a
+/// buffer.
+#[derive(Component, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Variation(
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub f32,
+);
+
+impl Variation {
+    /// Amount actually applied.
+    fn amount(self) -> f32 {
+        self.0.clamp(0.0, 0.5)
+    }
+
+    /// A jitter source for one spawn, drawing from `seed`. A zero amount
+    /// gives [`Jitter::NONE`] whatever the seed.
+    pub fn jitter(self, seed: u64) -> Jitter {
+        let amount = self.amount();
+        if amount == 0.0 {
+            return Jitter::NONE;
+        }
+        Jitter {
+            amount,
+            state: seed,
+        }
+    }
+}
+
+/// Seeded source of the random factors a [`Variation`] applies, passed to
+/// each one-shot's `build_*_graph`. [`Jitter::NONE`] leaves the preset
+/// exactly as configured.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Jitter {
+    amount: f32,
+    state: u64,
+}
+
+impl Jitter {
+    /// No variation: every factor is 1.0.
+    pub const NONE: Self = Self {
+        amount: 0.0,
+        state: 0,
+    };
+
+    /// Whether every factor is 1.0, so the preset plays as configured.
+    pub fn is_none(&self) -> bool {
+        self.amount == 0.0
+    }
+
+    /// Next factor, uniform in 1 ± amount.
+    pub fn scale(&mut self) -> f32 {
+        if self.amount == 0.0 {
+            return 1.0;
+        }
+        // SplitMix64: tiny, and plenty for a handful of draws per spawn.
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        let unit = (z >> 40) as f32 / (1u64 << 24) as f32;
+        1.0 + self.amount * (2.0 * unit - 1.0)
+    }
+
+    /// Largest factor `scale` can return, for sizing a one-shot's lifetime
+    /// around a stretched decay.
+    pub fn max_scale(&self) -> f32 {
+        1.0 + self.amount
+    }
+}
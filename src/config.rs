@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+use crate::components::effect::{Delay, Distortion, Eq3, Gate, Reverb, RingMod};
+use crate::components::filter::{AllPass, BandPass, Formant, HighPass, LowPass, Resonator};
+use crate::components::mixer::MixBus;
+use crate::components::polyphony::Polyphony;
+use crate::components::synth::{
+    Amplitude, Frequency, HarmonicOsc, OscSync, OscillatorType, StereoSynth, Synth, SynthBundle,
+    Wavetable,
+};
+
+/// Maximum number of one-shot voices allowed to play at once.
+///
+/// When more one-shot entities (anything carrying a `OneShotLifetime`) are
+/// alive than this, the oldest are despawned to make room for new spawns.
+/// Continuous presets (Heartbeat, EarRinging, Synth) are never counted.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct VoiceLimit(pub usize);
+
+impl Default for VoiceLimit {
+    fn default() -> Self {
+        Self(32)
+    }
+}
+
+/// Global audio settings.
+///
+/// Decoder options are mirrored to the audio thread whenever the resource
+/// changes, so they apply to sounds that are already playing.
+#[derive(Resource, Debug, Clone)]
+pub struct AudioConfig {
+    /// Flush subnormal floats to zero while graphs are processed. Prevents
+    /// CPU spikes as long reverb and delay tails decay; on by default.
+    pub denormal_protection: bool,
+    /// Cutoff in Hz of a gentle high-pass on every source's output, removing
+    /// DC offset that wastes headroom and thumps on start/stop. `None`
+    /// disables it; on at 20 Hz by default.
+    pub master_high_pass: Option<f32>,
+    /// Output mono instead of stereo. Presets still render in stereo and are
+    /// averaged down to one channel at the end of their graph, so this halves
+    /// decoding and mixing work in rodio but not the presets' own DSP; stereo
+    /// stages (reverbs, panning, spatial) collapse to their mono sum. Read
+    /// when each graph is built, so set it before spawning sounds.
+    pub force_mono: bool,
+    /// Output channel count for surround or quad devices, 2 by default. The
+    /// stereo graphs play on the first two channels (front left and right)
+    /// and the rest are silent. Overridden by `force_mono`; read when each
+    /// graph is built.
+    pub output_channels: u16,
+    /// Peak level the `tanh` soft limiter on every source's output eases
+    /// toward, so stacked loud layers saturate instead of hard-clipping.
+    /// Samples below 70% of it pass untouched. `0.0` disables it; 1.0 (full
+    /// scale) by default.
+    pub limit_ceiling: f32,
+    /// Frames each source renders per block, clamped to 1..=`MAX_BUFFER_SIZE`
+    /// (64). Parameter changes take effect on block boundaries, so smaller
+    /// blocks make `ParamHandle` writes audible sooner, at the cost of more
+    /// per-block overhead (CPU). Applies live.
+    pub block_size: usize,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            denormal_protection: true,
+            master_high_pass: Some(20.0),
+            force_mono: false,
+            output_channels: 2,
+            limit_ceiling: 1.0,
+            block_size: fundsp::MAX_BUFFER_SIZE,
+        }
+    }
+}
+
+impl AudioConfig {
+    /// Output channel count for newly built graphs.
+    pub fn channels(&self) -> u16 {
+        if self.force_mono { 1 } else { self.output_channels.max(1) }
+    }
+}
+
+/// Output volumes of the mixer buses (0.0 = silent, 1.0 = unity).
+///
+/// Each source is scaled by `master` times the volume of its
+/// [`MixBus`]. Changes apply live to sounds that are already playing.
+#[derive(Resource, Debug, Clone)]
+pub struct Mixer {
+    pub master: f32,
+    pub sfx: f32,
+    pub ambience: f32,
+    pub ui: f32,
+    pub music: f32,
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Self {
+            master: 1.0,
+            sfx: 1.0,
+            ambience: 1.0,
+            ui: 1.0,
+            music: 1.0,
+        }
+    }
+}
+
+impl Mixer {
+    /// Volume of `bus`, not including `master`.
+    pub fn volume(&self, bus: MixBus) -> f32 {
+        match bus {
+            MixBus::Sfx => self.sfx,
+            MixBus::Ambience => self.ambience,
+            MixBus::Ui => self.ui,
+            MixBus::Music => self.music,
+        }
+    }
+
+    /// Mutable volume of `bus`, e.g. for a settings slider.
+    pub fn volume_mut(&mut self, bus: MixBus) -> &mut f32 {
+        match bus {
+            MixBus::Sfx => &mut self.sfx,
+            MixBus::Ambience => &mut self.ambience,
+            MixBus::Ui => &mut self.ui,
+            MixBus::Music => &mut self.music,
+        }
+    }
+}
+
+/// Random source for per-spawn randomness: `Variation` jitter, `Burst`
+/// timing and pitch, and `ArpMode::Random` notes. Seeded from the OS unless
+/// the plugin is built with [`BevyProcAudPlugin::with_seed`], or the resource
+/// is replaced with [`ProcAudRng::seeded`]; a fixed seed replays a session's
+/// variations exactly.
+///
+/// Preset `seed` fields are separate: they default to random when the preset
+/// is constructed, so set them too for fully repeatable audio.
+///
+/// [`BevyProcAudPlugin::with_seed`]: crate::plugin::BevyProcAudPlugin::with_seed
+#[derive(Resource, Debug, Clone)]
+pub struct ProcAudRng(pub SmallRng);
+
+impl Default for ProcAudRng {
+    fn default() -> Self {
+        Self(SmallRng::from_os_rng())
+    }
+}
+
+impl ProcAudRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self(SmallRng::seed_from_u64(seed))
+    }
+}
+
+/// Serializable description of a `Synth` entity: oscillator, level, and the
+/// optional filter/effect components.
+///
+/// Mirrors [`SynthBundle`]; convert with `SynthBundle::from(config)` to spawn.
+/// Also an asset: with the `serde` feature, `.synth.ron` files load into it,
+/// and entities carrying a
+/// [`SynthConfigHandle`](crate::components::synth::SynthConfigHandle) get its
+/// components inserted.
+#[derive(Asset, TypePath, Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SynthConfig {
+    pub oscillator: OscillatorType,
+    pub frequency: Frequency,
+    pub amplitude: Amplitude,
+    pub wavetable: Option<Wavetable>,
+    pub osc_sync: Option<OscSync>,
+    pub harmonic: Option<HarmonicOsc>,
+    pub stereo: Option<StereoSynth>,
+    pub polyphony: Option<Polyphony>,
+    pub formant: Option<Formant>,
+    pub low_pass: Option<LowPass>,
+    pub high_pass: Option<HighPass>,
+    pub band_pass: Option<BandPass>,
+    pub resonator: Option<Resonator>,
+    pub all_pass: Option<AllPass>,
+    pub reverb: Option<Reverb>,
+    pub delay: Option<Delay>,
+    pub distortion: Option<Distortion>,
+    pub ring_mod: Option<RingMod>,
+    pub gate: Option<Gate>,
+    pub eq: Option<Eq3>,
+}
+
+impl SynthConfig {
+    /// Snapshot of the synth components currently on `entity`, the inverse
+    /// of spawning a [`SynthBundle`] from a config. Missing components are
+    /// left at their defaults; a `ConvolutionReverb` is not captured.
+    ///
+    /// ```ignore
+    /// let config = SynthConfig::from_entity(world.entity(synth));
+    /// ```
+    pub fn from_entity(entity: EntityRef) -> Self {
+        Self {
+            oscillator: entity.get::<OscillatorType>().copied().unwrap_or_default(),
+            frequency: entity.get::<Frequency>().copied().unwrap_or_default(),
+            amplitude: entity.get::<Amplitude>().copied().unwrap_or_default(),
+            wavetable: entity.get::<Wavetable>().cloned(),
+            osc_sync: entity.get::<OscSync>().copied(),
+            harmonic: entity.get::<HarmonicOsc>().cloned(),
+            stereo: entity.get::<StereoSynth>().copied(),
+            polyphony: entity.get::<Polyphony>().copied(),
+            formant: entity.get::<Formant>().cloned(),
+            low_pass: entity.get::<LowPass>().cloned(),
+            high_pass: entity.get::<HighPass>().cloned(),
+            band_pass: entity.get::<BandPass>().cloned(),
+            resonator: entity.get::<Resonator>().cloned(),
+            all_pass: entity.get::<AllPass>().cloned(),
+            reverb: entity.get::<Reverb>().cloned(),
+            delay: entity.get::<Delay>().cloned(),
+            distortion: entity.get::<Distortion>().cloned(),
+            ring_mod: entity.get::<RingMod>().cloned(),
+            gate: entity.get::<Gate>().cloned(),
+            eq: entity.get::<Eq3>().cloned(),
+        }
+    }
+
+    /// Pretty-printed RON, in the `.synth.ron` format the loader reads.
+    #[cfg(feature = "serde")]
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+}
+
+impl From<SynthConfig> for SynthBundle {
+    fn from(config: SynthConfig) -> Self {
+        Self {
+            synth: Synth,
+            oscillator: config.oscillator,
+            frequency: config.frequency,
+            amplitude: config.amplitude,
+            wavetable: config.wavetable,
+            osc_sync: config.osc_sync,
+            harmonic: config.harmonic,
+            stereo: config.stereo,
+            polyphony: config.polyphony,
+            formant: config.formant,
+            low_pass: config.low_pass,
+            high_pass: config.high_pass,
+            band_pass: config.band_pass,
+            resonator: config.resonator,
+            all_pass: config.all_pass,
+            reverb: config.reverb,
+            delay: config.delay,
+            distortion: config.distortion,
+            ring_mod: config.ring_mod,
+            gate: config.gate,
+            eq: config.eq,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use fundsp::audiounit::AudioUnit;
+
+use crate::dsp::render::render_to_buffer;
+
+/// Loudness every one-shot preset is normalized to at `intensity: 1.0`, as
+/// the RMS of its loudest [`WINDOW_SECS`] window (0.1 ≈ -20 dBFS).
+pub const REFERENCE_RMS: f32 = 0.1;
+
+/// Length of the windows compared by [`measure_peak_rms`], in seconds.
+pub const WINDOW_SECS: f32 = 0.05;
+
+/// How long a preset is rendered to measure its normalization gain.
+pub const MEASURE_SECS: f32 = 3.0;
+
+/// Bounds on the normalization gain, so a near-silent reference render
+/// can't blow a preset up.
+const GAIN_RANGE: (f32, f32) = (0.25, 4.0);
+
+/// Sample rate used for measurements.
+const SAMPLE_RATE: u32 = 44100;
+
+/// RMS level of `seconds` of a generator graph, over all channels.
+pub fn measure_rms(graph: &mut dyn AudioUnit, seconds: f32) -> f32 {
+    let (samples, _) = render_to_buffer(graph, seconds, SAMPLE_RATE);
+    rms(&samples)
+}
+
+/// RMS level of the loudest [`WINDOW_SECS`] window in `seconds` of a
+/// generator graph.
+///
+/// Unlike [`measure_rms`] this ignores how long a sound's silent tail is, so
+/// a short blip and a long explosion compare fairly.
+pub fn measure_peak_rms(graph: &mut dyn AudioUnit, seconds: f32) -> f32 {
+    let (samples, channels) = render_to_buffer(graph, seconds, SAMPLE_RATE);
+    let window = ((WINDOW_SECS * SAMPLE_RATE as f32) as usize).max(1) * channels as usize;
+    samples.chunks(window).map(rms).fold(0.0, f32::max)
+}
+
+/// Gain bringing a graph's loudest window to [`REFERENCE_RMS`].
+pub fn normalization_gain(graph: &mut dyn AudioUnit) -> f32 {
+    let level = measure_peak_rms(graph, MEASURE_SECS);
+    if level > f32::EPSILON {
+        (REFERENCE_RMS / level).clamp(GAIN_RANGE.0, GAIN_RANGE.1)
+    } else {
+        1.0
+    }
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_sq: f32 = samples.iter().map(|s| s * s).sum();
+    (sum_sq / samples.len() as f32).sqrt()
+}
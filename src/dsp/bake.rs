@@ -0,0 +1,237 @@
+use bevy::prelude::*;
+use fundsp::audiounit::AudioUnit;
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::dsp::analysis::normalization_gain;
+use crate::dsp::render::render_to_buffer;
+use crate::dsp::source::ProceduralAudio;
+
+/// Samples of render a [`BakeCache`] holds by default before evicting the
+/// least recently used: 16 Mi, or 64 MiB of `f32`.
+pub const DEFAULT_MAX_SAMPLES: usize = 16 * 1024 * 1024;
+
+/// Misses in a row, without a hit, after which a preset type's bake keys are
+/// reported as never repeating.
+const UNREPEATED_MISSES: u32 = 16;
+
+/// Interned PCM renders of one-shot presets, keyed by preset + params.
+///
+/// Filled by the one-shot build systems for entities marked with
+/// [`BakedOneShot`](crate::components::lifetime::BakedOneShot). Identical
+/// spawns share one buffer, so a burst of the same impact renders only once.
+///
+/// Also tracks the audio assets playing those renders, so identical spawns
+/// share one asset as well as one buffer, and holds each preset type's
+/// loudness normalization gain, measured from one offline render. The plugin
+/// measures every preset at startup; without it, a preset is measured the
+/// first time it is spawned.
+///
+/// Holds at most [`DEFAULT_MAX_SAMPLES`] of audio, or the budget given to
+/// [`BakeCache::with_max_samples`] (insert it before adding the plugin), and
+/// evicts the least recently used renders past it. Keys that never repeat,
+/// such as those of spawns with a random `seed`, are warned about once per
+/// preset type.
+#[derive(Resource)]
+pub struct BakeCache {
+    entries: HashMap<String, BakedRender>,
+    max_samples: usize,
+    /// Samples across all `entries`.
+    held_samples: usize,
+    /// Advanced on every lookup, to order renders by last use.
+    clock: u64,
+    /// Misses in a row per preset type; see [`BakeCache::count_lookup`].
+    misses: HashMap<TypeId, u32>,
+    /// Preset types already warned about, so each is reported once.
+    warned: HashSet<TypeId>,
+    /// Shared assets by key. Only their ids are held, so an asset unloads
+    /// once its last sharer despawns.
+    assets: HashMap<String, AssetId<ProceduralAudio>>,
+    gains: HashMap<TypeId, f32>,
+}
+
+struct BakedRender {
+    samples: Arc<Vec<f32>>,
+    channels: u16,
+    /// `BakeCache::clock` when last looked up.
+    last_used: u64,
+}
+
+impl Default for BakeCache {
+    fn default() -> Self {
+        Self::with_max_samples(DEFAULT_MAX_SAMPLES)
+    }
+}
+
+impl BakeCache {
+    /// An empty cache holding at most `max_samples` samples of render. The
+    /// latest render is always kept, even when it alone is over budget.
+    pub fn with_max_samples(max_samples: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            max_samples,
+            held_samples: 0,
+            clock: 0,
+            misses: HashMap::new(),
+            warned: HashSet::new(),
+            assets: HashMap::new(),
+            gains: HashMap::new(),
+        }
+    }
+
+    /// Return the cached render for `key`, rendering `seconds` of the graph
+    /// produced by `build` on a miss and evicting the least recently used
+    /// renders if that goes over budget. Returns (samples, channels).
+    pub fn get_or_bake(
+        &mut self,
+        key: String,
+        seconds: f32,
+        sample_rate: u32,
+        build: impl FnOnce() -> Box<dyn AudioUnit>,
+    ) -> (Arc<Vec<f32>>, u16) {
+        self.clock += 1;
+        if let Some(render) = self.entries.get_mut(&key) {
+            render.last_used = self.clock;
+            return (render.samples.clone(), render.channels);
+        }
+        let mut graph = build();
+        let (samples, channels) = render_to_buffer(&mut *graph, seconds, sample_rate);
+        let samples = Arc::new(samples);
+        self.held_samples += samples.len();
+        self.entries.insert(
+            key,
+            BakedRender {
+                samples: samples.clone(),
+                channels,
+                last_used: self.clock,
+            },
+        );
+        self.evict();
+        (samples, channels)
+    }
+
+    /// Drop least recently used renders until the cache is within budget.
+    fn evict(&mut self) {
+        while self.held_samples > self.max_samples && self.entries.len() > 1 {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, render)| render.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                return;
+            };
+            if let Some(render) = self.entries.remove(&oldest) {
+                self.held_samples -= render.samples.len();
+            }
+        }
+    }
+
+    /// Whether a render for `key` is held.
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Note a lookup of a bake key for the preset type `preset`, warning once
+    /// if its keys keep missing: a spawn whose key can't repeat renders from
+    /// scratch and only pushes reusable renders out of the cache.
+    pub(crate) fn count_lookup(&mut self, preset: TypeId, name: &str, hit: bool) {
+        let misses = self.misses.entry(preset).or_default();
+        *misses = if hit { 0 } else { *misses + 1 };
+        if *misses >= UNREPEATED_MISSES && self.warned.insert(preset) {
+            warn!(
+                "{name}: {UNREPEATED_MISSES} BakedOneShot spawns in a row rendered a new \
+                 sound; give them a fixed `seed` so identical spawns share one render"
+            );
+        }
+    }
+
+    /// Warn once for the preset type `preset` that jittered spawns are never
+    /// baked, as no two of them sound the same.
+    pub(crate) fn refuse_jitter(&mut self, preset: TypeId, name: &str) {
+        if self.warned.insert(preset) {
+            warn!("{name}: BakedOneShot spawns with a Variation never repeat, so they run live");
+        }
+    }
+
+    /// Return the shared audio asset for `key`, adding the one produced by
+    /// `create` to `assets` when there is none or it has been unloaded.
+    /// Entries for unloaded assets are dropped along the way.
+    pub fn get_or_add_asset(
+        &mut self,
+        key: String,
+        assets: &mut Assets<ProceduralAudio>,
+        create: impl FnOnce(&mut Self) -> ProceduralAudio,
+    ) -> Handle<ProceduralAudio> {
+        if let Some(handle) = self
+            .assets
+            .get(&key)
+            .and_then(|&id| assets.get_strong_handle(id))
+        {
+            return handle;
+        }
+        self.assets.retain(|_, id| assets.contains(*id));
+        let handle = assets.add(create(self));
+        self.assets.insert(key, handle.id());
+        handle
+    }
+
+    /// Whether `id` is an asset shared by identical baked spawns, whose
+    /// playback state can't change without moving every sharer.
+    pub fn is_shared(&self, id: AssetId<ProceduralAudio>) -> bool {
+        self.assets.values().any(|&shared| shared == id)
+    }
+
+    /// Give a sharer of the asset `handle` an asset of its own to change: a
+    /// copy of the render on the same bus and rate, starting `start_secs`
+    /// in. `None` when `handle` isn't shared.
+    pub fn unshare(
+        &self,
+        handle: &Handle<ProceduralAudio>,
+        start_secs: f32,
+        assets: &mut Assets<ProceduralAudio>,
+    ) -> Option<Handle<ProceduralAudio>> {
+        if !self.is_shared(handle.id()) {
+            return None;
+        }
+        let audio = assets.get(handle)?.unshared(start_secs)?;
+        Some(assets.add(audio))
+    }
+
+    /// Return the normalization gain for the preset type `key`, measuring the
+    /// reference graph produced by `build` on a miss.
+    pub fn normalization_gain(
+        &mut self,
+        key: TypeId,
+        build: impl FnOnce() -> Box<dyn AudioUnit>,
+    ) -> f32 {
+        *self
+            .gains
+            .entry(key)
+            .or_insert_with(|| normalization_gain(&mut *build()))
+    }
+
+    /// Record a normalization gain measured ahead of time, such as by
+    /// `loudness_measure_system`.
+    pub fn insert_normalization_gain(&mut self, key: TypeId, gain: f32) {
+        self.gains.insert(key, gain);
+    }
+
+    /// Number of distinct renders held.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forget every cached render and shared asset. Playing sounds keep their
+    /// buffers and assets alive; normalization gains are kept.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.held_samples = 0;
+        self.assets.clear();
+    }
+}
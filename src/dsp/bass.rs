@@ -0,0 +1,31 @@
+use fundsp::prelude32::*;
+
+/// Upper edge of the sub-bass band the harmonics are generated from, in Hz.
+const SUB_CUTOFF: f32 = 120.0;
+/// Band the generated harmonics are kept in, in Hz: above the sub, below the
+/// body of the sound.
+const HARMONIC_LOW: f32 = 80.0;
+const HARMONIC_HIGH: f32 = 480.0;
+/// Butterworth Q of the band-limiting filters.
+const BAND_Q: f32 = std::f32::consts::FRAC_1_SQRT_2;
+/// Makeup for the rectified band, which comes out well below its input.
+const HARMONIC_GAIN: f32 = 2.0;
+
+/// Append a psychoacoustic bass enhancer to a stereo graph.
+///
+/// The mono sum is band-limited to the sub-bass and full-wave rectified,
+/// which doubles its frequency and adds further even harmonics. Band-passed
+/// to drop the rectifier's DC and fizz, they are mixed back into both
+/// channels at `amount`, so the ear fills in the missing fundamental.
+pub fn build_bass_enhancer_stage(graph: Box<dyn AudioUnit>, amount: f32) -> Box<dyn AudioUnit> {
+    let amount = amount.clamp(0.0, 1.0) * HARMONIC_GAIN;
+    let harmonics = join::<U2>()
+        >> lowpass_hz(SUB_CUTOFF, BAND_Q)
+        >> lowpass_hz(SUB_CUTOFF, BAND_Q)
+        >> map(|frame: &Frame<f32, U1>| -> f32 { frame[0].abs() })
+        >> highpass_hz(HARMONIC_LOW, BAND_Q)
+        >> lowpass_hz(HARMONIC_HIGH, BAND_Q)
+        >> split::<U2>();
+    let stage = multipass::<U2>() & (harmonics * amount);
+    Box::new(Net::wrap(graph) >> Net::wrap(Box::new(stage)))
+}
@@ -0,0 +1,419 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+use std::f32::consts::TAU;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+use std::sync::Arc;
+
+/// Samples per convolution block. The convolver's output lags its input by
+/// this much (about 12ms at 44.1kHz), which a reverb tail hides.
+pub const BLOCK: usize = 512;
+
+/// Longest impulse response used, in seconds; longer ones are truncated.
+pub const MAX_IR_SECS: f32 = 10.0;
+
+/// A recorded stereo impulse response for a
+/// [`ConvolutionReverb`](crate::components::effect::ConvolutionReverb).
+///
+/// Loaded from `.wav` files by [`ImpulseResponseLoader`]; a mono file is used
+/// for both channels, and channels past the second are ignored.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct ImpulseResponse {
+    channels: [Arc<Vec<f32>>; 2],
+    sample_rate: f32,
+}
+
+impl ImpulseResponse {
+    /// A stereo response recorded at `sample_rate`. The shorter channel is
+    /// padded with silence.
+    pub fn new(mut left: Vec<f32>, mut right: Vec<f32>, sample_rate: f32) -> Self {
+        let len = std::cmp::max(left.len(), right.len());
+        left.resize(len, 0.0);
+        right.resize(len, 0.0);
+        Self {
+            channels: [Arc::new(left), Arc::new(right)],
+            sample_rate,
+        }
+    }
+
+    /// The same response on both channels.
+    pub fn mono(samples: Vec<f32>, sample_rate: f32) -> Self {
+        let samples = Arc::new(samples);
+        Self {
+            channels: [samples.clone(), samples],
+            sample_rate,
+        }
+    }
+
+    /// Take the first two channels of a decoded `wave`.
+    pub fn from_wave(wave: &Wave) -> Self {
+        let channel = |index: usize| (0..wave.len()).map(|i| wave.at(index, i)).collect();
+        let sample_rate = wave.sample_rate() as f32;
+        match wave.channels() {
+            0 => Self::mono(Vec::new(), sample_rate),
+            1 => Self::mono(channel(0), sample_rate),
+            _ => Self::new(channel(0), channel(1), sample_rate),
+        }
+    }
+
+    /// Length in samples.
+    pub fn len(&self) -> usize {
+        self.channels[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Channel `index` (0 = left, 1 = right) resampled to `sample_rate`,
+    /// truncated to `MAX_IR_SECS`.
+    fn resampled(&self, index: usize, sample_rate: f32) -> Vec<f32> {
+        let source = &self.channels[index];
+        let ratio = self.sample_rate / sample_rate;
+        let len = std::cmp::min(
+            (source.len() as f32 / ratio) as usize,
+            (MAX_IR_SECS * sample_rate) as usize,
+        );
+        (0..len)
+            .map(|i| {
+                let position = i as f32 * ratio;
+                let index = position as usize;
+                let a = source.get(index).copied().unwrap_or(0.0);
+                let b = source.get(index + 1).copied().unwrap_or(0.0);
+                a + (b - a) * position.fract()
+            })
+            .collect()
+    }
+}
+
+/// Loads [`ImpulseResponse`] assets from `.wav` files.
+#[derive(Default, TypePath)]
+pub struct ImpulseResponseLoader;
+
+/// Failure to read or decode an impulse response file.
+#[derive(Debug)]
+pub enum ImpulseResponseLoaderError {
+    Io(std::io::Error),
+    Decode(String),
+}
+
+impl fmt::Display for ImpulseResponseLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not read impulse response: {e}"),
+            Self::Decode(e) => write!(f, "could not decode impulse response: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ImpulseResponseLoaderError {}
+
+impl From<std::io::Error> for ImpulseResponseLoaderError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl AssetLoader for ImpulseResponseLoader {
+    type Asset = ImpulseResponse;
+    type Settings = ();
+    type Error = ImpulseResponseLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let wave = Wave::load_slice(bytes)
+            .map_err(|e| ImpulseResponseLoaderError::Decode(e.to_string()))?;
+        Ok(ImpulseResponse::from_wave(&wave))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["wav"]
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Complex {
+    re: f32,
+    im: f32,
+}
+
+impl Complex {
+    fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+/// In-place radix-2 FFT of one fixed power-of-two size.
+struct Fft {
+    twiddles: Vec<Complex>,
+    reversed: Vec<usize>,
+}
+
+impl Fft {
+    fn new(size: usize) -> Self {
+        let bits = size.trailing_zeros();
+        Self {
+            twiddles: (0..size / 2)
+                .map(|k| {
+                    let angle = -TAU * k as f32 / size as f32;
+                    Complex {
+                        re: angle.cos(),
+                        im: angle.sin(),
+                    }
+                })
+                .collect(),
+            reversed: (0..size)
+                .map(|i| i.reverse_bits() >> (usize::BITS - bits))
+                .collect(),
+        }
+    }
+
+    fn forward(&self, buffer: &mut [Complex]) {
+        self.transform(buffer, false);
+    }
+
+    /// Inverse transform, scaled so `inverse(forward(x)) == x`.
+    fn inverse(&self, buffer: &mut [Complex]) {
+        self.transform(buffer, true);
+        let scale = 1.0 / buffer.len() as f32;
+        for value in buffer.iter_mut() {
+            value.re *= scale;
+            value.im *= scale;
+        }
+    }
+
+    fn transform(&self, buffer: &mut [Complex], inverse: bool) {
+        let size = buffer.len();
+        for (i, &j) in self.reversed.iter().enumerate() {
+            if j > i {
+                buffer.swap(i, j);
+            }
+        }
+        let mut len = 2;
+        while len <= size {
+            let half = len / 2;
+            let step = size / len;
+            for start in (0..size).step_by(len) {
+                for k in 0..half {
+                    let twiddle = self.twiddles[k * step];
+                    let twiddle = if inverse { twiddle.conj() } else { twiddle };
+                    let a = buffer[start + k];
+                    let b = buffer[start + k + half] * twiddle;
+                    buffer[start + k] = a + b;
+                    buffer[start + k + half] = a - b;
+                }
+            }
+            len *= 2;
+        }
+    }
+}
+
+/// One channel of a [`Convolver`].
+#[derive(Clone)]
+struct ConvolverChannel {
+    /// Spectrum of each `BLOCK`-long partition of the impulse response.
+    partitions: Arc<Vec<Vec<Complex>>>,
+    /// Spectra of the most recent input windows, one per partition.
+    history: Vec<Vec<Complex>>,
+    newest: usize,
+    /// The previous block followed by the block being filled.
+    input: Vec<f32>,
+    /// Output of the last completed block.
+    output: Vec<f32>,
+}
+
+impl ConvolverChannel {
+    fn new(fft: &Fft, response: &[f32], gain: f32) -> Self {
+        let partitions: Vec<Vec<Complex>> = response
+            .chunks(BLOCK)
+            .map(|chunk| {
+                let mut spectrum = vec![Complex::default(); 2 * BLOCK];
+                for (bin, sample) in spectrum.iter_mut().zip(chunk) {
+                    bin.re = sample * gain;
+                }
+                fft.forward(&mut spectrum);
+                spectrum
+            })
+            .collect();
+        Self {
+            history: vec![vec![Complex::default(); 2 * BLOCK]; partitions.len()],
+            partitions: Arc::new(partitions),
+            newest: 0,
+            input: vec![0.0; 2 * BLOCK],
+            output: vec![0.0; BLOCK],
+        }
+    }
+
+    fn reset(&mut self) {
+        for spectrum in &mut self.history {
+            spectrum.fill(Complex::default());
+        }
+        self.input.fill(0.0);
+        self.output.fill(0.0);
+    }
+
+    /// Convolve the window in `input` with the whole response, leaving the
+    /// next block of output in `output`.
+    fn process(&mut self, fft: &Fft, scratch: &mut [Complex]) {
+        if self.partitions.is_empty() {
+            self.output.fill(0.0);
+            return;
+        }
+        let count = self.partitions.len();
+        self.newest = (self.newest + 1) % count;
+        let window = &mut self.history[self.newest];
+        for (bin, sample) in window.iter_mut().zip(&self.input) {
+            *bin = Complex {
+                re: *sample,
+                im: 0.0,
+            };
+        }
+        fft.forward(window);
+
+        // Both signals are real, so only the lower half of the spectrum needs
+        // accumulating; the upper half is its mirror image.
+        scratch.fill(Complex::default());
+        for (age, partition) in self.partitions.iter().enumerate() {
+            let window = &self.history[(self.newest + count - age) % count];
+            for bin in 0..=BLOCK {
+                scratch[bin] = scratch[bin] + window[bin] * partition[bin];
+            }
+        }
+        for bin in 1..BLOCK {
+            scratch[2 * BLOCK - bin] = scratch[bin].conj();
+        }
+        fft.inverse(scratch);
+
+        // Overlap-save: the second half of the circular result is valid.
+        for (out, value) in self.output.iter_mut().zip(&scratch[BLOCK..]) {
+            *out = value.re;
+        }
+        self.input.copy_within(BLOCK.., 0);
+    }
+}
+
+/// Stereo convolution with an [`ImpulseResponse`], using uniformly
+/// partitioned FFT convolution so long responses stay affordable.
+///
+/// Inputs and outputs are left and right; the output is fully wet and
+/// `BLOCK` samples late. The response is resampled to the stream's sample
+/// rate and scaled to unit energy, so recordings of any level come out near
+/// the input's loudness.
+#[derive(Clone)]
+pub struct Convolver {
+    response: ImpulseResponse,
+    fft: Arc<Fft>,
+    channels: [ConvolverChannel; 2],
+    scratch: Vec<Complex>,
+    position: usize,
+}
+
+impl Convolver {
+    pub fn new(response: &ImpulseResponse) -> Self {
+        let fft = Arc::new(Fft::new(2 * BLOCK));
+        let channels = Self::prepare(&fft, response, 44100.0);
+        Self {
+            response: response.clone(),
+            fft,
+            channels,
+            scratch: vec![Complex::default(); 2 * BLOCK],
+            position: 0,
+        }
+    }
+
+    fn prepare(fft: &Fft, response: &ImpulseResponse, sample_rate: f32) -> [ConvolverChannel; 2] {
+        let channels = [0, 1].map(|index| response.resampled(index, sample_rate));
+        let energy = channels
+            .iter()
+            .map(|channel| channel.iter().map(|s| s * s).sum::<f32>())
+            .fold(0.0, f32::max);
+        let gain = if energy > 0.0 {
+            energy.sqrt().recip()
+        } else {
+            0.0
+        };
+        channels.map(|channel| ConvolverChannel::new(fft, &channel, gain))
+    }
+}
+
+impl AudioNode for Convolver {
+    const ID: u64 = 0x6270_615f_636f_6e76;
+    type Inputs = U2;
+    type Outputs = U2;
+
+    fn reset(&mut self) {
+        for channel in &mut self.channels {
+            channel.reset();
+        }
+        self.position = 0;
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.channels = Self::prepare(&self.fft, &self.response, sample_rate as f32);
+        self.position = 0;
+    }
+
+    fn tick(&mut self, input: &Frame<f32, Self::Inputs>) -> Frame<f32, Self::Outputs> {
+        let mut output = [0.0; 2];
+        for (index, channel) in self.channels.iter_mut().enumerate() {
+            channel.input[BLOCK + self.position] = input[index];
+            output[index] = channel.output[self.position];
+        }
+        self.position += 1;
+        if self.position == BLOCK {
+            self.position = 0;
+            for channel in &mut self.channels {
+                channel.process(&self.fft, &mut self.scratch);
+            }
+        }
+        output.into()
+    }
+}
@@ -0,0 +1,56 @@
+use fundsp::audiounit::AudioUnit;
+use fundsp::net::{Net, NodeId, Source};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Describe a `Net`'s wiring as a Graphviz DOT digraph.
+///
+/// Nodes are numbered in `Net` order and labelled with their input/output
+/// counts; edges are labelled `output → input` channel. The net's own inputs
+/// and outputs appear as `in` and `out`. Render with `dot -Tsvg`:
+///
+/// ```ignore
+/// std::fs::write("graph.dot", net_to_dot(&net))?;
+/// ```
+pub fn net_to_dot(net: &Net) -> String {
+    let ids: Vec<NodeId> = net.ids().copied().collect();
+    let index: HashMap<NodeId, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+    // `write!` into a `String` can't fail.
+    let mut dot = String::from("digraph net {\n    rankdir=LR;\n    node [shape=box];\n");
+    if net.inputs() > 0 {
+        let _ = writeln!(dot, "    in [label=\"in\\n{} ch\", shape=ellipse];", net.inputs());
+    }
+    let _ = writeln!(dot, "    out [label=\"out\\n{} ch\", shape=ellipse];", net.outputs());
+
+    for (i, id) in ids.iter().enumerate() {
+        let node = net.node(*id);
+        let _ = writeln!(
+            dot,
+            "    n{i} [label=\"#{i}\\n{} in / {} out\"];",
+            node.inputs(),
+            node.outputs()
+        );
+    }
+
+    let edge = |dot: &mut String, source: Source, target: &str, channel: usize| match source {
+        Source::Local(id, out) => {
+            let _ = writeln!(dot, "    n{} -> {target} [label=\"{out}→{channel}\"];", index[&id]);
+        }
+        Source::Global(out) => {
+            let _ = writeln!(dot, "    in -> {target} [label=\"{out}→{channel}\"];");
+        }
+        Source::Zero => {}
+    };
+    for (i, id) in ids.iter().enumerate() {
+        for channel in 0..net.node(*id).inputs() {
+            edge(&mut dot, net.source(*id, channel), &format!("n{i}"), channel);
+        }
+    }
+    for channel in 0..net.outputs() {
+        edge(&mut dot, net.output_source(channel), "out", channel);
+    }
+
+    dot.push_str("}\n");
+    dot
+}
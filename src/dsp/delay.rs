@@ -0,0 +1,100 @@
+use fundsp::prelude32::*;
+
+/// Longest delay time supported, in seconds.
+pub const MAX_DELAY_SECS: f32 = 2.0;
+
+/// Highest feedback allowed, keeping the echoes decaying.
+pub const MAX_FEEDBACK: f32 = 0.95;
+
+/// Stereo feedback delay.
+///
+/// Inputs 0 and 1 are the left and right signal, input 2 the delay time in
+/// seconds, and input 3 the feedback; outputs are the dry signal blended
+/// with the echoes by `mix`. Normally each channel echoes into itself. In
+/// ping-pong mode the mono sum enters the left line only and each line feeds
+/// the other, so the echoes alternate left, right, left.
+#[derive(Clone)]
+pub struct StereoDelay {
+    lines: [Vec<f32>; 2],
+    write: usize,
+    sample_rate: f32,
+    mix: f32,
+    ping_pong: bool,
+}
+
+impl StereoDelay {
+    pub fn new(mix: f32, ping_pong: bool) -> Self {
+        let mut delay = Self {
+            lines: [Vec::new(), Vec::new()],
+            write: 0,
+            sample_rate: 44100.0,
+            mix: mix.clamp(0.0, 1.0),
+            ping_pong,
+        };
+        delay.allocate();
+        delay
+    }
+
+    /// Size both lines for `MAX_DELAY_SECS` at the current sample rate.
+    fn allocate(&mut self) {
+        let len = (MAX_DELAY_SECS * self.sample_rate) as usize + 2;
+        self.lines = [vec![0.0; len], vec![0.0; len]];
+        self.write = 0;
+    }
+
+    /// Line `channel` read `delay` samples behind the write head, linearly
+    /// interpolated.
+    fn read(&self, channel: usize, delay: f32) -> f32 {
+        let line = &self.lines[channel];
+        let len = line.len();
+        let position = (self.write + len) as f32 - delay;
+        let index = position as usize;
+        let frac = position.fract();
+        let a = line[index % len];
+        let b = line[(index + 1) % len];
+        a + (b - a) * frac
+    }
+}
+
+impl AudioNode for StereoDelay {
+    const ID: u64 = 0x6270_615f_646c_6179;
+    type Inputs = U4;
+    type Outputs = U2;
+
+    fn reset(&mut self) {
+        for line in &mut self.lines {
+            line.fill(0.0);
+        }
+        self.write = 0;
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_rate = sample_rate as f32;
+        self.allocate();
+    }
+
+    fn tick(&mut self, input: &Frame<f32, Self::Inputs>) -> Frame<f32, Self::Outputs> {
+        let max_delay = (self.lines[0].len() - 2) as f32;
+        let delay = (input[2] * self.sample_rate).clamp(1.0, max_delay);
+        let feedback = input[3].clamp(0.0, MAX_FEEDBACK);
+        let left = self.read(0, delay);
+        let right = self.read(1, delay);
+
+        let (into_left, into_right) = if self.ping_pong {
+            let mono = (input[0] + input[1]) * 0.5;
+            (mono + right * feedback, left * feedback)
+        } else {
+            (input[0] + left * feedback, input[1] + right * feedback)
+        };
+        self.lines[0][self.write] = into_left;
+        self.lines[1][self.write] = into_right;
+        self.write = (self.write + 1) % self.lines[0].len();
+
+        let dry = 1.0 - self.mix;
+        [
+            input[0] * dry + left * self.mix,
+            input[1] * dry + right * self.mix,
+        ]
+        .into()
+    }
+}
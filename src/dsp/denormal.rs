@@ -0,0 +1,80 @@
+/// Enables flush-to-zero on the current thread and restores the previous
+/// floating-point mode when dropped. A no-op on unsupported targets.
+///
+/// Reverb and delay tails decay toward zero through subnormal floats, which
+/// are very slow on many CPUs. While the guard is alive, subnormal results
+/// are flushed to zero (and, on x86, subnormal inputs are read as zero).
+pub(crate) struct FlushToZeroGuard {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    previous: u64,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl FlushToZeroGuard {
+    /// MXCSR flush-to-zero (FTZ) and denormals-are-zero (DAZ) bits.
+    const FLAGS: u32 = (1 << 15) | (1 << 6);
+
+    // `_mm_getcsr`/`_mm_setcsr` are deprecated in favor of inline asm, but
+    // remain the portable way to reach MXCSR on both x86 targets.
+    #[allow(deprecated)]
+    pub(crate) fn new() -> Self {
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::{_mm_getcsr, _mm_setcsr};
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::{_mm_getcsr, _mm_setcsr};
+
+        // SAFETY: SSE is baseline on every target Bevy supports; only the
+        // flush mode bits are changed.
+        let previous = unsafe { _mm_getcsr() };
+        unsafe { _mm_setcsr(previous | Self::FLAGS) };
+        Self {
+            previous: previous as u64,
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl Drop for FlushToZeroGuard {
+    #[allow(deprecated)]
+    fn drop(&mut self) {
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::_mm_setcsr;
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::_mm_setcsr;
+
+        // SAFETY: restores the value read in `new`.
+        unsafe { _mm_setcsr(self.previous as u32) };
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl FlushToZeroGuard {
+    /// FPCR flush-to-zero (FZ) bit.
+    const FLAGS: u64 = 1 << 24;
+
+    pub(crate) fn new() -> Self {
+        let previous: u64;
+        // SAFETY: reading and writing FPCR only changes this thread's
+        // floating-point mode.
+        unsafe {
+            std::arch::asm!("mrs {}, fpcr", out(reg) previous);
+            std::arch::asm!("msr fpcr, {}", in(reg) previous | Self::FLAGS);
+        }
+        Self { previous }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+impl Drop for FlushToZeroGuard {
+    fn drop(&mut self) {
+        // SAFETY: restores the value read in `new`.
+        unsafe { std::arch::asm!("msr fpcr, {}", in(reg) self.previous) };
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+impl FlushToZeroGuard {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::dsp::param::ParamHandle;
+
+/// Smoothing applied to the fade gain so per-frame steps don't zipper.
+const SMOOTHING: f32 = 0.005;
+
+/// Live gain of a fading source's output stage, ramped by `fade_system`.
+#[derive(Component)]
+pub struct FadeParams {
+    pub gain: ParamHandle,
+    pub(crate) in_secs: f32,
+    pub(crate) out_secs: f32,
+    /// Current ramp position (0.0–1.0).
+    pub(crate) level: f32,
+    /// Ramping down toward despawn.
+    pub(crate) stopping: bool,
+}
+
+/// Append a fade gain to a stereo graph.
+///
+/// Returns (graph, params). The gain starts at zero when there is a fade-in,
+/// so the first block is already silent.
+pub fn build_fade_stage(
+    graph: Box<dyn AudioUnit>,
+    in_secs: f32,
+    out_secs: f32,
+) -> (Box<dyn AudioUnit>, FadeParams) {
+    let level = if in_secs > 0.0 { 0.0 } else { 1.0 };
+    let gain = ParamHandle::new("fade", level, 0.0, 1.0);
+
+    let stage = var(gain.shared()) >> follow(SMOOTHING) >> split::<U2>();
+    let net = Net::wrap(graph) * Net::wrap(Box::new(stage));
+
+    (
+        Box::new(net),
+        FadeParams {
+            gain,
+            in_secs,
+            out_secs,
+            level,
+            stopping: false,
+        },
+    )
+}
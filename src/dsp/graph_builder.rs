@@ -1,142 +1,993 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
+use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::components::effect::{Delay, Distortion, Reverb};
-use crate::components::filter::{BandPass, HighPass, LowPass};
-use crate::components::synth::{Amplitude, Frequency, OscillatorType};
+use crate::components::effect::{Delay, Distortion, Eq3, Gate, Reverb, RingMod};
+use crate::components::filter::{AllPass, BandPass, Formant, HighPass, LowPass, Resonator, Vowel};
+use crate::components::polyphony::Polyphony;
+use crate::components::synth::{
+    Amplitude, Frequency, HarmonicOsc, OscSync, OscillatorType, StereoSynth, Wavetable,
+};
+use crate::config::SynthConfig;
+use crate::dsp::convolution::{Convolver, ImpulseResponse};
+use crate::dsp::delay::{StereoDelay, MAX_DELAY_SECS, MAX_FEEDBACK};
+use crate::dsp::oscillator::{harmonic_osc, harmonic_table, HardSync, Waveform, WavetableOsc};
 use crate::dsp::param::ParamHandle;
+use crate::dsp::reverb::reverb_stereo;
+#[cfg(debug_assertions)]
+use crate::dsp::validate::check_net;
+
+/// Q of each formant band-pass, narrowing with the formant number.
+const FORMANT_Q: [f32; 3] = [8.0, 12.0, 15.0];
+
+/// Detune of each `StereoSynth` side from `Frequency` at full spread, in
+/// cents.
+const MAX_SPREAD_CENTS: f32 = 10.0;
+
+/// Release of the `Gate`'s peak detector, in seconds: long enough to ride
+/// over zero crossings so the gate doesn't chatter within a cycle.
+const GATE_HOLD: f32 = 0.02;
+
+/// Shortest `Gate` fade, in seconds; also the detector's attack.
+const MIN_GATE_TIME: f32 = 0.0001;
+
+/// Shortest `Polyphony` voice attack or release, in seconds, so notes
+/// starting or stopping mid-cycle don't click.
+const MIN_VOICE_FADE: f32 = 0.001;
+
+/// Largest boost or cut of an `Eq3` band, in dB.
+const MAX_EQ_DB: f32 = 24.0;
+
+/// Q of the `Eq3` shelves (Butterworth, no overshoot at the corner) and of
+/// its mid bell, which is wide enough to act as a broad tone control.
+const EQ_SHELF_Q: f32 = std::f32::consts::FRAC_1_SQRT_2;
+const EQ_MID_Q: f32 = 0.5;
+
+/// Live controls for a synth's formant filter bank.
+#[derive(Clone)]
+pub struct FormantHandles {
+    pub centers: [Shared; 3],
+    pub gains: [Shared; 3],
+    pub intensity: ParamHandle,
+}
+
+impl FormantHandles {
+    fn new(formant: &Formant) -> Self {
+        let bands = formant.vowel.formants();
+        Self {
+            centers: bands.map(|(center, _)| Shared::new(center)),
+            gains: bands.map(|(_, gain)| Shared::new(gain)),
+            intensity: ParamHandle::new("formant_intensity", formant.intensity, 0.0, 1.0),
+        }
+    }
+
+    /// Retune the band-passes to another vowel.
+    pub fn set_vowel(&self, vowel: Vowel) {
+        for (i, (center, gain)) in vowel.formants().into_iter().enumerate() {
+            self.centers[i].set_value(center);
+            self.gains[i].set_value(gain);
+        }
+    }
+}
+
+/// Live controls for one voice of a `Polyphony` synth. A level of 0.0 lets
+/// the voice fade out.
+#[derive(Clone)]
+pub struct VoiceHandles {
+    pub frequency: Shared,
+    pub level: Shared,
+}
+
+impl VoiceHandles {
+    fn new() -> Self {
+        Self {
+            frequency: Shared::new(440.0),
+            level: Shared::new(0.0),
+        }
+    }
+}
 
 /// Holds all parameter handles for a synth entity's DSP graph.
+///
+/// Besides the typed fields, every handle is indexed by its
+/// [`ParamHandle::name`] so tools can address parameters generically with
+/// [`SynthParams::set`].
 #[derive(Component)]
 pub struct SynthParams {
     pub frequency: ParamHandle,
     pub amplitude: ParamHandle,
     pub filter_cutoff: Option<ParamHandle>,
     pub filter_resonance: Option<ParamHandle>,
+    pub resonator_freq: Option<ParamHandle>,
+    pub resonator_q: Option<ParamHandle>,
+    pub allpass_freq: Option<ParamHandle>,
+    pub allpass_q: Option<ParamHandle>,
+    pub formant: Option<FormantHandles>,
+    pub ring_mod_freq: Option<ParamHandle>,
+    pub osc_sync_ratio: Option<ParamHandle>,
+    pub stereo_spread: Option<ParamHandle>,
+    pub delay_time: Option<ParamHandle>,
+    pub delay_feedback: Option<ParamHandle>,
+    pub delay_bpm: Option<ParamHandle>,
+    pub gate_threshold: Option<ParamHandle>,
+    pub eq_low_gain: Option<ParamHandle>,
+    pub eq_mid_gain: Option<ParamHandle>,
+    pub eq_high_gain: Option<ParamHandle>,
+    /// One per `Polyphony` voice; empty for a monophonic synth.
+    pub voices: Vec<VoiceHandles>,
+    /// Pitch multiplier applied on top of every oscillator's frequency,
+    /// written by `SpatialEmitter` Doppler. Kept apart from `frequency` so
+    /// the two never overwrite each other.
+    pub doppler: Shared,
+    by_name: HashMap<&'static str, ParamHandle>,
+}
+
+impl SynthParams {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        frequency: ParamHandle,
+        amplitude: ParamHandle,
+        filter_cutoff: Option<ParamHandle>,
+        filter_resonance: Option<ParamHandle>,
+        resonator_freq: Option<ParamHandle>,
+        resonator_q: Option<ParamHandle>,
+        allpass_freq: Option<ParamHandle>,
+        allpass_q: Option<ParamHandle>,
+        formant: Option<FormantHandles>,
+        ring_mod_freq: Option<ParamHandle>,
+        osc_sync_ratio: Option<ParamHandle>,
+        stereo_spread: Option<ParamHandle>,
+        delay_time: Option<ParamHandle>,
+        delay_feedback: Option<ParamHandle>,
+        delay_bpm: Option<ParamHandle>,
+        gate_threshold: Option<ParamHandle>,
+        eq_low_gain: Option<ParamHandle>,
+        eq_mid_gain: Option<ParamHandle>,
+        eq_high_gain: Option<ParamHandle>,
+        voices: Vec<VoiceHandles>,
+    ) -> Self {
+        let by_name = [Some(&frequency), Some(&amplitude)]
+            .into_iter()
+            .chain([filter_cutoff.as_ref(), filter_resonance.as_ref()])
+            .chain([resonator_freq.as_ref(), resonator_q.as_ref()])
+            .chain([allpass_freq.as_ref(), allpass_q.as_ref()])
+            .chain([formant.as_ref().map(|formant| &formant.intensity)])
+            .chain([ring_mod_freq.as_ref(), osc_sync_ratio.as_ref()])
+            .chain([stereo_spread.as_ref()])
+            .chain([
+                delay_time.as_ref(),
+                delay_feedback.as_ref(),
+                delay_bpm.as_ref(),
+            ])
+            .chain([gate_threshold.as_ref()])
+            .chain([
+                eq_low_gain.as_ref(),
+                eq_mid_gain.as_ref(),
+                eq_high_gain.as_ref(),
+            ])
+            .flatten()
+            .map(|handle| (handle.name, handle.clone()))
+            .collect();
+        Self {
+            frequency,
+            amplitude,
+            filter_cutoff,
+            filter_resonance,
+            resonator_freq,
+            resonator_q,
+            allpass_freq,
+            allpass_q,
+            formant,
+            ring_mod_freq,
+            osc_sync_ratio,
+            stereo_spread,
+            delay_time,
+            delay_feedback,
+            delay_bpm,
+            gate_threshold,
+            eq_low_gain,
+            eq_mid_gain,
+            eq_high_gain,
+            voices,
+            doppler: Shared::new(1.0),
+            by_name,
+        }
+    }
+
+    /// Look up a handle by name (`"frequency"`, `"amplitude"`,
+    /// `"filter_cutoff"`, `"filter_resonance"`, `"resonator_freq"`,
+    /// `"resonator_q"`, `"allpass_freq"`, `"allpass_q"`,
+    /// `"formant_intensity"`, `"ring_mod_freq"`, `"osc_sync_ratio"`,
+    /// `"stereo_spread"`, `"delay_time"`, `"delay_feedback"`, `"delay_bpm"`,
+    /// `"gate_threshold"`, `"eq_low_gain"`, `"eq_mid_gain"`, `"eq_high_gain"`).
+    pub fn get(&self, name: &str) -> Option<&ParamHandle> {
+        self.by_name.get(name)
+    }
+
+    /// Set a parameter by name. Returns `false` if this graph has no
+    /// parameter called `name`.
+    pub fn set(&self, name: &str, value: f32) -> bool {
+        match self.get(name) {
+            Some(handle) => {
+                handle.set(value);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
-/// Build a FunDSP graph from synth component data.
+/// Oscillator, filter, and effect data for a synth graph, decoupled from
+/// the ECS.
+///
+/// The synth build system goes through this too; use it directly to render
+/// or bake a synth (e.g. in a build script) without spawning an entity.
 ///
-/// Returns (graph, params) where graph is stereo out and params
-/// contains all live-tweakable parameter handles.
+/// ```ignore
+/// let (graph, params) = GraphSpec::new(&OscillatorType::Saw, &Frequency(220.0), &Amplitude(0.3))
+///     .low_pass(&LowPass::default())
+///     .build();
+/// ```
+#[derive(Clone, Copy)]
+pub struct GraphSpec<'a> {
+    pub oscillator: &'a OscillatorType,
+    pub frequency: &'a Frequency,
+    pub amplitude: &'a Amplitude,
+    pub wavetable: Option<&'a Wavetable>,
+    pub osc_sync: Option<&'a OscSync>,
+    pub harmonic: Option<&'a HarmonicOsc>,
+    pub stereo: Option<&'a StereoSynth>,
+    pub polyphony: Option<&'a Polyphony>,
+    pub formant: Option<&'a Formant>,
+    pub low_pass: Option<&'a LowPass>,
+    pub high_pass: Option<&'a HighPass>,
+    pub band_pass: Option<&'a BandPass>,
+    pub resonator: Option<&'a Resonator>,
+    pub all_pass: Option<&'a AllPass>,
+    pub reverb: Option<&'a Reverb>,
+    /// Loaded impulse response and wet/dry mix of a `ConvolutionReverb`;
+    /// replaces `reverb`.
+    pub convolution: Option<(&'a ImpulseResponse, f32)>,
+    pub delay: Option<&'a Delay>,
+    pub distortion: Option<&'a Distortion>,
+    pub ring_mod: Option<&'a RingMod>,
+    pub gate: Option<&'a Gate>,
+    pub eq: Option<&'a Eq3>,
+}
+
+impl<'a> GraphSpec<'a> {
+    /// A bare oscillator with no filters or effects.
+    pub fn new(
+        oscillator: &'a OscillatorType,
+        frequency: &'a Frequency,
+        amplitude: &'a Amplitude,
+    ) -> Self {
+        Self {
+            oscillator,
+            frequency,
+            amplitude,
+            wavetable: None,
+            osc_sync: None,
+            harmonic: None,
+            stereo: None,
+            polyphony: None,
+            formant: None,
+            low_pass: None,
+            high_pass: None,
+            band_pass: None,
+            resonator: None,
+            all_pass: None,
+            reverb: None,
+            convolution: None,
+            delay: None,
+            distortion: None,
+            ring_mod: None,
+            gate: None,
+            eq: None,
+        }
+    }
+
+    pub fn wavetable(mut self, wavetable: &'a Wavetable) -> Self {
+        self.wavetable = Some(wavetable);
+        self
+    }
+
+    pub fn osc_sync(mut self, osc_sync: &'a OscSync) -> Self {
+        self.osc_sync = Some(osc_sync);
+        self
+    }
+
+    pub fn harmonic(mut self, harmonic: &'a HarmonicOsc) -> Self {
+        self.harmonic = Some(harmonic);
+        self
+    }
+
+    pub fn stereo(mut self, stereo: &'a StereoSynth) -> Self {
+        self.stereo = Some(stereo);
+        self
+    }
+
+    pub fn polyphony(mut self, polyphony: &'a Polyphony) -> Self {
+        self.polyphony = Some(polyphony);
+        self
+    }
+
+    pub fn formant(mut self, formant: &'a Formant) -> Self {
+        self.formant = Some(formant);
+        self
+    }
+
+    pub fn low_pass(mut self, low_pass: &'a LowPass) -> Self {
+        self.low_pass = Some(low_pass);
+        self
+    }
+
+    pub fn high_pass(mut self, high_pass: &'a HighPass) -> Self {
+        self.high_pass = Some(high_pass);
+        self
+    }
+
+    pub fn band_pass(mut self, band_pass: &'a BandPass) -> Self {
+        self.band_pass = Some(band_pass);
+        self
+    }
+
+    pub fn resonator(mut self, resonator: &'a Resonator) -> Self {
+        self.resonator = Some(resonator);
+        self
+    }
+
+    pub fn all_pass(mut self, all_pass: &'a AllPass) -> Self {
+        self.all_pass = Some(all_pass);
+        self
+    }
+
+    pub fn reverb(mut self, reverb: &'a Reverb) -> Self {
+        self.reverb = Some(reverb);
+        self
+    }
+
+    pub fn convolution(mut self, ir: &'a ImpulseResponse, mix: f32) -> Self {
+        self.convolution = Some((ir, mix));
+        self
+    }
+
+    pub fn delay(mut self, delay: &'a Delay) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    pub fn distortion(mut self, distortion: &'a Distortion) -> Self {
+        self.distortion = Some(distortion);
+        self
+    }
+
+    pub fn ring_mod(mut self, ring_mod: &'a RingMod) -> Self {
+        self.ring_mod = Some(ring_mod);
+        self
+    }
+
+    pub fn gate(mut self, gate: &'a Gate) -> Self {
+        self.gate = Some(gate);
+        self
+    }
+
+    pub fn eq(mut self, eq: &'a Eq3) -> Self {
+        self.eq = Some(eq);
+        self
+    }
+
+    /// Apply the builder method `add` if `part` is present, for building
+    /// from optional components:
+    ///
+    /// ```ignore
+    /// let spec = GraphSpec::new(&osc, &freq, &amp).with(low_pass, GraphSpec::low_pass);
+    /// ```
+    pub fn with<T>(self, part: Option<T>, add: impl FnOnce(Self, T) -> Self) -> Self {
+        match part {
+            Some(part) => add(self, part),
+            None => self,
+        }
+    }
+
+    /// Build the FunDSP graph.
+    ///
+    /// Returns (graph, params) where graph is stereo out and params
+    /// contains all live-tweakable parameter handles.
+    pub fn build(&self) -> (Box<dyn AudioUnit>, SynthParams) {
+        let GraphSpec {
+            oscillator: osc_type,
+            frequency: freq,
+            amplitude: amp,
+            wavetable,
+            osc_sync,
+            harmonic,
+            stereo,
+            polyphony,
+            formant,
+            low_pass,
+            high_pass,
+            band_pass,
+            resonator,
+            all_pass,
+            reverb: reverb_cfg,
+            convolution,
+            delay,
+            distortion,
+            ring_mod,
+            gate,
+            eq,
+        } = *self;
+
+        let freq_param = ParamHandle::new("frequency", freq.0, 20.0, 20000.0);
+        let amp_param = ParamHandle::new("amplitude", amp.0, 0.0, 1.0);
+
+        let freq_s = freq_param.shared().clone();
+        let amp_s = amp_param.shared().clone();
+        let doppler_s = Shared::new(1.0);
+
+        let mut filter_cutoff_param = None;
+        let mut filter_resonance_param = None;
+        let mut resonator_freq_param = None;
+        let mut resonator_q_param = None;
+        let mut allpass_freq_param = None;
+        let mut allpass_q_param = None;
+        let mut formant_handles = None;
+        let mut ring_mod_freq_param = None;
+        let mut osc_sync_ratio_param = None;
+        let mut stereo_spread_param = None;
+        let mut voice_handles = Vec::new();
+
+        // Use a Net to dynamically wire the graph.
+        let mut net = Net::new(0, 2);
+
+        // A `StereoSynth` builds the whole chain once per channel, detuned
+        // apart by the spread, instead of splitting one mono chain. The first
+        // pass creates each param handle and the second reuses it.
+        let sides: &[f32] = if stereo.is_some() {
+            &[-1.0, 1.0]
+        } else {
+            &[0.0]
+        };
+        let mut chain_ends = Vec::with_capacity(sides.len());
+        for (channel, &side) in sides.iter().enumerate() {
+            let spread_s = stereo.map(|stereo| {
+                shared_param(
+                    &mut stereo_spread_param,
+                    "stereo_spread",
+                    stereo.spread,
+                    0.0,
+                    1.0,
+                )
+            });
+            // Pitch of an oscillator playing at `freq`, detuned toward this
+            // side and Doppler-shifted.
+            let pitch = |freq: &Shared| -> Net {
+                let detuned = match &spread_s {
+                    Some(spread_s) => {
+                        let detune = (var(freq) | var(spread_s))
+                            >> map(move |frame: &Frame<f32, U2>| -> f32 {
+                                let cents = side * frame[1] * MAX_SPREAD_CENTS;
+                                frame[0] * (cents / 1200.0).exp2()
+                            });
+                        Net::wrap(Box::new(detune))
+                    }
+                    None => Net::wrap(Box::new(var(freq))),
+                };
+                detuned * Net::wrap(Box::new(var(&doppler_s)))
+            };
+
+            // A polyphonic synth sums one enveloped oscillator per voice in
+            // place of the single oscillator at `Frequency`.
+            let osc_id = match polyphony {
+                Some(poly) => {
+                    if voice_handles.is_empty() {
+                        voice_handles = (0..poly.voices()).map(|_| VoiceHandles::new()).collect();
+                    }
+                    let attack = poly.attack.max(MIN_VOICE_FADE);
+                    let release = poly.release.max(MIN_VOICE_FADE);
+                    let bank = voice_handles
+                        .iter()
+                        .enumerate()
+                        .map(|(index, voice)| {
+                            let seed = (2 * index + channel) as u64;
+                            let osc = oscillator(
+                                osc_type,
+                                harmonic,
+                                wavetable,
+                                osc_sync,
+                                seed,
+                                &mut osc_sync_ratio_param,
+                            );
+                            let envelope = var(&voice.level) >> afollow(attack, release);
+                            (pitch(&voice.frequency) >> Net::wrap(osc))
+                                * Net::wrap(Box::new(envelope))
+                        })
+                        .reduce(|sum, voice| sum + voice)
+                        .expect("a polyphonic synth has at least one voice");
+                    net.push(Box::new(bank))
+                }
+                None => {
+                    let osc = oscillator(
+                        osc_type,
+                        harmonic,
+                        wavetable,
+                        osc_sync,
+                        channel as u64,
+                        &mut osc_sync_ratio_param,
+                    );
+                    net.push(Box::new(pitch(&freq_s) >> Net::wrap(osc)))
+                }
+            };
+
+            let mut last_id = osc_id;
+
+            // Apply formant bank if present: three band-passes in parallel,
+            // each scaled by its formant gain, blended with the dry signal by
+            // intensity.
+            if let Some(formant) = formant {
+                let handles = formant_handles.get_or_insert_with(|| FormantHandles::new(formant));
+                let band = |i: usize| {
+                    let filter =
+                        (pass() | var(&handles.centers[i]) | constant(FORMANT_Q[i])) >> bandpass();
+                    filter * var(&handles.gains[i])
+                };
+                let bank = band(0) & band(1) & band(2);
+                let blend = map(|frame: &Frame<f32, U3>| -> f32 {
+                    frame[0] + (frame[1] - frame[0]) * frame[2]
+                });
+                let mix = ((pass() ^ bank) | var(handles.intensity.shared())) >> blend;
+                let formant_id = net.push(Box::new(mix));
+                net.connect(last_id, 0, formant_id, 0);
+                last_id = formant_id;
+            }
+
+            // Apply filter if present (priority: low-pass > high-pass > band-pass).
+            if let Some(lp) = low_pass {
+                let cutoff_s = shared_param(
+                    &mut filter_cutoff_param,
+                    "filter_cutoff",
+                    lp.cutoff_hz,
+                    20.0,
+                    20000.0,
+                );
+                let res_s = shared_param(
+                    &mut filter_resonance_param,
+                    "filter_resonance",
+                    lp.resonance,
+                    0.1,
+                    10.0,
+                );
+                let cutoff_id = net.push(Box::new(var(&cutoff_s)));
+                let res_id = net.push(Box::new(var(&res_s)));
+                let filter_id = net.push(Box::new(moog()));
+                net.connect(last_id, 0, filter_id, 0);
+                net.connect(cutoff_id, 0, filter_id, 1);
+                net.connect(res_id, 0, filter_id, 2);
+                last_id = filter_id;
+            } else if let Some(hp) = high_pass {
+                shared_param(
+                    &mut filter_cutoff_param,
+                    "filter_cutoff",
+                    hp.cutoff_hz,
+                    20.0,
+                    20000.0,
+                );
+                let filter_id = net.push(Box::new(highpole_hz(hp.cutoff_hz)));
+                net.connect(last_id, 0, filter_id, 0);
+                last_id = filter_id;
+            } else if let Some(bp) = band_pass {
+                let cutoff_s = shared_param(
+                    &mut filter_cutoff_param,
+                    "filter_cutoff",
+                    bp.center_hz,
+                    20.0,
+                    20000.0,
+                );
+                let bw_s = shared_param(
+                    &mut filter_resonance_param,
+                    "filter_resonance",
+                    bp.bandwidth,
+                    10.0,
+                    5000.0,
+                );
+                let cutoff_id = net.push(Box::new(var(&cutoff_s)));
+                let bw_id = net.push(Box::new(var(&bw_s)));
+                let filter_id = net.push(Box::new(bandpass()));
+                net.connect(last_id, 0, filter_id, 0);
+                net.connect(cutoff_id, 0, filter_id, 1);
+                net.connect(bw_id, 0, filter_id, 2);
+                last_id = filter_id;
+            }
+
+            // Apply resonator if present. FunDSP's resonator takes a bandwidth,
+            // so derive it from the center frequency and Q inside the graph.
+            if let Some(res) = resonator {
+                let freq_s = shared_param(
+                    &mut resonator_freq_param,
+                    "resonator_freq",
+                    res.freq_hz,
+                    20.0,
+                    20000.0,
+                );
+                let q_s = shared_param(&mut resonator_q_param, "resonator_q", res.q, 0.5, 200.0);
+                let freq_id = net.push(Box::new(var(&freq_s)));
+                let bw_id = net.push(Box::new(
+                    (var(&freq_s) | var(&q_s))
+                        >> map(|frame: &Frame<f32, U2>| -> f32 { frame[0] / frame[1] }),
+                ));
+                let res_id = net.push(Box::new(fundsp::prelude32::resonator()));
+                net.connect(last_id, 0, res_id, 0);
+                net.connect(freq_id, 0, res_id, 1);
+                net.connect(bw_id, 0, res_id, 2);
+                last_id = res_id;
+            }
+
+            // Apply all-pass if present, with live center and Q.
+            if let Some(ap) = all_pass {
+                let freq_s = shared_param(
+                    &mut allpass_freq_param,
+                    "allpass_freq",
+                    ap.freq_hz,
+                    20.0,
+                    20000.0,
+                );
+                let q_s = shared_param(&mut allpass_q_param, "allpass_q", ap.q, 0.1, 20.0);
+                let ap_node = (pass() | var(&freq_s) | var(&q_s)) >> allpass();
+                let ap_id = net.push(Box::new(ap_node));
+                net.connect(last_id, 0, ap_id, 0);
+                last_id = ap_id;
+            }
+
+            // Apply distortion if present.
+            if let Some(dist) = distortion {
+                let drive = dist.drive;
+                let mix = dist.mix;
+                let shape = dist.shape;
+                let shaper = map(move |frame: &Frame<f32, U1>| -> f32 {
+                    let x = frame[0];
+                    let saturated = shape.apply(x * drive);
+                    x * (1.0 - mix) + saturated * mix
+                });
+                // `oversample` runs the shaper at 2x with band-limited
+                // resampling; nesting it gives 4x.
+                let dist_id = match dist.oversample {
+                    0 | 1 => net.push(Box::new(shaper)),
+                    2 => net.push(Box::new(oversample(shaper))),
+                    _ => net.push(Box::new(oversample(oversample(shaper)))),
+                };
+                net.connect(last_id, 0, dist_id, 0);
+                last_id = dist_id;
+            }
+
+            // Apply ring modulation if present: signal times a sine carrier.
+            if let Some(ring) = ring_mod {
+                let freq_s = shared_param(
+                    &mut ring_mod_freq_param,
+                    "ring_mod_freq",
+                    ring.freq_hz,
+                    0.1,
+                    20000.0,
+                );
+                let mix = ring.mix;
+                let carrier = var(&freq_s) >> sine();
+                let ring_node = (pass() | carrier)
+                    >> map(move |frame: &Frame<f32, U2>| -> f32 {
+                        let x = frame[0];
+                        x * (1.0 - mix) + x * frame[1] * mix
+                    });
+                let ring_id = net.push(Box::new(ring_node));
+                net.connect(last_id, 0, ring_id, 0);
+                last_id = ring_id;
+            }
+
+            // Apply amplitude via a 2-input multiply map node.
+            let amp_id = net.push(Box::new(var(&amp_s)));
+            let amp_mul_id = net.push(Box::new(map(|frame: &Frame<f32, U2>| -> f32 {
+                frame[0] * frame[1]
+            })));
+            net.connect(last_id, 0, amp_mul_id, 0);
+            net.connect(amp_id, 0, amp_mul_id, 1);
+            chain_ends.push(amp_mul_id);
+        }
+
+        // Connect to output: one chain per channel, or the mono chain split
+        // to both.
+        if let [left, right] = chain_ends[..] {
+            net.connect_output(left, 0, 0);
+            net.connect_output(right, 0, 1);
+        } else {
+            let split_id = net.push(Box::new(split::<U2>()));
+            net.connect(chain_ends[0], 0, split_id, 0);
+            net.connect_output(split_id, 0, 0);
+            net.connect_output(split_id, 1, 1);
+        }
+
+        // Apply the EQ if present: one band chain per channel, sharing gains.
+        let mut eq_low_gain_param = None;
+        let mut eq_mid_gain_param = None;
+        let mut eq_high_gain_param = None;
+        if let Some(eq) = eq {
+            let low = ParamHandle::new("eq_low_gain", eq.low_gain, -MAX_EQ_DB, MAX_EQ_DB);
+            let mid = ParamHandle::new("eq_mid_gain", eq.mid_gain, -MAX_EQ_DB, MAX_EQ_DB);
+            let high = ParamHandle::new("eq_high_gain", eq.high_gain, -MAX_EQ_DB, MAX_EQ_DB);
+            let gains = [low.shared(), mid.shared(), high.shared()];
+            net = net >> (eq3(eq, gains) | eq3(eq, gains));
+            eq_low_gain_param = Some(low);
+            eq_mid_gain_param = Some(mid);
+            eq_high_gain_param = Some(high);
+        }
+
+        // Apply delay if present, in stereo so ping-pong can cross-feed.
+        let mut delay_time_param = None;
+        let mut delay_feedback_param = None;
+        let mut delay_bpm_param = None;
+        if let Some(delay) = delay {
+            // A tempo-synced delay converts its live bpm to seconds in the graph.
+            let time_node = match delay.sync {
+                Some(sync) => {
+                    let bpm = ParamHandle::new("delay_bpm", sync.bpm, 20.0, 400.0);
+                    let beats = sync.division.beats();
+                    let seconds = var(bpm.shared())
+                        >> map(move |frame: &Frame<f32, U1>| -> f32 {
+                            beats * 60.0 / frame[0].max(1.0)
+                        });
+                    delay_bpm_param = Some(bpm);
+                    Net::wrap(Box::new(seconds))
+                }
+                None => {
+                    let time =
+                        ParamHandle::new("delay_time", delay.time_seconds, 0.001, MAX_DELAY_SECS);
+                    let seconds = Net::wrap(Box::new(var(time.shared())));
+                    delay_time_param = Some(time);
+                    seconds
+                }
+            };
+            let feedback = ParamHandle::new("delay_feedback", delay.feedback, 0.0, MAX_FEEDBACK);
+            let delay_node = (multipass::<U2>() | time_node | var(feedback.shared()))
+                >> An(StereoDelay::new(delay.mix, delay.ping_pong));
+            net = net >> delay_node;
+            delay_feedback_param = Some(feedback);
+        }
+
+        // Apply reverb if present, preferring a convolution.
+        if let Some((ir, mix)) = convolution {
+            let mix = mix.clamp(0.0, 1.0);
+            let convolver = (multipass::<U2>() * (1.0 - mix)) & (An(Convolver::new(ir)) * mix);
+            net = net >> Net::wrap(Box::new(convolver));
+        } else if let Some(rev) = reverb_cfg {
+            net = net >> reverb_stereo(rev);
+        }
+
+        // Apply the gate last, so it also closes on reverb tails.
+        let mut gate_threshold_param = None;
+        if let Some(gate) = gate {
+            let threshold = ParamHandle::new("gate_threshold", gate.threshold, 0.0, 1.0);
+            net = net >> gate_stereo(gate, threshold.shared());
+            gate_threshold_param = Some(threshold);
+        }
+        #[cfg(debug_assertions)]
+        if let Err(error) = check_net(&net) {
+            panic!("build_synth_graph wired an invalid net: {error}");
+        }
+        let final_graph: Box<dyn AudioUnit> = Box::new(net);
+
+        let mut params = SynthParams::new(
+            freq_param,
+            amp_param,
+            filter_cutoff_param,
+            filter_resonance_param,
+            resonator_freq_param,
+            resonator_q_param,
+            allpass_freq_param,
+            allpass_q_param,
+            formant_handles,
+            ring_mod_freq_param,
+            osc_sync_ratio_param,
+            stereo_spread_param,
+            delay_time_param,
+            delay_feedback_param,
+            delay_bpm_param,
+            gate_threshold_param,
+            eq_low_gain_param,
+            eq_mid_gain_param,
+            eq_high_gain_param,
+            voice_handles,
+        );
+        params.doppler = doppler_s;
+
+        (final_graph, params)
+    }
+}
+
+/// Oscillator for one chain, frequency in and signal out: additive partials
+/// or a wavetable replace the stock waveform, and any of them can be
+/// hard-synced (partials by rendering them to a table). A nonzero `seed`
+/// reseeds noise so separate chains decorrelate.
+fn oscillator(
+    osc_type: &OscillatorType,
+    harmonic: Option<&HarmonicOsc>,
+    wavetable: Option<&Wavetable>,
+    osc_sync: Option<&OscSync>,
+    seed: u64,
+    osc_sync_ratio_param: &mut Option<ParamHandle>,
+) -> Box<dyn AudioUnit> {
+    match (osc_type, harmonic, wavetable, osc_sync) {
+        (_, Some(harmonic), _, None) => Box::new(harmonic_osc(&harmonic.partials)),
+        (_, None, Some(table), None) => Box::new(An(WavetableOsc::new(table.0.clone()))),
+        (OscillatorType::Noise, None, None, _) => {
+            let mut source: Box<dyn AudioUnit> = Box::new(sink() | noise());
+            if seed > 0 {
+                source.ping(false, AttoHash::new(seed));
+            }
+            source
+        }
+        (shape, harmonic, table, Some(sync)) => {
+            let waveform = match (harmonic, table) {
+                (Some(harmonic), _) => {
+                    Waveform::Table(Arc::new(harmonic_table(&harmonic.partials)))
+                }
+                (None, Some(table)) => Waveform::Table(table.0.clone()),
+                (None, None) => Waveform::Basic(*shape),
+            };
+            let ratio_s = shared_param(
+                osc_sync_ratio_param,
+                "osc_sync_ratio",
+                sync.ratio,
+                0.1,
+                16.0,
+            );
+            Box::new((pass() | var(&ratio_s)) >> An(HardSync::new(waveform)))
+        }
+        (OscillatorType::Sine, None, None, None) => Box::new(sine()),
+        (OscillatorType::Saw, None, None, None) => Box::new(saw()),
+        (OscillatorType::Square, None, None, None) => Box::new(square()),
+        (OscillatorType::Triangle, None, None, None) => Box::new(triangle()),
+    }
+}
+
+/// Stereo noise gate. A peak detector follows the louder channel; while it
+/// is at or above `threshold` the gain fades toward 1 over `attack`, and
+/// below it toward 0 over `release`. Both channels share the gain, so the
+/// image doesn't shift as the gate moves.
+fn gate_stereo(gate: &Gate, threshold: &Shared) -> Net {
+    let detector = map(|frame: &Frame<f32, U2>| -> f32 { frame[0].abs().max(frame[1].abs()) })
+        >> afollow(MIN_GATE_TIME, GATE_HOLD);
+    let open = (detector | var(threshold))
+        >> map(|frame: &Frame<f32, U2>| -> f32 {
+            if frame[0] >= frame[1] {
+                1.0
+            } else {
+                0.0
+            }
+        })
+        >> afollow(
+            gate.attack.max(MIN_GATE_TIME),
+            gate.release.max(MIN_GATE_TIME),
+        );
+
+    let mut net = Net::new(2, 2);
+    let gain_id = net.push(Box::new(open));
+    net.connect_input(0, gain_id, 0);
+    net.connect_input(1, gain_id, 1);
+    for channel in 0..2 {
+        let mul_id = net.push(Box::new(map(|frame: &Frame<f32, U2>| -> f32 {
+            frame[0] * frame[1]
+        })));
+        net.connect_input(channel, mul_id, 0);
+        net.connect(gain_id, 0, mul_id, 1);
+        net.connect_output(mul_id, 0, channel);
+    }
+    net
+}
+
+/// One channel of an `Eq3`: low shelf, mid bell, high shelf. `gains` are the
+/// live low, mid, and high gains in dB.
+fn eq3(eq: &Eq3, gains: [&Shared; 3]) -> Net {
+    let low_freq = eq.low_freq.clamp(20.0, 20000.0);
+    let high_freq = eq.high_freq.clamp(low_freq, 20000.0);
+    // The bell sits midway between the corners on a log scale.
+    let mid_freq = (low_freq * high_freq).sqrt();
+    let amp =
+        |gain: &Shared| var(gain) >> map(|frame: &Frame<f32, U1>| -> f32 { db_amp(frame[0]) });
+
+    let low = (pass() | dc((low_freq, EQ_SHELF_Q)) | amp(gains[0])) >> lowshelf();
+    let mid = (pass() | dc((mid_freq, EQ_MID_Q)) | amp(gains[1])) >> bell();
+    let high = (pass() | dc((high_freq, EQ_SHELF_Q)) | amp(gains[2])) >> highshelf();
+    Net::wrap(Box::new(low >> mid >> high))
+}
+
+/// The shared value of the param in `slot`, creating the handle on first use
+/// so both chains of a `StereoSynth` read the same one.
+fn shared_param(
+    slot: &mut Option<ParamHandle>,
+    name: &'static str,
+    initial: f32,
+    min: f32,
+    max: f32,
+) -> Shared {
+    slot.get_or_insert_with(|| ParamHandle::new(name, initial, min, max))
+        .shared()
+        .clone()
+}
+
+impl<'a> From<&'a SynthConfig> for GraphSpec<'a> {
+    fn from(config: &'a SynthConfig) -> Self {
+        Self {
+            oscillator: &config.oscillator,
+            frequency: &config.frequency,
+            amplitude: &config.amplitude,
+            wavetable: config.wavetable.as_ref(),
+            osc_sync: config.osc_sync.as_ref(),
+            harmonic: config.harmonic.as_ref(),
+            stereo: config.stereo.as_ref(),
+            polyphony: config.polyphony.as_ref(),
+            formant: config.formant.as_ref(),
+            low_pass: config.low_pass.as_ref(),
+            high_pass: config.high_pass.as_ref(),
+            band_pass: config.band_pass.as_ref(),
+            resonator: config.resonator.as_ref(),
+            all_pass: config.all_pass.as_ref(),
+            reverb: config.reverb.as_ref(),
+            convolution: None,
+            delay: config.delay.as_ref(),
+            distortion: config.distortion.as_ref(),
+            ring_mod: config.ring_mod.as_ref(),
+            gate: config.gate.as_ref(),
+            eq: config.eq.as_ref(),
+        }
+    }
+}
+
+/// Build a FunDSP graph from synth component data: a positional shim over
+/// [`GraphSpec`], which names each part and is the easier one to extend.
+#[allow(clippy::too_many_arguments)]
 pub fn build_synth_graph(
     osc_type: &OscillatorType,
     freq: &Frequency,
     amp: &Amplitude,
+    wavetable: Option<&Wavetable>,
+    osc_sync: Option<&OscSync>,
+    harmonic: Option<&HarmonicOsc>,
+    stereo: Option<&StereoSynth>,
+    polyphony: Option<&Polyphony>,
+    formant: Option<&Formant>,
     low_pass: Option<&LowPass>,
     high_pass: Option<&HighPass>,
     band_pass: Option<&BandPass>,
+    resonator: Option<&Resonator>,
+    all_pass: Option<&AllPass>,
     reverb_cfg: Option<&Reverb>,
-    _delay: Option<&Delay>,
+    convolution: Option<(&ImpulseResponse, f32)>,
+    delay: Option<&Delay>,
     distortion: Option<&Distortion>,
+    ring_mod: Option<&RingMod>,
+    gate: Option<&Gate>,
+    eq: Option<&Eq3>,
 ) -> (Box<dyn AudioUnit>, SynthParams) {
-    let freq_param = ParamHandle::new("frequency", freq.0, 20.0, 20000.0);
-    let amp_param = ParamHandle::new("amplitude", amp.0, 0.0, 1.0);
-
-    let freq_s = freq_param.shared().clone();
-    let amp_s = amp_param.shared().clone();
-
-    let mut filter_cutoff_param = None;
-    let mut filter_resonance_param = None;
-
-    // Use a Net to dynamically wire the graph.
-    let mut net = Net::new(0, 2);
-
-    // Build oscillator driven by frequency parameter.
-    let osc_id = match osc_type {
-        OscillatorType::Sine => net.push(Box::new(var(&freq_s) >> sine())),
-        OscillatorType::Saw => net.push(Box::new(var(&freq_s) >> saw())),
-        OscillatorType::Square => net.push(Box::new(var(&freq_s) >> square())),
-        OscillatorType::Triangle => net.push(Box::new(var(&freq_s) >> triangle())),
-        OscillatorType::Noise => net.push(Box::new(noise())),
-    };
-
-    let mut last_id = osc_id;
-
-    // Apply filter if present (priority: low-pass > high-pass > band-pass).
-    if let Some(lp) = low_pass {
-        let cutoff = ParamHandle::new("filter_cutoff", lp.cutoff_hz, 20.0, 20000.0);
-        let res = ParamHandle::new("filter_resonance", lp.resonance, 0.1, 10.0);
-        let cutoff_s = cutoff.shared().clone();
-        let res_s = res.shared().clone();
-        let cutoff_id = net.push(Box::new(var(&cutoff_s)));
-        let res_id = net.push(Box::new(var(&res_s)));
-        let filter_id = net.push(Box::new(moog()));
-        net.connect(last_id, 0, filter_id, 0);
-        net.connect(cutoff_id, 0, filter_id, 1);
-        net.connect(res_id, 0, filter_id, 2);
-        filter_cutoff_param = Some(cutoff);
-        filter_resonance_param = Some(res);
-        last_id = filter_id;
-    } else if let Some(hp) = high_pass {
-        let cutoff = ParamHandle::new("filter_cutoff", hp.cutoff_hz, 20.0, 20000.0);
-        let filter_id = net.push(Box::new(highpole_hz(hp.cutoff_hz)));
-        net.connect(last_id, 0, filter_id, 0);
-        filter_cutoff_param = Some(cutoff);
-        last_id = filter_id;
-    } else if let Some(bp) = band_pass {
-        let cutoff = ParamHandle::new("filter_cutoff", bp.center_hz, 20.0, 20000.0);
-        let bw = ParamHandle::new("filter_resonance", bp.bandwidth, 10.0, 5000.0);
-        let cutoff_s = cutoff.shared().clone();
-        let bw_s = bw.shared().clone();
-        let cutoff_id = net.push(Box::new(var(&cutoff_s)));
-        let bw_id = net.push(Box::new(var(&bw_s)));
-        let filter_id = net.push(Box::new(bandpass()));
-        net.connect(last_id, 0, filter_id, 0);
-        net.connect(cutoff_id, 0, filter_id, 1);
-        net.connect(bw_id, 0, filter_id, 2);
-        filter_cutoff_param = Some(cutoff);
-        filter_resonance_param = Some(bw);
-        last_id = filter_id;
-    }
-
-    // Apply distortion if present.
-    if let Some(dist) = distortion {
-        let drive = dist.drive;
-        let mix = dist.mix;
-        let dist_id = net.push(Box::new(map(move |frame: &Frame<f32, U1>| -> f32 {
-            let x = frame[0];
-            let saturated = (x * drive).tanh();
-            x * (1.0 - mix) + saturated * mix
-        })));
-        net.connect(last_id, 0, dist_id, 0);
-        last_id = dist_id;
-    }
-
-    // Apply amplitude via a 2-input multiply map node.
-    let amp_id = net.push(Box::new(var(&amp_s)));
-    let amp_mul_id = net.push(Box::new(map(|frame: &Frame<f32, U2>| -> f32 {
-        frame[0] * frame[1]
-    })));
-    net.connect(last_id, 0, amp_mul_id, 0);
-    net.connect(amp_id, 0, amp_mul_id, 1);
-    last_id = amp_mul_id;
-
-    // Split to stereo.
-    let split_id = net.push(Box::new(split::<U2>()));
-    net.connect(last_id, 0, split_id, 0);
-
-    // Connect to output.
-    net.connect_output(split_id, 0, 0);
-    net.connect_output(split_id, 1, 1);
-
-    // Apply reverb if present.
-    let final_graph: Box<dyn AudioUnit> = if let Some(rev) = reverb_cfg {
-        let room = rev.room_size;
-        let time = rev.decay_time;
-        let damp = rev.damping;
-        let reverb_node = reverb2_stereo(room, time, damp, 1.0, lowpole_hz(6000.0));
-        Box::new(net >> reverb_node)
-    } else {
-        Box::new(net)
-    };
-
-    let params = SynthParams {
-        frequency: freq_param,
-        amplitude: amp_param,
-        filter_cutoff: filter_cutoff_param,
-        filter_resonance: filter_resonance_param,
-    };
-
-    (final_graph, params)
+    GraphSpec {
+        oscillator: osc_type,
+        frequency: freq,
+        amplitude: amp,
+        wavetable,
+        osc_sync,
+        harmonic,
+        stereo,
+        polyphony,
+        formant,
+        low_pass,
+        high_pass,
+        band_pass,
+        resonator,
+        all_pass,
+        reverb: reverb_cfg,
+        convolution,
+        delay,
+        distortion,
+        ring_mod,
+        gate,
+        eq,
+    }
+    .build()
 }
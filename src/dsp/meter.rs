@@ -0,0 +1,155 @@
+use fundsp::shared::Shared;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// Smoothing time for the RMS reading, in seconds.
+const RMS_WINDOW: f32 = 0.05;
+/// Time for the peak reading to fall back by ~63%, in seconds.
+const PEAK_RELEASE: f32 = 0.3;
+
+/// Lock-free audio→ECS level readout, the reverse of [`ParamHandle`].
+///
+/// The decoder calls [`LevelMeter::update`] with every block it produces;
+/// the main thread reads the latest values with [`LevelMeter::rms`] and
+/// [`LevelMeter::peak`]. Clones share the same atomics.
+///
+/// [`ParamHandle`]: crate::dsp::param::ParamHandle
+#[derive(Clone)]
+pub struct LevelMeter {
+    rms: Shared,
+    peak: Shared,
+}
+
+impl Default for LevelMeter {
+    fn default() -> Self {
+        Self {
+            rms: Shared::new(0.0),
+            peak: Shared::new(0.0),
+        }
+    }
+}
+
+impl LevelMeter {
+    /// Fold one block of interleaved samples lasting `seconds` into the
+    /// running readings (audio thread).
+    pub(crate) fn update(&self, block: &[f32], seconds: f32) {
+        if block.is_empty() {
+            return;
+        }
+        let (sum_sq, block_peak) = block
+            .iter()
+            .fold((0.0_f32, 0.0_f32), |(sum, peak), s| (sum + s * s, peak.max(s.abs())));
+        let mean_sq = sum_sq / block.len() as f32;
+
+        // One-pole smoothing of the mean square, so the RMS is a running average.
+        let keep = (-seconds / RMS_WINDOW).exp();
+        let previous = self.rms.value();
+        let smoothed = previous * previous * keep + mean_sq * (1.0 - keep);
+        self.rms.set_value(smoothed.sqrt());
+
+        // Instant attack, exponential release.
+        let released = self.peak.value() * (-seconds / PEAK_RELEASE).exp();
+        self.peak.set_value(block_peak.max(released));
+    }
+
+    /// Running RMS level of the output (0.0 = silence, 1.0 = full scale).
+    pub fn rms(&self) -> f32 {
+        self.rms.value()
+    }
+
+    /// Recent peak level of the output, with a short release.
+    pub fn peak(&self) -> f32 {
+        self.peak.value()
+    }
+}
+
+/// Smoothing time of the background level a transient must jump above, in
+/// seconds.
+const TRANSIENT_WINDOW: f32 = 0.25;
+/// Quietest block energy (mean square, about -50 dB) that can be an onset.
+const TRANSIENT_FLOOR: f32 = 1e-5;
+/// Dead time after an onset, so one hit isn't reported twice.
+const TRANSIENT_HOLDOFF: f32 = 0.05;
+
+/// Lock-free onset counter shared between decoders and the main thread.
+///
+/// Detection is off until [`TransientTap::set_sensitivity`] is given a
+/// value above zero; decoders then bump [`TransientTap::count`] on every
+/// sudden jump in level. Clones share the same atomics.
+#[derive(Clone)]
+pub struct TransientTap {
+    sensitivity: Shared,
+    count: Arc<AtomicU32>,
+}
+
+impl Default for TransientTap {
+    fn default() -> Self {
+        Self {
+            sensitivity: Shared::new(0.0),
+            count: Arc::default(),
+        }
+    }
+}
+
+impl TransientTap {
+    /// Detect onsets at `sensitivity` (0.0 = off, 1.0 = most sensitive).
+    pub fn set_sensitivity(&self, sensitivity: f32) {
+        self.sensitivity.set_value(sensitivity.clamp(0.0, 1.0));
+    }
+
+    /// Onsets detected so far, wrapping.
+    pub fn count(&self) -> u32 {
+        self.count.load(Ordering::Relaxed)
+    }
+}
+
+/// Per-decoder onset detector feeding a [`TransientTap`]: flags a block whose
+/// energy jumps well above the recent average.
+#[derive(Default)]
+pub(crate) struct TransientDetector {
+    tap: TransientTap,
+    /// Running mean square of recent blocks.
+    average: f32,
+    /// Seconds left before another onset can be flagged.
+    holdoff: f32,
+}
+
+impl TransientDetector {
+    pub(crate) fn new(tap: TransientTap) -> Self {
+        Self {
+            tap,
+            ..Self::default()
+        }
+    }
+
+    /// Check one block of interleaved samples lasting `seconds` for an
+    /// onset (audio thread).
+    pub(crate) fn update(&mut self, block: &[f32], seconds: f32) {
+        let sensitivity = self.tap.sensitivity.value();
+        if sensitivity <= 0.0 || block.is_empty() {
+            return;
+        }
+        let energy = block.iter().map(|s| s * s).sum::<f32>() / block.len() as f32;
+
+        // Energy ratio over the average that counts as an onset: 8x (+9 dB)
+        // at the least sensitive, 1.5x (+1.8 dB) at the most.
+        let ratio = 8.0 - 6.5 * sensitivity;
+        self.holdoff = (self.holdoff - seconds).max(0.0);
+        if self.holdoff > 0.0 {
+            // Still inside the last onset: the level it rose to is the
+            // baseline from here on, so a sound that holds isn't flagged
+            // again while the average catches up.
+            self.average = self.average.max(energy);
+            return;
+        }
+        if energy > TRANSIENT_FLOOR && energy > self.average * ratio {
+            self.tap.count.fetch_add(1, Ordering::Relaxed);
+            self.holdoff = TRANSIENT_HOLDOFF;
+            self.average = energy;
+            return;
+        }
+
+        let keep = (-seconds / TRANSIENT_WINDOW).exp();
+        self.average = self.average * keep + energy * (1.0 - keep);
+    }
+}
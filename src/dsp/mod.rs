@@ -1,3 +1,22 @@
+pub mod analysis;
+pub mod bake;
+pub mod bass;
+pub mod convolution;
+pub mod debug;
+pub mod delay;
+pub(crate) mod denormal;
+pub mod fade;
 pub mod graph_builder;
+pub mod meter;
+pub mod oscillator;
 pub mod param;
+pub mod playback;
+pub mod render;
+pub mod reverb;
+pub mod reverb_bus;
+pub mod side_chain;
 pub mod source;
+pub mod spatial;
+pub mod validate;
+
+pub use graph_builder::GraphSpec;
@@ -0,0 +1,187 @@
+use fundsp::prelude32::*;
+use std::f32::consts::TAU;
+use std::sync::Arc;
+
+use crate::components::synth::OscillatorType;
+
+/// Single-cycle waveform read by the custom oscillators in this module.
+#[derive(Clone)]
+pub enum Waveform {
+    /// Naive (non-band-limited) stock shape. `Noise` has no cycle and
+    /// yields silence.
+    Basic(OscillatorType),
+    /// User-provided single cycle, linearly interpolated.
+    Table(Arc<Vec<f32>>),
+}
+
+impl Waveform {
+    /// Value at `phase` in `0.0..1.0`.
+    fn at(&self, phase: f32) -> f32 {
+        match self {
+            Self::Basic(shape) => basic(*shape, phase),
+            Self::Table(table) => table_at(table, phase),
+        }
+    }
+}
+
+fn basic(shape: OscillatorType, phase: f32) -> f32 {
+    match shape {
+        OscillatorType::Sine => (phase * TAU).sin(),
+        OscillatorType::Saw => 2.0 * phase - 1.0,
+        OscillatorType::Square => {
+            if phase < 0.5 {
+                1.0
+            } else {
+                -1.0
+            }
+        }
+        OscillatorType::Triangle => 4.0 * (phase - 0.5).abs() - 1.0,
+        OscillatorType::Noise => 0.0,
+    }
+}
+
+/// Linear interpolation into a single-cycle table, wrapping past the end.
+/// An empty table yields silence.
+fn table_at(table: &[f32], phase: f32) -> f32 {
+    if table.is_empty() {
+        return 0.0;
+    }
+    let position = phase * table.len() as f32;
+    let index = std::cmp::min(position as usize, table.len() - 1);
+    let next = table[(index + 1) % table.len()];
+    let frac = position - index as f32;
+    table[index] + (next - table[index]) * frac
+}
+
+/// Length of the single-cycle table a harmonic spectrum is rendered into.
+const HARMONIC_TABLE_LEN: usize = 2048;
+
+/// `(harmonic number, gain)` for each non-zero partial, scaled so the peaks
+/// sum to at most 1.0 and the additive mix can't clip.
+fn normalized_partials(partials: &[f32]) -> impl Iterator<Item = (f32, f32)> + '_ {
+    let total: f32 = partials.iter().map(|amp| amp.abs()).sum();
+    let norm = if total > 1.0 { 1.0 / total } else { 1.0 };
+    partials
+        .iter()
+        .enumerate()
+        .filter(|(_, amp)| **amp != 0.0)
+        .map(move |(i, amp)| ((i + 1) as f32, amp * norm))
+}
+
+/// Additive oscillator: input 0 is the frequency in Hz, output is a sine at
+/// each harmonic of it, weighted by its entry in `partials`. No partials
+/// means silence.
+pub fn harmonic_osc(partials: &[f32]) -> Net {
+    let mut mix = Net::wrap(Box::new(sink() | zero()));
+    for (n, gain) in normalized_partials(partials) {
+        mix = mix & Net::wrap(Box::new(((pass() * n) >> sine()) * gain));
+    }
+    mix
+}
+
+/// One cycle of the same spectrum as [`harmonic_osc`], for oscillators that
+/// read a table (e.g. [`HardSync`]).
+pub fn harmonic_table(partials: &[f32]) -> Vec<f32> {
+    let mut table = vec![0.0; HARMONIC_TABLE_LEN];
+    for (n, gain) in normalized_partials(partials) {
+        for (i, sample) in table.iter_mut().enumerate() {
+            let phase = i as f32 / HARMONIC_TABLE_LEN as f32;
+            *sample += (phase * n * TAU).sin() * gain;
+        }
+    }
+    table
+}
+
+/// Wavetable oscillator: input 0 is the frequency in Hz, output is the
+/// table read at a wrapping phase accumulator.
+#[derive(Clone)]
+pub struct WavetableOsc {
+    table: Arc<Vec<f32>>,
+    phase: f32,
+    sample_duration: f32,
+}
+
+impl WavetableOsc {
+    pub fn new(table: Arc<Vec<f32>>) -> Self {
+        Self {
+            table,
+            phase: 0.0,
+            sample_duration: 1.0 / 44100.0,
+        }
+    }
+}
+
+impl AudioNode for WavetableOsc {
+    const ID: u64 = 0x6270_615f_7774_626c;
+    type Inputs = U1;
+    type Outputs = U1;
+
+    fn reset(&mut self) {
+        self.phase = 0.0;
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_duration = (1.0 / sample_rate) as f32;
+    }
+
+    fn tick(&mut self, input: &Frame<f32, Self::Inputs>) -> Frame<f32, Self::Outputs> {
+        let value = table_at(&self.table, self.phase);
+        self.phase = (self.phase + input[0] * self.sample_duration).rem_euclid(1.0);
+        [value].into()
+    }
+}
+
+/// Hard-synced oscillator pair.
+///
+/// A silent master runs at the frequency on input 0; a slave reading
+/// `waveform` runs at that frequency times the ratio on input 1 and
+/// restarts its cycle whenever the master completes one. Outputs the slave.
+/// The reset keeps the master's sub-sample overshoot, so the period stays
+/// exact.
+#[derive(Clone)]
+pub struct HardSync {
+    waveform: Waveform,
+    master_phase: f32,
+    slave_phase: f32,
+    sample_duration: f32,
+}
+
+impl HardSync {
+    pub fn new(waveform: Waveform) -> Self {
+        Self {
+            waveform,
+            master_phase: 0.0,
+            slave_phase: 0.0,
+            sample_duration: 1.0 / 44100.0,
+        }
+    }
+}
+
+impl AudioNode for HardSync {
+    const ID: u64 = 0x6270_615f_7379_6e63;
+    type Inputs = U2;
+    type Outputs = U1;
+
+    fn reset(&mut self) {
+        self.master_phase = 0.0;
+        self.slave_phase = 0.0;
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_duration = (1.0 / sample_rate) as f32;
+    }
+
+    fn tick(&mut self, input: &Frame<f32, Self::Inputs>) -> Frame<f32, Self::Outputs> {
+        let value = self.waveform.at(self.slave_phase);
+        let step = input[0].max(0.0) * self.sample_duration;
+        let ratio = input[1].max(0.0);
+        self.master_phase += step;
+        if self.master_phase >= 1.0 {
+            self.master_phase = self.master_phase.fract();
+            self.slave_phase = (self.master_phase * ratio).fract();
+        } else {
+            self.slave_phase = (self.slave_phase + step * ratio).fract();
+        }
+        [value].into()
+    }
+}
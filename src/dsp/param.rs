@@ -5,9 +5,15 @@ use fundsp::shared::Shared;
 /// Create one per parameter when building a DSP graph. The ECS sync systems
 /// call [`ParamHandle::set`] on the main thread; the audio thread reads the
 /// value through the [`fundsp::prelude::var`] node wired to the same [`Shared`].
+///
+/// When the graph smooths or glides the value, the target written by `set`
+/// and what the graph is outputting differ; a graph can report the latter
+/// through [`ParamHandle::output_monitor`], read back with
+/// [`ParamHandle::current`].
 #[derive(Clone)]
 pub struct ParamHandle {
     inner: Shared,
+    output: Option<Shared>,
     pub name: &'static str,
     pub min: f32,
     pub max: f32,
@@ -17,6 +23,7 @@ impl ParamHandle {
     pub fn new(name: &'static str, initial: f32, min: f32, max: f32) -> Self {
         Self {
             inner: Shared::new(initial),
+            output: None,
             name,
             min,
             max,
@@ -29,11 +36,27 @@ impl ParamHandle {
         self.inner.set_value(clamped);
     }
 
-    /// Get the current value.
+    /// Get the last value written by `set` (the target).
     pub fn get(&self) -> f32 {
         self.inner.value()
     }
 
+    /// Get the value the graph is currently outputting, e.g. partway through
+    /// a glide toward the target. Equal to [`ParamHandle::get`] unless the
+    /// graph reports its output through [`ParamHandle::output_monitor`].
+    pub fn current(&self) -> f32 {
+        self.output.as_ref().unwrap_or(&self.inner).value()
+    }
+
+    /// `Shared` for the graph to report the smoothed value into, via
+    /// `monitor(&shared, Meter::Sample)` after the smoothing node. Call while
+    /// building the graph, before the handle is cloned out to the ECS.
+    pub fn output_monitor(&mut self) -> Shared {
+        self.output
+            .get_or_insert_with(|| Shared::new(self.inner.value()))
+            .clone()
+    }
+
     /// Access the inner `Shared` for wiring into a FunDSP graph via `var(&shared)`.
     pub fn shared(&self) -> &Shared {
         &self.inner
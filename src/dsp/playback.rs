@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::dsp::param::ParamHandle;
+
+/// Smoothing applied to the pause gain so pausing doesn't click.
+const SMOOTHING: f32 = 0.005;
+
+/// Live pause gain of a graph's output stage, written by `playback_sync_system`.
+#[derive(Component)]
+pub struct PlaybackParams {
+    pub gain: ParamHandle,
+}
+
+/// Append a pause gain to a stereo graph. Starts unmuted.
+pub fn build_playback_stage(graph: Box<dyn AudioUnit>) -> (Box<dyn AudioUnit>, PlaybackParams) {
+    let gain = ParamHandle::new("playback", 1.0, 0.0, 1.0);
+
+    let stage = var(gain.shared()) >> follow(SMOOTHING) >> split::<U2>();
+    let net = Net::wrap(graph) * Net::wrap(Box::new(stage));
+
+    (Box::new(net), PlaybackParams { gain })
+}
@@ -0,0 +1,45 @@
+use fundsp::audiounit::AudioUnit;
+use fundsp::buffer::{BufferRef, BufferVec};
+use fundsp::MAX_BUFFER_SIZE;
+
+/// Render `seconds` of audio from a generator graph into memory.
+///
+/// Returns (samples, channels) where samples are interleaved frame by frame
+/// and channels is the graph's output count. The graph is rendered from its
+/// current state, so pass a freshly built graph to capture a one-shot from
+/// its start. Processing runs in `MAX_BUFFER_SIZE` blocks through a single
+/// reused `BufferVec`, the same way `ProceduralAudioDecoder` pulls samples.
+pub fn render_to_buffer(
+    graph: &mut dyn AudioUnit,
+    seconds: f32,
+    sample_rate: u32,
+) -> (Vec<f32>, u16) {
+    debug_assert_eq!(graph.inputs(), 0, "render_to_buffer expects a generator graph");
+
+    let ch = graph.outputs();
+    let frames = (seconds.max(0.0) * sample_rate as f32).round() as usize;
+
+    graph.set_sample_rate(sample_rate as f64);
+    graph.allocate();
+
+    let mut samples = Vec::with_capacity(frames * ch);
+    let mut output_buf = BufferVec::new(ch);
+    let input = BufferRef::empty();
+
+    let mut remaining = frames;
+    while remaining > 0 {
+        let size = remaining.min(MAX_BUFFER_SIZE);
+        graph.process(size, &input, &mut output_buf.buffer_mut());
+
+        // Interleave channels into the flat buffer.
+        for i in 0..size {
+            for c in 0..ch {
+                samples.push(output_buf.at_f32(c, i));
+            }
+        }
+
+        remaining -= size;
+    }
+
+    (samples, ch as u16)
+}
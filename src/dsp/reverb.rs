@@ -0,0 +1,118 @@
+use fundsp::prelude32::*;
+
+use crate::components::effect::Reverb;
+
+/// Early-reflection tap times for a `room_size` of 0.5, in milliseconds. The
+/// sides use different, unrelated times so the reflections decorrelate.
+const EARLY_TAPS_MS: [[f32; 6]; 2] = [
+    [7.0, 13.1, 19.7, 27.3, 35.9, 43.1],
+    [9.4, 15.7, 22.3, 29.9, 38.2, 46.6],
+];
+
+/// Level of each early tap, falling off with distance.
+const EARLY_GAINS: [f32; 6] = [0.5, 0.42, 0.35, 0.29, 0.24, 0.2];
+
+/// Longest early tap at any room size, in seconds, with some headroom.
+const MAX_EARLY_SECS: f32 = 0.08;
+
+/// Stereo reverb for a [`Reverb`]: the diffuse `reverb2_stereo` tail blended
+/// with discrete early reflections by `early_late_mix`, then widened or
+/// narrowed by `width`. Stereo in, fully wet stereo out.
+///
+/// At the default `early_late_mix` of 0.0 and `width` of 1.0 this is the bare
+/// tail.
+pub fn reverb_stereo(reverb: &Reverb) -> Net {
+    let tail = reverb2_stereo(
+        reverb.room_size,
+        reverb.decay_time,
+        reverb.damping,
+        1.0,
+        lowpole_hz(6000.0),
+    );
+    let early_mix = reverb.early_late_mix.clamp(0.0, 1.0);
+    let mut net = if early_mix > 0.0 {
+        let early = An(EarlyReflections::new(reverb.room_size));
+        Net::wrap(Box::new((tail * (1.0 - early_mix)) & (early * early_mix)))
+    } else {
+        Net::wrap(Box::new(tail))
+    };
+
+    let width = reverb.width.clamp(0.0, 2.0);
+    if width != 1.0 {
+        // Scale the side (difference) signal, leaving the mid untouched.
+        let mid_side = map(move |frame: &Frame<f32, U2>| -> (f32, f32) {
+            let mid = (frame[0] + frame[1]) * 0.5;
+            let side = (frame[0] - frame[1]) * 0.5 * width;
+            (mid + side, mid - side)
+        });
+        net = net >> Net::wrap(Box::new(mid_side));
+    }
+    net
+}
+
+/// Multi-tap early reflections: each channel is read back at a handful of
+/// short, fixed delays, spaced out further in bigger rooms.
+///
+/// Inputs and outputs are left and right; the output is fully wet.
+#[derive(Clone)]
+pub struct EarlyReflections {
+    lines: [Vec<f32>; 2],
+    write: usize,
+    sample_rate: f32,
+    /// Tap delays in seconds, per channel.
+    taps: [[f32; 6]; 2],
+}
+
+impl EarlyReflections {
+    /// Reflections for a room of `room_size` (0.0–1.0, as on [`Reverb`]).
+    pub fn new(room_size: f32) -> Self {
+        let scale = 0.5 + room_size.clamp(0.0, 1.0);
+        let mut reflections = Self {
+            lines: [Vec::new(), Vec::new()],
+            write: 0,
+            sample_rate: 44100.0,
+            taps: EARLY_TAPS_MS.map(|side| side.map(|ms| ms * 0.001 * scale)),
+        };
+        reflections.allocate();
+        reflections
+    }
+
+    /// Size both lines for `MAX_EARLY_SECS` at the current sample rate.
+    fn allocate(&mut self) {
+        let len = (MAX_EARLY_SECS * self.sample_rate) as usize + 1;
+        self.lines = [vec![0.0; len], vec![0.0; len]];
+        self.write = 0;
+    }
+}
+
+impl AudioNode for EarlyReflections {
+    const ID: u64 = 0x6270_615f_6561_726c;
+    type Inputs = U2;
+    type Outputs = U2;
+
+    fn reset(&mut self) {
+        for line in &mut self.lines {
+            line.fill(0.0);
+        }
+        self.write = 0;
+    }
+
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_rate = sample_rate as f32;
+        self.allocate();
+    }
+
+    fn tick(&mut self, input: &Frame<f32, Self::Inputs>) -> Frame<f32, Self::Outputs> {
+        let len = self.lines[0].len();
+        let mut output = [0.0; 2];
+        for (channel, out) in output.iter_mut().enumerate() {
+            self.lines[channel][self.write] = input[channel];
+            for (tap, gain) in self.taps[channel].iter().zip(EARLY_GAINS) {
+                let delay = std::cmp::min((tap * self.sample_rate) as usize, len - 1);
+                *out += self.lines[channel][(self.write + len - delay) % len] * gain;
+            }
+        }
+        self.write = (self.write + 1) % len;
+        output.into()
+    }
+}
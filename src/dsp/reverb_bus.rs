@@ -0,0 +1,156 @@
+use bevy::prelude::*;
+use fundsp::buffer::BufferVec;
+use fundsp::prelude32::*;
+use fundsp::MAX_BUFFER_SIZE;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::components::effect::Reverb;
+use crate::dsp::param::ParamHandle;
+use crate::dsp::reverb::reverb_stereo;
+
+/// Capacity of the bus ring, in stereo frames.
+const RING_FRAMES: usize = 16 * MAX_BUFFER_SIZE;
+/// How far ahead of the bus read position a new send starts writing, in
+/// frames. Absorbs the block-to-block jitter between decoders.
+const SEND_LATENCY: u64 = 2 * MAX_BUFFER_SIZE as u64;
+
+/// One shared stereo reverb that every [`ReverbSend`] source feeds.
+///
+/// Per-entity reverbs (`Reverb` on a synth, `reverb_mix` on presets) each run
+/// their own `reverb2_stereo`, which adds up quickly. Sends instead mix into
+/// this bus, played by a single source spawned the first time a send appears.
+/// `reverb` is read at that point; change it before spawning any sends.
+/// Its `mix` is unused, since the bus output is fully wet.
+///
+/// [`ReverbSend`]: crate::components::effect::ReverbSend
+#[derive(Resource)]
+pub struct ReverbBus {
+    pub reverb: Reverb,
+    pub(crate) input: ReverbBusInput,
+    pub(crate) player: Option<Entity>,
+}
+
+impl Default for ReverbBus {
+    fn default() -> Self {
+        Self {
+            reverb: Reverb {
+                room_size: 0.6,
+                decay_time: 2.0,
+                damping: 0.4,
+                mix: 1.0,
+                ..default()
+            },
+            input: ReverbBusInput::default(),
+            player: None,
+        }
+    }
+}
+
+/// Build the bus reverb: stereo in, fully wet stereo out.
+pub(crate) fn build_reverb_bus_graph(reverb: &Reverb) -> Box<dyn AudioUnit> {
+    Box::new(reverb_stereo(reverb))
+}
+
+/// Ring of stereo frames that sends sum into and the bus decoder drains.
+struct BusRing {
+    frames: Vec<[f32; 2]>,
+    /// Absolute index of the next frame the bus will read.
+    read: u64,
+    /// Set once the bus decoder starts pulling; sends are dropped before
+    /// that so nothing piles up unread.
+    running: bool,
+}
+
+/// Shared handle to the bus ring, cloned into every sending decoder.
+#[derive(Clone)]
+pub(crate) struct ReverbBusInput(Arc<Mutex<BusRing>>);
+
+impl Default for ReverbBusInput {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(BusRing {
+            frames: vec![[0.0; 2]; RING_FRAMES],
+            read: 0,
+            running: false,
+        })))
+    }
+}
+
+impl ReverbBusInput {
+    /// Only plain sample data sits behind the lock, so a panic elsewhere
+    /// can't leave it inconsistent.
+    fn lock(&self) -> MutexGuard<'_, BusRing> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Drain the next `frames` frames into the first two channels of `out`,
+    /// leaving silence behind for the sends to sum into (audio thread).
+    pub(crate) fn take(&self, frames: usize, out: &mut BufferVec) {
+        let mut ring = self.lock();
+        ring.running = true;
+        for i in 0..frames {
+            let slot = (ring.read as usize + i) % RING_FRAMES;
+            let [left, right] = std::mem::take(&mut ring.frames[slot]);
+            out.set_f32(0, i, left);
+            out.set_f32(1, i, right);
+        }
+        ring.read += frames as u64;
+    }
+}
+
+/// A decoder's connection to the bus: the live send level and where its
+/// next block lands in the ring.
+#[derive(Clone)]
+pub(crate) struct ReverbSendTap {
+    bus: ReverbBusInput,
+    level: Shared,
+    cursor: Option<u64>,
+}
+
+impl ReverbSendTap {
+    pub(crate) fn new(bus: ReverbBusInput, level: Shared) -> Self {
+        Self {
+            bus,
+            level,
+            cursor: None,
+        }
+    }
+
+    /// Sum one block of interleaved samples into the bus, scaled by the send
+    /// level. Mono blocks feed both bus channels (audio thread).
+    pub(crate) fn send(&mut self, block: &[f32], channels: usize) {
+        let level = self.level.value();
+        let mut ring = self.bus.lock();
+        let frames = block.len() / channels;
+        if !ring.running || level <= 0.0 || frames == 0 {
+            self.cursor = None;
+            return;
+        }
+
+        // Decoders advance in lockstep with the bus, so the cursor only needs
+        // resyncing when it starts or has drifted outside the ring window.
+        let cursor = match self.cursor {
+            Some(cursor)
+                if cursor >= ring.read
+                    && cursor + frames as u64 <= ring.read + RING_FRAMES as u64 =>
+            {
+                cursor
+            }
+            _ => ring.read + SEND_LATENCY,
+        };
+        for (i, frame) in block.chunks(channels).enumerate() {
+            let slot = (cursor as usize + i) % RING_FRAMES;
+            let left = frame[0];
+            let right = frame.get(1).copied().unwrap_or(left);
+            ring.frames[slot][0] += left * level;
+            ring.frames[slot][1] += right * level;
+        }
+        self.cursor = Some(cursor + frames as u64);
+    }
+}
+
+/// Live send level of an entity routed to the [`ReverbBus`], written by
+/// `reverb_send_sync_system`.
+#[derive(Component)]
+pub struct ReverbSendParams {
+    pub level: ParamHandle,
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::dsp::param::ParamHandle;
+
+/// Source RMS (about -12 dBFS) at which a target is ducked by the full
+/// `amount`; quieter sources duck it proportionally less.
+const FULL_DUCK_RMS: f32 = 0.25;
+/// Seconds for the dip to deepen when the source gets louder.
+const DUCK_ATTACK: f32 = 0.01;
+/// Seconds for the dip to recover once the source quietens.
+const DUCK_RELEASE: f32 = 0.3;
+
+/// Live ducking depth of a side-chained source (0.0 = full level, 1.0 =
+/// silent), written by `side_chain_system` from the source entity's level.
+#[derive(Component)]
+pub struct SideChainParams {
+    pub depth: ParamHandle,
+}
+
+impl SideChainParams {
+    /// Duck by `amount` (0.0–1.0) scaled by how loud the source is: no
+    /// change for a silent source, down to `1.0 - amount` at -12 dBFS RMS and
+    /// above.
+    pub fn set(&self, source_rms: f32, amount: f32) {
+        let depth = amount.clamp(0.0, 1.0) * (source_rms / FULL_DUCK_RMS).min(1.0);
+        self.depth.set(depth);
+    }
+}
+
+/// Append a side-chain ducking gain to a stereo graph. The dip follows the
+/// depth quickly as the source gets louder and slowly as it fades, so the
+/// ducked sound doesn't pump on every syllable or hit.
+pub fn build_side_chain_stage(graph: Box<dyn AudioUnit>) -> (Box<dyn AudioUnit>, SideChainParams) {
+    let params = SideChainParams {
+        depth: ParamHandle::new("side_chain_depth", 0.0, 0.0, 1.0),
+    };
+    let gain = dc(1.0) - (var(params.depth.shared()) >> afollow(DUCK_ATTACK, DUCK_RELEASE));
+    let stage = multipass::<U2>() * (gain >> split::<U2>());
+    let net = Net::wrap(graph) >> Net::wrap(Box::new(stage));
+
+    (Box::new(net), params)
+}
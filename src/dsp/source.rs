@@ -3,39 +3,736 @@ use bevy::prelude::*;
 use fundsp::audiounit::AudioUnit;
 use fundsp::buffer::{BufferRef, BufferVec};
 use fundsp::MAX_BUFFER_SIZE;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, sync_channel, Receiver, Sender, SyncSender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::components::mixer::MixBus;
+use crate::config::{AudioConfig, Mixer};
+use crate::dsp::denormal::FlushToZeroGuard;
+use crate::dsp::meter::{LevelMeter, TransientDetector, TransientTap};
+use crate::dsp::reverb_bus::{ReverbBusInput, ReverbSendTap};
+
+/// Fraction of the ceiling below which the limiter is transparent.
+const LIMIT_KNEE: f32 = 0.7;
+
+/// Consecutive all-zero blocks, once a one-shot graph has sounded, after
+/// which it is no longer processed: about 46 ms at the default block size.
+const SILENT_BLOCK_LIMIT: u32 = 32;
+
+/// Decoder options mirrored from [`AudioConfig`] and bus volumes mirrored
+/// from [`Mixer`], for the audio thread.
+///
+/// The plugin keeps one as a resource and shares it with every source's
+/// audio, and from there with its decoders, so changes reach sounds that
+/// are already playing. A source that is never played through the plugin
+/// keeps default settings of its own.
+#[derive(Resource, Clone, Default)]
+pub struct OutputSettings(Arc<SharedOutputSettings>);
+
+struct SharedOutputSettings {
+    denormal_protection: AtomicBool,
+    /// Master high-pass cutoff in Hz as `f32` bits; 0.0 disables it.
+    master_high_pass: AtomicU32,
+    /// Frames rendered per decoder block, at most `MAX_BUFFER_SIZE`.
+    block_size: AtomicUsize,
+    /// Soft limiter ceiling as `f32` bits; 0.0 disables it.
+    limit_ceiling: AtomicU32,
+    /// Gain of each `MixBus`, master included, as `f32` bits.
+    bus_gains: [AtomicU32; 4],
+}
+
+impl Default for SharedOutputSettings {
+    fn default() -> Self {
+        Self {
+            denormal_protection: AtomicBool::new(true),
+            master_high_pass: AtomicU32::new(20.0_f32.to_bits()),
+            block_size: AtomicUsize::new(MAX_BUFFER_SIZE),
+            limit_ceiling: AtomicU32::new(1.0_f32.to_bits()),
+            bus_gains: [const { AtomicU32::new(1.0_f32.to_bits()) }; 4],
+        }
+    }
+}
+
+impl OutputSettings {
+    /// Push decoder options from `AudioConfig` to the audio thread.
+    pub(crate) fn apply_audio_config(&self, config: &AudioConfig) {
+        let settings = &self.0;
+        let cutoff = config.master_high_pass.unwrap_or(0.0).max(0.0);
+        let ceiling = config.limit_ceiling.max(0.0);
+        let block_size = config.block_size.clamp(1, MAX_BUFFER_SIZE);
+        settings
+            .denormal_protection
+            .store(config.denormal_protection, Ordering::Relaxed);
+        settings.master_high_pass.store(cutoff.to_bits(), Ordering::Relaxed);
+        settings.limit_ceiling.store(ceiling.to_bits(), Ordering::Relaxed);
+        settings.block_size.store(block_size, Ordering::Relaxed);
+    }
+
+    /// Push bus volumes from `Mixer` to the audio thread.
+    pub(crate) fn apply_mixer(&self, mixer: &Mixer) {
+        for bus in MixBus::ALL {
+            let gain = (mixer.master * mixer.volume(bus)).max(0.0);
+            self.0.bus_gains[bus.index()].store(gain.to_bits(), Ordering::Relaxed);
+        }
+    }
+
+    /// Whether `other` is this same set of settings.
+    pub(crate) fn is_shared_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    fn denormal_protection(&self) -> bool {
+        self.0.denormal_protection.load(Ordering::Relaxed)
+    }
+
+    fn master_high_pass(&self) -> f32 {
+        f32::from_bits(self.0.master_high_pass.load(Ordering::Relaxed))
+    }
+
+    fn block_size(&self) -> usize {
+        self.0.block_size.load(Ordering::Relaxed)
+    }
+
+    fn limit_ceiling(&self) -> f32 {
+        f32::from_bits(self.0.limit_ceiling.load(Ordering::Relaxed))
+    }
+
+    /// Current gain of the bus stored in `mix_bus` (audio thread).
+    fn bus_gain(&self, mix_bus: &AtomicU8) -> f32 {
+        let bus = MixBus::from_index(mix_bus.load(Ordering::Relaxed) as usize);
+        f32::from_bits(self.0.bus_gains[bus.index()].load(Ordering::Relaxed))
+    }
+}
+
+/// Soft-limit `samples` in place: linear up to the knee, then `tanh`
+/// saturation that approaches but never exceeds `ceiling`.
+fn soft_limit(samples: &mut [f32], ceiling: f32) {
+    let knee = ceiling * LIMIT_KNEE;
+    let range = ceiling - knee;
+    for sample in samples {
+        let level = sample.abs();
+        if level > knee {
+            *sample = (knee + range * ((level - knee) / range).tanh()).copysign(*sample);
+        }
+    }
+}
+
+/// One-pole DC blocker per channel: `y[n] = x[n] - x[n-1] + r * y[n-1]`.
+#[derive(Default)]
+struct DcBlocker {
+    previous_in: Vec<f32>,
+    previous_out: Vec<f32>,
+}
+
+impl DcBlocker {
+    /// Filter every channel of interleaved `samples` in place, with the
+    /// -3 dB point near `cutoff_hz`.
+    fn process(&mut self, samples: &mut [f32], channels: usize, cutoff_hz: f32, sample_rate: u32) {
+        self.previous_in.resize(channels, 0.0);
+        self.previous_out.resize(channels, 0.0);
+        let r = (-std::f32::consts::TAU * cutoff_hz / sample_rate as f32).exp();
+        for frame in samples.chunks_mut(channels) {
+            for (ch, sample) in frame.iter_mut().enumerate() {
+                let x = *sample;
+                let y = x - self.previous_in[ch] + r * self.previous_out[ch];
+                self.previous_in[ch] = x;
+                self.previous_out[ch] = y;
+                *sample = y;
+            }
+        }
+    }
+}
 
 /// The `Asset` type bridging FunDSP audio graphs to Bevy's audio pipeline.
 ///
-/// Contains a FunDSP `AudioUnit` that generates samples on the audio thread.
-/// The `Mutex` is only contested once at decoder creation — the decoder then
-/// owns the unit exclusively.
+/// Contains either a FunDSP `AudioUnit` that generates samples on the audio
+/// thread, or a pre-rendered PCM buffer for baked one-shots. The `Mutex` is
+/// only contested once at decoder creation — the decoder then owns the unit
+/// exclusively. Graphs swapped in by [`ProceduralAudio::crossfade_to`] are
+/// cloned and allocated on the main thread and handed to each decoder over
+/// a channel, so the audio thread never locks, allocates a graph or drops one.
+///
+/// Decoders report their output level to a shared [`LevelMeter`], read on
+/// the main thread through [`ProceduralAudio::meter`]. If several sinks play
+/// the same asset at once, the meter reflects whichever decoded last.
+/// Likewise, a reverb send attached to the asset is cloned into each decoder,
+/// and every decoder follows the asset's mixer bus and restarts on
+/// [`ProceduralAudio::retrigger`].
 #[derive(Asset, TypePath)]
 pub struct ProceduralAudio {
-    graph: Arc<Mutex<Box<dyn AudioUnit>>>,
+    data: AudioData,
     sample_rate: u32,
     channels: u16,
+    meter: LevelMeter,
+    transients: TransientTap,
+    reverb_send: Option<ReverbSendTap>,
+    /// `MixBus` index, shared with decoders so bus changes apply live.
+    mix_bus: Arc<AtomicU8>,
+    /// The plugin's `OutputSettings`, shared with decoders.
+    output: OutputSettings,
+    /// Bumped by `retrigger`; decoders restart when it moves.
+    generation: Arc<AtomicU32>,
+    /// Hands `crossfade_to` graphs to running decoders.
+    swap: GraphSwap,
+    /// Playback rate as `f32` bits, shared with decoders.
+    rate: Arc<AtomicU32>,
+    /// Whether the graph stops running once it falls silent; see
+    /// [`ProceduralAudio::set_skip_silence`].
+    skip_silence: bool,
+}
+
+/// Swapped-out graphs a decoder can queue before it drops one itself.
+const RETIRED_GRAPHS: usize = 8;
+
+/// A graph for a decoder to crossfade to, with the fade length in frames.
+type GraphHandover = (Box<dyn AudioUnit>, usize);
+
+/// Hands graphs swapped in by [`ProceduralAudio::crossfade_to`] to running
+/// decoders, and takes back the ones they have finished with so they are
+/// dropped on the main thread too.
+struct GraphSwap {
+    /// One sender per decoder; the audio thread only ever `try_recv`s.
+    decoders: Mutex<Vec<Sender<GraphHandover>>>,
+    retired_tx: SyncSender<Box<dyn AudioUnit>>,
+    retired_rx: Mutex<Receiver<Box<dyn AudioUnit>>>,
+}
+
+impl Default for GraphSwap {
+    fn default() -> Self {
+        let (retired_tx, retired_rx) = sync_channel(RETIRED_GRAPHS);
+        Self {
+            decoders: Mutex::default(),
+            retired_tx,
+            retired_rx: Mutex::new(retired_rx),
+        }
+    }
+}
+
+impl GraphSwap {
+    /// Open a channel for a new decoder.
+    fn register(&self) -> Receiver<GraphHandover> {
+        self.collect();
+        let (tx, rx) = channel();
+        lock(&self.decoders).push(tx);
+        rx
+    }
+
+    /// Drop the graphs decoders have retired.
+    fn collect(&self) {
+        lock(&self.retired_rx).try_iter().for_each(drop);
+    }
+}
+
+/// Lock a mutex that is never left half-written by a panicking holder.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Hand a graph the decoder has finished with back to the main thread, or
+/// drop it here if the main thread hasn't kept up.
+fn retire(retired: &SyncSender<Box<dyn AudioUnit>>, graph: Box<dyn AudioUnit>) {
+    let _ = retired.try_send(graph);
+}
+
+enum AudioData {
+    /// Live DSP graph, cloned into each decoder.
+    Graph(Arc<Mutex<Box<dyn AudioUnit>>>),
+    /// Interleaved samples rendered ahead of time, shared by every decoder.
+    Baked {
+        samples: Arc<Vec<f32>>,
+        /// Interleaved index decoders start reading at; retriggers rewind
+        /// to 0.
+        start: usize,
+    },
+    /// The reverb bus: a stereo-input graph fed from the shared ring.
+    Bus {
+        input: ReverbBusInput,
+        graph: Arc<Mutex<Box<dyn AudioUnit>>>,
+    },
 }
 
 impl ProceduralAudio {
+    /// Play `graph`, a generator with one output per channel of `channels`.
+    ///
+    /// # Panics
+    ///
+    /// If the graph has inputs, or its output count is not `channels`.
     pub fn new(mut graph: Box<dyn AudioUnit>, sample_rate: u32, channels: u16) -> Self {
+        assert_eq!(graph.inputs(), 0, "ProceduralAudio graph must be a generator");
+        assert_eq!(
+            graph.outputs(),
+            channels as usize,
+            "ProceduralAudio graph outputs must match its channel count"
+        );
+        graph.set_sample_rate(sample_rate as f64);
+        graph.allocate();
+        Self {
+            data: AudioData::Graph(Arc::new(Mutex::new(graph))),
+            sample_rate,
+            channels,
+            meter: LevelMeter::default(),
+            transients: TransientTap::default(),
+            reverb_send: None,
+            mix_bus: Arc::default(),
+            output: OutputSettings::default(),
+            generation: Arc::default(),
+            swap: GraphSwap::default(),
+            rate: Arc::new(AtomicU32::new(1.0_f32.to_bits())),
+            skip_silence: false,
+        }
+    }
+
+    /// Play back pre-rendered interleaved samples instead of running a graph.
+    ///
+    /// Once the buffer is exhausted the decoder outputs silence, matching how
+    /// a live one-shot graph gates to zero until its entity is despawned.
+    pub fn baked(samples: Arc<Vec<f32>>, sample_rate: u32, channels: u16) -> Self {
+        Self {
+            data: AudioData::Baked { samples, start: 0 },
+            sample_rate,
+            channels,
+            meter: LevelMeter::default(),
+            transients: TransientTap::default(),
+            reverb_send: None,
+            mix_bus: Arc::default(),
+            output: OutputSettings::default(),
+            generation: Arc::default(),
+            swap: GraphSwap::default(),
+            rate: Arc::new(AtomicU32::new(1.0_f32.to_bits())),
+            skip_silence: false,
+        }
+    }
+
+    /// Play the reverb bus: `graph` takes the stereo mix of every send and
+    /// has one output per channel.
+    pub(crate) fn reverb_bus(
+        input: ReverbBusInput,
+        mut graph: Box<dyn AudioUnit>,
+        sample_rate: u32,
+        channels: u16,
+    ) -> Self {
+        debug_assert_eq!(graph.outputs(), channels as usize);
         graph.set_sample_rate(sample_rate as f64);
         graph.allocate();
         Self {
-            graph: Arc::new(Mutex::new(graph)),
+            data: AudioData::Bus {
+                input,
+                graph: Arc::new(Mutex::new(graph)),
+            },
             sample_rate,
             channels,
+            meter: LevelMeter::default(),
+            transients: TransientTap::default(),
+            reverb_send: None,
+            mix_bus: Arc::default(),
+            output: OutputSettings::default(),
+            generation: Arc::default(),
+            swap: GraphSwap::default(),
+            rate: Arc::new(AtomicU32::new(1.0_f32.to_bits())),
+            skip_silence: false,
+        }
+    }
+
+    /// Once the graph has produced sound and its output has then been exactly
+    /// zero for a run of blocks, stop processing it and emit silence directly
+    /// until it is retriggered. For one-shots, whose envelopes gate to zero
+    /// well before their entity is despawned. Only affects decoders created
+    /// afterwards.
+    pub(crate) fn set_skip_silence(&mut self) {
+        self.skip_silence = true;
+    }
+
+    /// Live output level of this source.
+    pub fn meter(&self) -> &LevelMeter {
+        &self.meter
+    }
+
+    /// Onsets detected in this source's output, shared by all its decoders.
+    pub fn transients(&self) -> &TransientTap {
+        &self.transients
+    }
+
+    /// Feed this source's output into the reverb bus. Only affects decoders
+    /// created afterwards.
+    pub(crate) fn set_reverb_send(&mut self, tap: ReverbSendTap) {
+        self.reverb_send = Some(tap);
+    }
+
+    /// Play through `bus`, including decoders that are already running.
+    pub(crate) fn set_mix_bus(&self, bus: MixBus) {
+        self.mix_bus.store(bus.index() as u8, Ordering::Relaxed);
+    }
+
+    /// The bus this source plays through.
+    pub(crate) fn mix_bus(&self) -> MixBus {
+        MixBus::from_index(self.mix_bus.load(Ordering::Relaxed) as usize)
+    }
+
+    /// The output settings this source's decoders follow.
+    pub(crate) fn output_settings(&self) -> &OutputSettings {
+        &self.output
+    }
+
+    /// Follow `settings` instead. Only affects decoders created afterwards.
+    pub(crate) fn set_output_settings(&mut self, settings: OutputSettings) {
+        self.output = settings;
+    }
+
+    /// A copy of a baked source, on the same bus and at the same rate but
+    /// with playback state of its own, whose decoders start `start_secs`
+    /// into the buffer. `None` unless the source is baked.
+    pub(crate) fn unshared(&self, start_secs: f32) -> Option<Self> {
+        let AudioData::Baked { samples, .. } = &self.data else {
+            return None;
+        };
+        let frames = (start_secs.max(0.0) * self.sample_rate as f32) as usize;
+        let mut audio = Self::baked(samples.clone(), self.sample_rate, self.channels);
+        audio.data = AudioData::Baked {
+            samples: samples.clone(),
+            start: (frames * self.channels as usize).min(samples.len()),
+        };
+        audio.set_mix_bus(self.mix_bus());
+        audio.set_output_settings(self.output.clone());
+        audio.rate.store(self.rate.load(Ordering::Relaxed), Ordering::Relaxed);
+        Some(audio)
+    }
+
+    /// Play `rate` times faster (and higher), including decoders that are
+    /// already running.
+    pub(crate) fn set_playback_rate(&self, rate: f32) {
+        self.rate.store(rate.clamp(0.25, 4.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Restart running decoders from t = 0 at their next block: graphs are
+    /// reset, baked buffers rewind.
+    pub fn retrigger(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Replace the live graph with `graph`, which must have the same channel
+    /// count. Running decoders play the old and new graphs together for
+    /// `secs`, fading one into the other at equal power, then drop the old
+    /// one; decoders created afterwards start on `graph`.
+    ///
+    /// Gives `graph` back when it can't be swapped in: the source is baked
+    /// or the reverb bus, or the graph's channels don't match.
+    pub fn crossfade_to(
+        &self,
+        mut graph: Box<dyn AudioUnit>,
+        secs: f32,
+    ) -> Result<(), Box<dyn AudioUnit>> {
+        let AudioData::Graph(shared) = &self.data else {
+            return Err(graph);
+        };
+        if graph.inputs() != 0 || graph.outputs() != self.channels as usize {
+            return Err(graph);
+        }
+        graph.set_sample_rate(self.sample_rate as f64);
+        graph.allocate();
+        let frames = (secs.max(0.0) * self.sample_rate as f32) as usize;
+        // Each running decoder gets its own copy; dropped ones are forgotten.
+        self.swap.collect();
+        lock(&self.swap.decoders).retain(|decoder| {
+            let mut copy = graph.clone();
+            copy.allocate();
+            decoder.send((copy, frames)).is_ok()
+        });
+        *lock(shared) = graph;
+        Ok(())
+    }
+}
+
+/// Clone a shared graph for a new decoder.
+fn clone_graph(graph: &Mutex<Box<dyn AudioUnit>>, sample_rate: u32) -> Box<dyn AudioUnit> {
+    // The graph is only cloned here, never mutated, so a panic in another
+    // holder can't leave it half-written: recover the guard.
+    let graph = graph.lock().unwrap_or_else(|poisoned| {
+        warn!("ProceduralAudio graph lock poisoned; recovering");
+        poisoned.into_inner()
+    });
+    let mut cloned = graph.clone();
+    cloned.set_sample_rate(sample_rate as f64);
+    cloned.allocate();
+    cloned
+}
+
+/// The graph a decoder is crossfading away from after a swap.
+struct GraphFade {
+    graph: Box<dyn AudioUnit>,
+    output_buf: BufferVec,
+    /// The old graph's interleaved block, mixed into the new one's.
+    block: Vec<f32>,
+    /// Frames of the crossfade played so far, out of `frames`.
+    frame: usize,
+    frames: usize,
+}
+
+impl GraphFade {
+    /// Render the old graph's next block and mix it into `buffer`, which
+    /// holds the new graph's. Returns whether the fade has finished.
+    fn mix(&mut self, buffer: &mut [f32], ch: usize) -> bool {
+        self.block.resize(buffer.len(), 0.0);
+        let input = BufferRef::empty();
+        process_graph(self.graph.as_mut(), &input, &mut self.output_buf, &mut self.block, ch);
+        let quarter = std::f32::consts::FRAC_PI_2;
+        for (new, old) in buffer.chunks_mut(ch).zip(self.block.chunks(ch)) {
+            let progress = (self.frame as f32 / self.frames as f32).min(1.0);
+            let (new_gain, old_gain) = (progress * quarter).sin_cos();
+            for (sample, old) in new.iter_mut().zip(old) {
+                *sample = *sample * new_gain + old * old_gain;
+            }
+            self.frame += 1;
+        }
+        self.frame >= self.frames
+    }
+}
+
+/// Where a decoder pulls its samples from.
+enum DecoderSource {
+    Graph {
+        graph: Box<dyn AudioUnit>,
+        /// Graphs swapped in by `crossfade_to`, ready to play.
+        swaps: Receiver<GraphHandover>,
+        /// Where finished graphs go to be dropped off the audio thread.
+        retired: SyncSender<Box<dyn AudioUnit>>,
+        /// FunDSP output buffer for block processing.
+        output_buf: BufferVec,
+        /// `None` when silence is never skipped.
+        silence: Option<SilenceSkip>,
+        /// Set while crossfading from a swapped-out graph.
+        fade: Option<GraphFade>,
+    },
+    Baked {
+        samples: Arc<Vec<f32>>,
+        /// Read position in `samples`.
+        cursor: usize,
+    },
+    Bus {
+        input: ReverbBusInput,
+        graph: Box<dyn AudioUnit>,
+        /// Stereo block drained from the bus ring.
+        input_buf: BufferVec,
+        output_buf: BufferVec,
+    },
+}
+
+impl DecoderSource {
+    /// Fill `buffer` with the next `buffer.len() / ch` frames.
+    fn render(&mut self, buffer: &mut [f32], ch: usize) {
+        let size = buffer.len() / ch;
+        match self {
+            DecoderSource::Graph {
+                graph,
+                output_buf,
+                silence,
+                fade,
+                retired,
+                ..
+            } => {
+                if silence.as_ref().is_some_and(SilenceSkip::finished) {
+                    buffer.fill(0.0);
+                    return;
+                }
+                process_graph(graph.as_mut(), &BufferRef::empty(), output_buf, buffer, ch);
+                if fade.as_mut().is_some_and(|fade| fade.mix(buffer, ch)) {
+                    if let Some(done) = fade.take() {
+                        retire(retired, done.graph);
+                    }
+                }
+                if let Some(silence) = silence {
+                    silence.update(buffer);
+                }
+            }
+            DecoderSource::Bus {
+                input,
+                graph,
+                input_buf,
+                output_buf,
+            } => {
+                input.take(size, input_buf);
+                process_graph(
+                    graph.as_mut(),
+                    &input_buf.buffer_ref(),
+                    output_buf,
+                    buffer,
+                    ch,
+                );
+            }
+            DecoderSource::Baked { samples, cursor } => {
+                // Copy the next block, padding with silence past the end.
+                let start = (*cursor).min(samples.len());
+                let end = (start + buffer.len()).min(samples.len());
+                let n = end - start;
+                buffer[..n].copy_from_slice(&samples[start..end]);
+                buffer[n..].fill(0.0);
+                *cursor = end;
+            }
+        }
+    }
+
+    /// Switch to the latest graph swapped in since the last block,
+    /// crossfading from the current one. Only live graphs can be swapped.
+    fn swap(&mut self, ch: usize) {
+        let DecoderSource::Graph {
+            graph,
+            swaps,
+            retired,
+            silence,
+            fade,
+            ..
+        } = self
+        else {
+            return;
+        };
+        // Swaps that arrived together skip straight to the last one.
+        let latest = swaps.try_iter().reduce(|older, newer| {
+            retire(retired, older.0);
+            newer
+        });
+        let Some((new, frames)) = latest else {
+            return;
+        };
+        let old = std::mem::replace(graph, new);
+        if let Some(previous) = fade.take() {
+            retire(retired, previous.graph);
+        }
+        if frames > 0 {
+            *fade = Some(GraphFade {
+                graph: old,
+                output_buf: BufferVec::new(ch),
+                block: Vec::new(),
+                frame: 0,
+                frames,
+            });
+        } else {
+            retire(retired, old);
+        }
+        if let Some(silence) = silence {
+            silence.restart();
+        }
+    }
+
+    /// Start over from t = 0. The reverb bus is shared and never restarts.
+    fn restart(&mut self) {
+        match self {
+            DecoderSource::Graph {
+                graph,
+                silence,
+                fade,
+                retired,
+                ..
+            } => {
+                graph.reset();
+                if let Some(done) = fade.take() {
+                    retire(retired, done.graph);
+                }
+                if let Some(silence) = silence {
+                    silence.restart();
+                }
+            }
+            DecoderSource::Baked { cursor, .. } => *cursor = 0,
+            DecoderSource::Bus { .. } => {}
         }
     }
 }
 
+/// Detects a one-shot graph that has finished, so the decoder can stop
+/// running it: it has sounded, with only all-zero blocks since. Leading
+/// silence before the first sound never counts.
+#[derive(Default)]
+struct SilenceSkip {
+    /// Whether the graph has output anything but zeros yet.
+    sounded: bool,
+    /// Consecutive all-zero blocks since it last sounded.
+    silent_blocks: u32,
+}
+
+impl SilenceSkip {
+    /// Whether the graph no longer needs processing.
+    fn finished(&self) -> bool {
+        self.silent_blocks >= SILENT_BLOCK_LIMIT
+    }
+
+    /// Note one rendered block.
+    fn update(&mut self, block: &[f32]) {
+        if block.iter().all(|s| *s == 0.0) {
+            if self.sounded {
+                self.silent_blocks += 1;
+            }
+        } else {
+            self.sounded = true;
+            self.silent_blocks = 0;
+        }
+    }
+
+    fn restart(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Resampler that plays a source faster or slower, shifting its pitch and
+/// timing together, so it works the same for any graph or baked buffer.
+#[derive(Default)]
+struct Varispeed {
+    /// Interleaved source frames not yet consumed.
+    pending: Vec<f32>,
+    /// Read position into `pending`, in frames.
+    position: f64,
+}
+
+impl Varispeed {
+    /// Whether no source frames are buffered, so the source can be read
+    /// directly again.
+    fn is_idle(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Fill `buffer` by reading `source` at `rate` source frames per output
+    /// frame, interpolating linearly.
+    fn render(&mut self, source: &mut DecoderSource, buffer: &mut [f32], rate: f32, ch: usize) {
+        for frame in buffer.chunks_mut(ch) {
+            let index = self.position as usize;
+            while (index + 2) * ch > self.pending.len() {
+                let start = self.pending.len();
+                self.pending.resize(start + MAX_BUFFER_SIZE * ch, 0.0);
+                source.render(&mut self.pending[start..], ch);
+            }
+            let frac = (self.position - index as f64) as f32;
+            for (c, sample) in frame.iter_mut().enumerate() {
+                let a = self.pending[index * ch + c];
+                let b = self.pending[(index + 1) * ch + c];
+                *sample = a + (b - a) * frac;
+            }
+            self.position += rate as f64;
+        }
+
+        // Drop what has been read past; keep the fractional position.
+        let consumed = (self.position as usize).min(self.pending.len() / ch);
+        self.pending.drain(..consumed * ch);
+        self.position -= consumed as f64;
+    }
+}
+
 /// Iterator that pulls samples from a FunDSP graph for rodio playback.
 pub struct ProceduralAudioDecoder {
-    graph: Box<dyn AudioUnit>,
+    source: DecoderSource,
     sample_rate: u32,
     channels: u16,
-    /// FunDSP output buffer for block processing.
-    output_buf: BufferVec,
+    meter: LevelMeter,
+    transients: TransientDetector,
+    reverb_send: Option<ReverbSendTap>,
+    mix_bus: Arc<AtomicU8>,
+    output: OutputSettings,
+    /// Bus gain at the end of the previous block; changes ramp from it so
+    /// volume moves don't click.
+    mix_gain: f32,
+    generation: Arc<AtomicU32>,
+    /// `generation` as of the last block.
+    seen_generation: u32,
+    rate: Arc<AtomicU32>,
+    varispeed: Varispeed,
+    dc_blocker: DcBlocker,
     /// Interleaved sample buffer for rodio.
     buffer: Vec<f32>,
     pos: usize,
@@ -45,20 +742,58 @@ pub struct ProceduralAudioDecoder {
 impl ProceduralAudioDecoder {
     fn fill_block(&mut self) {
         let ch = self.channels as usize;
-        let size = MAX_BUFFER_SIZE;
+        let size = self.output.block_size();
         self.buffer.resize(size * ch, 0.0);
 
-        let input = BufferRef::empty();
-        let mut output = self.output_buf.buffer_mut();
-        self.graph.process(size, &input, &mut output);
-
-        // Interleave channels into the flat buffer.
-        for i in 0..size {
-            let base = i * ch;
-            self.buffer[base] = output.at_f32(0, i);
-            if ch >= 2 {
-                self.buffer[base + 1] = output.at_f32(1, i);
+        let generation = self.generation.load(Ordering::Relaxed);
+        if generation != self.seen_generation {
+            self.seen_generation = generation;
+            self.source.restart();
+            self.varispeed = Varispeed::default();
+        }
+
+        self.source.swap(ch);
+
+        let rate = f32::from_bits(self.rate.load(Ordering::Relaxed));
+        {
+            let _flush_to_zero = self
+                .output
+                .denormal_protection()
+                .then(FlushToZeroGuard::new);
+            if rate == 1.0 && self.varispeed.is_idle() {
+                self.source.render(&mut self.buffer, ch);
+            } else {
+                self.varispeed.render(&mut self.source, &mut self.buffer, rate, ch);
+            }
+        }
+
+        let cutoff = self.output.master_high_pass();
+        if cutoff > 0.0 {
+            self.dc_blocker.process(&mut self.buffer, ch, cutoff, self.sample_rate);
+        }
+
+        // Final mixer gain, ramped across the block when the volume changes.
+        let gain = self.output.bus_gain(&self.mix_bus);
+        if gain != 1.0 || self.mix_gain != 1.0 {
+            let step = (gain - self.mix_gain) / size as f32;
+            for (i, frame) in self.buffer.chunks_mut(ch).enumerate() {
+                let frame_gain = self.mix_gain + step * (i + 1) as f32;
+                frame.iter_mut().for_each(|sample| *sample *= frame_gain);
             }
+            self.mix_gain = gain;
+        }
+
+        let ceiling = self.output.limit_ceiling();
+        if ceiling > 0.0 {
+            soft_limit(&mut self.buffer, ceiling);
+        }
+
+        let seconds = size as f32 / self.sample_rate as f32;
+        self.meter.update(&self.buffer, seconds);
+        self.transients.update(&self.buffer, seconds);
+
+        if let Some(send) = &mut self.reverb_send {
+            send.send(&self.buffer, ch);
         }
 
         self.total = size * ch;
@@ -66,6 +801,27 @@ impl ProceduralAudioDecoder {
     }
 }
 
+/// Run one block of `graph`, as many frames as `buffer` holds, and
+/// interleave its output into `buffer`.
+fn process_graph(
+    graph: &mut dyn AudioUnit,
+    input: &BufferRef,
+    output_buf: &mut BufferVec,
+    buffer: &mut [f32],
+    ch: usize,
+) {
+    let size = buffer.len() / ch;
+    let mut output = output_buf.buffer_mut();
+    graph.process(size, input, &mut output);
+
+    // Interleave channels into the flat buffer.
+    for (i, frame) in buffer.chunks_mut(ch).enumerate() {
+        for (c, sample) in frame.iter_mut().enumerate() {
+            *sample = output.at_f32(c, i);
+        }
+    }
+}
+
 impl Iterator for ProceduralAudioDecoder {
     type Item = f32;
 
@@ -103,16 +859,42 @@ impl bevy::audio::Decodable for ProceduralAudio {
     type Decoder = ProceduralAudioDecoder;
 
     fn decoder(&self) -> Self::Decoder {
-        let graph = self.graph.lock().expect("ProceduralAudio graph lock poisoned");
-        let mut cloned = graph.clone();
-        cloned.set_sample_rate(self.sample_rate as f64);
-        cloned.allocate();
         let ch = self.channels as usize;
+        let source = match &self.data {
+            AudioData::Graph(graph) => DecoderSource::Graph {
+                graph: clone_graph(graph, self.sample_rate),
+                swaps: self.swap.register(),
+                retired: self.swap.retired_tx.clone(),
+                output_buf: BufferVec::new(ch),
+                silence: self.skip_silence.then(SilenceSkip::default),
+                fade: None,
+            },
+            AudioData::Baked { samples, start } => DecoderSource::Baked {
+                samples: samples.clone(),
+                cursor: *start,
+            },
+            AudioData::Bus { input, graph } => DecoderSource::Bus {
+                input: input.clone(),
+                graph: clone_graph(graph, self.sample_rate),
+                input_buf: BufferVec::new(2),
+                output_buf: BufferVec::new(ch),
+            },
+        };
         ProceduralAudioDecoder {
-            graph: cloned,
+            source,
             sample_rate: self.sample_rate,
             channels: self.channels,
-            output_buf: BufferVec::new(ch),
+            meter: self.meter.clone(),
+            transients: TransientDetector::new(self.transients.clone()),
+            reverb_send: self.reverb_send.clone(),
+            mix_bus: self.mix_bus.clone(),
+            output: self.output.clone(),
+            mix_gain: self.output.bus_gain(&self.mix_bus),
+            generation: self.generation.clone(),
+            seen_generation: self.generation.load(Ordering::Relaxed),
+            rate: self.rate.clone(),
+            varispeed: Varispeed::default(),
+            dc_blocker: DcBlocker::default(),
             buffer: vec![0.0; MAX_BUFFER_SIZE * ch],
             pos: MAX_BUFFER_SIZE * ch, // force fill on first call
             total: MAX_BUFFER_SIZE * ch,
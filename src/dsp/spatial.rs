@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::dsp::param::ParamHandle;
+
+/// Live parameters of a spatialized source's output stage, written by
+/// `spatial_sync_system`.
+#[derive(Component)]
+pub struct SpatialParams {
+    pub left: ParamHandle,
+    pub right: ParamHandle,
+    /// Air absorption low-pass cutoff, present when the emitter enables it.
+    pub filter_cutoff: Option<ParamHandle>,
+}
+
+/// Append the spatial output stage to a stereo graph: an optional low-pass
+/// per channel followed by a gain per channel.
+///
+/// Returns (graph, params). Gains start at unity and the cutoff wide open so
+/// the first block plays unaltered until the sync system has run. Each
+/// handle's [`ParamHandle::current`] reads what the graph last applied.
+pub fn build_spatial_stage(
+    graph: Box<dyn AudioUnit>,
+    air_absorption: bool,
+) -> (Box<dyn AudioUnit>, SpatialParams) {
+    let mut left = ParamHandle::new("spatial_left", 1.0, 0.0, 1.0);
+    let mut right = ParamHandle::new("spatial_right", 1.0, 0.0, 1.0);
+
+    let mut net = Net::wrap(graph);
+
+    let filter_cutoff = if air_absorption {
+        let mut cutoff = ParamHandle::new("filter_cutoff", 20000.0, 20.0, 20000.0);
+        let cutoff_s = cutoff.shared().clone();
+        let cutoff_out = monitor(&cutoff.output_monitor(), Meter::Sample);
+        let filter = ((pass() | (var(&cutoff_s) >> cutoff_out.clone())) >> lowpole())
+            | ((pass() | (var(&cutoff_s) >> cutoff_out)) >> lowpole());
+        net = net >> Net::wrap(Box::new(filter));
+        Some(cutoff)
+    } else {
+        None
+    };
+
+    let left_out = monitor(&left.output_monitor(), Meter::Sample);
+    let right_out = monitor(&right.output_monitor(), Meter::Sample);
+    let gain = (pass() * (var(left.shared()) >> left_out))
+        | (pass() * (var(right.shared()) >> right_out));
+    net = net >> Net::wrap(Box::new(gain));
+
+    (
+        Box::new(net),
+        SpatialParams {
+            left,
+            right,
+            filter_cutoff,
+        },
+    )
+}
+
+/// Smoothing applied to occlusion changes, so a raycast flipping between
+/// frames doesn't zipper.
+const OCCLUSION_SMOOTHING: f32 = 0.02;
+/// Low-pass cutoff of a fully occluded source, in Hz.
+const OCCLUDED_HZ: f32 = 500.0;
+/// Level of a fully occluded source, in dB.
+const OCCLUDED_DB: f32 = -15.0;
+
+/// Live low-pass cutoff and gain of an occluded source's output stage,
+/// written by `occlusion_sync_system`.
+#[derive(Component)]
+pub struct OcclusionParams {
+    pub cutoff: ParamHandle,
+    pub gain: ParamHandle,
+}
+
+impl OcclusionParams {
+    /// Set the cutoff and gain for `amount` of occlusion (0.0–1.0): the
+    /// cutoff falls exponentially from 20 kHz to 500 Hz and the gain
+    /// linearly in dB to -15 dB.
+    pub fn set(&self, amount: f32) {
+        let amount = amount.clamp(0.0, 1.0);
+        self.cutoff.set(20000.0 * (OCCLUDED_HZ / 20000.0).powf(amount));
+        self.gain.set(db_amp(OCCLUDED_DB * amount));
+    }
+}
+
+/// Append the occlusion stage to a stereo graph: a low-pass and a gain on
+/// each channel, both smoothed.
+///
+/// Returns (graph, params), already set for an `amount` of occlusion. The
+/// handles' [`ParamHandle::current`] reads the smoothed cutoff and gain.
+pub fn build_occlusion_stage(
+    graph: Box<dyn AudioUnit>,
+    amount: f32,
+) -> (Box<dyn AudioUnit>, OcclusionParams) {
+    let mut params = OcclusionParams {
+        cutoff: ParamHandle::new("occlusion_cutoff", 20000.0, 20.0, 20000.0),
+        gain: ParamHandle::new("occlusion_gain", 1.0, 0.0, 1.0),
+    };
+    params.set(amount);
+    let cutoff_out = monitor(&params.cutoff.output_monitor(), Meter::Sample);
+    let gain_out = monitor(&params.gain.output_monitor(), Meter::Sample);
+    let (cutoff, gain) = (&params.cutoff, &params.gain);
+
+    let channel = || {
+        ((pass() | (var(cutoff.shared()) >> follow(OCCLUSION_SMOOTHING) >> cutoff_out.clone()))
+            >> lowpole())
+            * (var(gain.shared()) >> follow(OCCLUSION_SMOOTHING) >> gain_out.clone())
+    };
+    let net = Net::wrap(graph) >> Net::wrap(Box::new(channel() | channel()));
+
+    (Box::new(net), params)
+}
+
+/// Live pan position of a panned source, written by `pan_sync_system`.
+#[derive(Component)]
+pub struct PanParams {
+    pub pan: ParamHandle,
+}
+
+/// Append an equal-power pan to a stereo graph.
+///
+/// Left is scaled by cos and right by sin of the pan position mapped onto a
+/// quarter circle, so loudness stays constant as the sound moves across.
+pub fn build_pan_stage(graph: Box<dyn AudioUnit>, pan: f32) -> (Box<dyn AudioUnit>, PanParams) {
+    let mut pan = ParamHandle::new("pan", pan, -1.0, 1.0);
+    let pan_s = pan.shared().clone();
+    let pan_out = monitor(&pan.output_monitor(), Meter::Sample);
+
+    let stage = ((pass() | (var(&pan_s) >> pan_out))
+        >> map(|frame: &Frame<f32, U2>| -> f32 {
+            frame[0] * ((frame[1] + 1.0) * std::f32::consts::FRAC_PI_4).cos()
+        }))
+        | ((pass() | var(&pan_s))
+            >> map(|frame: &Frame<f32, U2>| -> f32 {
+                frame[0] * ((frame[1] + 1.0) * std::f32::consts::FRAC_PI_4).sin()
+            }));
+    let net = Net::wrap(graph) >> Net::wrap(Box::new(stage));
+
+    (Box::new(net), PanParams { pan })
+}
@@ -0,0 +1,132 @@
+use fundsp::audiounit::AudioUnit;
+use fundsp::net::{Net, NodeId, Source};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Channels every `build_*_graph` must output: presets are stereo, and the
+/// output stages fit them to the `AudioConfig` layout afterwards.
+pub const GRAPH_CHANNELS: usize = 2;
+
+/// A graph that would fail or misbehave once played.
+///
+/// Nodes are numbered in `Net` order, as in
+/// [`net_to_dot`](super::debug::net_to_dot), so a DOT dump of the same net
+/// shows where they sit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// The graph has inputs; a playable graph is a generator.
+    Inputs { found: usize },
+    /// The graph doesn't output [`GRAPH_CHANNELS`] channels.
+    Outputs { found: usize },
+    /// An input of a node is fed by nothing.
+    UnconnectedInput {
+        node: usize,
+        arity: (usize, usize),
+        channel: usize,
+    },
+    /// An output channel of the graph is fed by nothing.
+    UnconnectedOutput { channel: usize },
+    /// A node whose outputs never reach the graph's outputs.
+    Unreachable { node: usize, arity: (usize, usize) },
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Inputs { found } => {
+                write!(f, "graph has {found} inputs; it must be a generator")
+            }
+            Self::Outputs { found } => {
+                write!(f, "graph has {found} outputs; expected {GRAPH_CHANNELS}")
+            }
+            Self::UnconnectedInput {
+                node,
+                arity: (inputs, outputs),
+                channel,
+            } => write!(
+                f,
+                "input {channel} of node #{node} ({inputs} in / {outputs} out) is not connected"
+            ),
+            Self::UnconnectedOutput { channel } => {
+                write!(f, "graph output {channel} is not connected")
+            }
+            Self::Unreachable {
+                node,
+                arity: (inputs, outputs),
+            } => write!(
+                f,
+                "node #{node} ({inputs} in / {outputs} out) doesn't reach the graph outputs"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// Check the outer shape of a built graph: a generator with
+/// [`GRAPH_CHANNELS`] outputs. Boxed graphs hide their wiring, so this is all
+/// that can be checked without the `Net`.
+pub fn check_graph(graph: &dyn AudioUnit) -> Result<(), GraphError> {
+    if graph.inputs() != 0 {
+        return Err(GraphError::Inputs {
+            found: graph.inputs(),
+        });
+    }
+    if graph.outputs() != GRAPH_CHANNELS {
+        return Err(GraphError::Outputs {
+            found: graph.outputs(),
+        });
+    }
+    Ok(())
+}
+
+/// Check a `Net` before it is played: [`check_graph`]'s shape, then the
+/// wiring. Every node input and graph output must be fed, and every node
+/// must feed the outputs, directly or through other nodes.
+///
+/// FunDSP itself only finds these mistakes by panicking in `process`, or
+/// not at all: an unreached node silently drops its sound.
+pub fn check_net(net: &Net) -> Result<(), GraphError> {
+    check_graph(net)?;
+    let ids: Vec<NodeId> = net.ids().copied().collect();
+    let arity = |id: NodeId| (net.node(id).inputs(), net.node(id).outputs());
+
+    for (node, &id) in ids.iter().enumerate() {
+        for channel in 0..net.node(id).inputs() {
+            if matches!(net.source(id, channel), Source::Zero) {
+                return Err(GraphError::UnconnectedInput {
+                    node,
+                    arity: arity(id),
+                    channel,
+                });
+            }
+        }
+    }
+
+    // Walk back from the outputs to every node they depend on.
+    let mut reached = HashSet::new();
+    let mut pending = Vec::new();
+    for channel in 0..net.outputs() {
+        match net.output_source(channel) {
+            Source::Local(id, _) => pending.push(id),
+            Source::Global(_) => {}
+            Source::Zero => return Err(GraphError::UnconnectedOutput { channel }),
+        }
+    }
+    while let Some(id) = pending.pop() {
+        if reached.insert(id) {
+            for channel in 0..net.node(id).inputs() {
+                if let Source::Local(source, _) = net.source(id, channel) {
+                    pending.push(source);
+                }
+            }
+        }
+    }
+    match ids.iter().position(|id| !reached.contains(id)) {
+        Some(node) => Err(GraphError::Unreachable {
+            node,
+            arity: arity(ids[node]),
+        }),
+        None => Ok(()),
+    }
+}
@@ -1,20 +1,92 @@
 pub mod components;
+pub mod config;
 pub mod dsp;
+#[cfg(feature = "serde")]
+pub mod loader;
+pub mod pitch;
 pub mod plugin;
 pub mod presets;
+#[cfg(feature = "serde")]
+mod serde_util;
 pub mod systems;
 
 pub mod prelude {
-    pub use crate::components::effect::{Delay, Distortion, Reverb};
-    pub use crate::components::filter::{BandPass, HighPass, LowPass};
-    pub use crate::components::synth::{Amplitude, Frequency, OscillatorType, Synth};
+    pub use crate::components::arpeggiator::{ArpMode, Arpeggiator};
+    pub use crate::components::automation::{Automation, Interpolation, LoopMode};
+    pub use crate::components::burst::Burst;
+    pub use crate::components::effect::{
+        BassEnhancer, ConvolutionReverb, Delay, DelaySync, Distortion, DistortionShape, Eq3, Gate,
+        NoteDivision, Reverb, ReverbSend, RingMod,
+    };
+    pub use crate::components::fade::{Crossfade, Fade, StopAudioExt, StopRequest};
+    pub use crate::components::filter::{
+        AllPass, BandPass, Formant, HighPass, LowPass, Resonator, Vowel,
+    };
+    pub use crate::components::level::{AudioLevel, TransientDetect, TransientDetected};
+    pub use crate::components::lifetime::{AutoStopOnSilence, BakedOneShot, Retrigger};
+    pub use crate::components::mixer::{MixBus, SideChain};
+    pub use crate::components::playback::{Playback, PlaybackRate};
+    pub use crate::components::polyphony::{NoteEvent, NoteEvents, Polyphony};
+    pub use crate::components::sequencer::{PresetTrigger, Sequencer};
+    pub use crate::components::spatial::{DopplerShift, Occlusion, Pan, SpatialEmitter};
+    pub use crate::components::synth::{
+        Amplitude, Frequency, FrequencyFromTransform, HarmonicOsc, NamedParam, OscSync,
+        OscillatorType, StereoSynth, Synth, SynthBundle, SynthConfigHandle, TransformAxis,
+        Wavetable,
+    };
+    pub use crate::components::variation::{Jitter, Variation};
+    pub use crate::config::{AudioConfig, Mixer, ProcAudRng, SynthConfig, VoiceLimit};
+    pub use crate::dsp::bass::build_bass_enhancer_stage;
+    pub use crate::dsp::convolution::ImpulseResponse;
+    pub use crate::dsp::fade::{build_fade_stage, FadeParams};
+    pub use crate::dsp::graph_builder::{build_synth_graph, GraphSpec, SynthParams};
+    pub use crate::dsp::meter::{LevelMeter, TransientTap};
+    pub use crate::dsp::param::ParamHandle;
+    pub use crate::dsp::playback::{build_playback_stage, PlaybackParams};
+    pub use crate::dsp::render::render_to_buffer;
+    pub use crate::dsp::reverb_bus::{ReverbBus, ReverbSendParams};
+    pub use crate::dsp::side_chain::{build_side_chain_stage, SideChainParams};
     pub use crate::dsp::source::ProceduralAudio;
+    pub use crate::dsp::spatial::{
+        build_occlusion_stage, build_pan_stage, build_spatial_stage, OcclusionParams, PanParams,
+        SpatialParams,
+    };
+    #[cfg(feature = "serde")]
+    pub use crate::loader::SaveSynthConfigExt;
     pub use crate::plugin::BevyProcAudPlugin;
-    pub use crate::presets::arcane_attack::ArcaneAttack;
-    pub use crate::presets::blunt_impact::BluntImpact;
-    pub use crate::presets::ear_ringing::EarRinging;
-    pub use crate::presets::explosion::Explosion;
-    pub use crate::presets::heartbeat::Heartbeat;
-    pub use crate::presets::lightning::{LightningStrike, LightningZap};
-    pub use crate::presets::sword_slash::SwordSlash;
+    pub use crate::presets::alarm::{build_alarm_graph, Alarm, AlarmKind, AlarmParams};
+    pub use crate::presets::arcane_attack::{
+        build_arcane_attack_graph, ArcaneAttack, ArcaneAttackParams,
+    };
+    pub use crate::presets::bell::{build_bell_graph, Bell};
+    pub use crate::presets::blunt_impact::{build_blunt_impact_graph, BluntImpact};
+    pub use crate::presets::drum::{build_drum_graph, Drum, DrumKind};
+    pub use crate::presets::ear_ringing::{build_ear_ringing_graph, EarRinging, EarRingingParams};
+    pub use crate::presets::engine::{build_engine_graph, Engine, EngineParams};
+    pub use crate::presets::explosion::{build_explosion_graph, Explosion, ExplosionParams};
+    pub use crate::presets::fire::{build_fire_graph, Fire, FireParams};
+    pub use crate::presets::footstep::{build_footstep_graph, Footstep, SurfaceType};
+    pub use crate::presets::glass_break::{build_glass_break_graph, GlassBreak};
+    pub use crate::presets::gunshot::{build_gunshot_graph, Gunshot};
+    pub use crate::presets::heartbeat::{
+        build_heartbeat_graph, Heartbeat, HeartbeatParams, HeartbeatPulse, HeartbeatState,
+    };
+    pub use crate::presets::laser::{build_laser_graph, Laser};
+    pub use crate::presets::lightning::{
+        build_lightning_strike_graph, build_lightning_zap_graph, LightningStrike, LightningZap,
+    };
+    pub use crate::presets::metronome::{
+        build_metronome_graph, Metronome, MetronomeParams, MetronomeTick,
+    };
+    pub use crate::presets::pickup::{build_pickup_graph, Pickup};
+    pub use crate::presets::pluck::{build_pluck_graph, Pluck};
+    pub use crate::presets::power_up::{build_power_up_graph, PowerUp};
+    pub use crate::presets::rain::{build_rain_graph, Rain, RainParams};
+    pub use crate::presets::splash::{build_splash_graph, Splash};
+    pub use crate::presets::sword_slash::{build_sword_slash_graph, SwordSlash};
+    pub use crate::presets::teleport::{build_teleport_graph, Teleport};
+    pub use crate::presets::test_tone::{build_test_tone_graph, TestKind, TestTone};
+    pub use crate::presets::ui_blip::{build_ui_blip_graph, BlipKind, UiBlip};
+    pub use crate::presets::whoosh::{build_whoosh_graph, Whoosh};
+    pub use crate::presets::wind::{build_wind_graph, Wind, WindParams};
 }
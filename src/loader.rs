@@ -0,0 +1,87 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::config::SynthConfig;
+
+/// Loads [`SynthConfig`] assets from `.synth.ron` files.
+#[derive(Default, TypePath)]
+pub struct SynthConfigLoader;
+
+/// Failure to read or parse a `.synth.ron` file.
+#[derive(Debug)]
+pub enum SynthConfigLoaderError {
+    Io(std::io::Error),
+    Ron(ron::de::SpannedError),
+}
+
+impl fmt::Display for SynthConfigLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not read synth config: {e}"),
+            Self::Ron(e) => write!(f, "could not parse synth config: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SynthConfigLoaderError {}
+
+impl From<std::io::Error> for SynthConfigLoaderError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ron::de::SpannedError> for SynthConfigLoaderError {
+    fn from(e: ron::de::SpannedError) -> Self {
+        Self::Ron(e)
+    }
+}
+
+impl AssetLoader for SynthConfigLoader {
+    type Asset = SynthConfig;
+    type Settings = ();
+    type Error = SynthConfigLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["synth.ron"]
+    }
+}
+
+/// `EntityCommands` extension for saving a tweaked `Synth` as a preset.
+pub trait SaveSynthConfigExt {
+    /// Write the entity's current synth components to `path` as a
+    /// `.synth.ron` file (see [`SynthConfig::from_entity`]). Failures are
+    /// logged.
+    fn save_synth_config(&mut self, path: impl Into<PathBuf>) -> &mut Self;
+}
+
+impl SaveSynthConfigExt for EntityCommands<'_> {
+    fn save_synth_config(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        let path = path.into();
+        self.queue(move |entity: EntityWorldMut| {
+            let config = SynthConfig::from_entity(EntityRef::from(&entity));
+            let saved = config
+                .to_ron()
+                .map_err(|e| e.to_string())
+                .and_then(|ron| std::fs::write(&path, ron).map_err(|e| e.to_string()));
+            if let Err(e) = saved {
+                error!("could not save synth config to {}: {e}", path.display());
+            }
+        })
+    }
+}
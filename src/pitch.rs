@@ -0,0 +1,69 @@
+//! Conversions between MIDI note numbers, note names, and frequencies.
+//!
+//! Notes use scientific pitch notation with A4 = MIDI 69 = 440Hz and twelve
+//! equal-tempered semitones per octave, so middle C is C4 = MIDI 60.
+
+/// Frequency of A4, in Hz.
+pub const A4_HZ: f32 = 440.0;
+
+/// MIDI note number of A4.
+pub const A4_MIDI: u8 = 69;
+
+/// Note names within an octave, spelled with sharps.
+const NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// Frequency of MIDI note `note`, in Hz.
+pub fn midi_to_hz(note: u8) -> f32 {
+    A4_HZ * 2.0_f32.powf((note as f32 - A4_MIDI as f32) / 12.0)
+}
+
+/// MIDI note number of `hz`, fractional between semitones. Round it for
+/// the nearest note.
+pub fn hz_to_midi(hz: f32) -> f32 {
+    A4_MIDI as f32 + 12.0 * (hz / A4_HZ).log2()
+}
+
+/// MIDI note number of a note name such as `"A4"`, `"C#3"`, `"Bb2"`, or
+/// `"C-1"`.
+///
+/// The letter is case-insensitive and may be followed by any number of
+/// sharps (`#` or `♯`) or flats (`b` or `♭`), then the octave. Returns `None`
+/// for anything else, or for notes outside MIDI's 0–127.
+pub fn note_name_to_midi(name: &str) -> Option<u8> {
+    let mut chars = name.trim().chars();
+    let pitch_class: i32 = match chars.next()?.to_ascii_uppercase() {
+        'C' => 0,
+        'D' => 2,
+        'E' => 4,
+        'F' => 5,
+        'G' => 7,
+        'A' => 9,
+        'B' => 11,
+        _ => return None,
+    };
+    let rest = chars.as_str();
+    let octave_start = rest
+        .find(|c: char| c == '-' || c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (accidentals, octave) = rest.split_at(octave_start);
+    let mut shift = 0;
+    for accidental in accidentals.chars() {
+        shift += match accidental {
+            '#' | '♯' => 1,
+            'b' | '♭' => -1,
+            _ => return None,
+        };
+    }
+    let octave: i32 = octave.parse().ok()?;
+    let note = (octave + 1) * 12 + pitch_class + shift;
+    u8::try_from(note).ok().filter(|note| *note <= 127)
+}
+
+/// Name of MIDI note `note`, spelled with a sharp where needed (e.g.
+/// `"A4"`, `"C#3"`, `"C-1"`).
+pub fn midi_to_note_name(note: u8) -> String {
+    let octave = note as i32 / 12 - 1;
+    format!("{}{octave}", NAMES[note as usize % 12])
+}
@@ -1,44 +1,182 @@
 use bevy::audio::AddAudioSource;
 use bevy::prelude::*;
 
-use crate::dsp::source::ProceduralAudio;
+use crate::components::level::TransientDetected;
+use crate::config::{AudioConfig, Mixer, ProcAudRng, SynthConfig, VoiceLimit};
+use crate::dsp::bake::BakeCache;
+use crate::dsp::convolution::{ImpulseResponse, ImpulseResponseLoader};
+use crate::dsp::reverb_bus::ReverbBus;
+use crate::dsp::source::{OutputSettings, ProceduralAudio};
+#[cfg(feature = "serde")]
+use crate::loader::SynthConfigLoader;
+use crate::presets::heartbeat::HeartbeatPulse;
+use crate::presets::metronome::MetronomeTick;
 use crate::systems::build::{
-    arcane_attack_build_system, blunt_impact_build_system, ear_ringing_build_system,
-    explosion_build_system, graph_build_system, heartbeat_build_system,
-    lightning_strike_build_system, lightning_zap_build_system, sword_slash_build_system,
+    alarm_build_system, arcane_attack_build_system, bell_build_system, blunt_impact_build_system,
+    convolution_reload_system, drum_build_system, ear_ringing_build_system, engine_build_system,
+    explosion_build_system, fire_build_system, footstep_build_system, glass_break_build_system,
+    graph_build_system, gunshot_build_system, heartbeat_build_system, laser_build_system,
+    lightning_strike_build_system, lightning_zap_build_system, loudness_measure_system,
+    metronome_build_system, pickup_build_system, pluck_build_system, power_up_build_system,
+    rain_build_system, reverb_send_build_system, splash_build_system, sword_slash_build_system,
+    synth_config_system, teleport_build_system, test_tone_build_system, ui_blip_build_system,
+    whoosh_build_system, wind_build_system,
+};
+use crate::systems::lifecycle::{
+    audio_cleanup_system, fade_system, oneshot_lifetime_system, retrigger_system,
+    silence_stop_system, voice_limit_system,
+};
+use crate::systems::sync::{
+    alarm_sync_system, arcane_attack_sync_system, arpeggiator_system, audio_config_system,
+    audio_level_system, automation_system, burst_system, ear_ringing_sync_system,
+    engine_sync_system, explosion_sync_system, fire_sync_system, frequency_from_transform_system,
+    heartbeat_pulse_system, heartbeat_sync_system, metronome_sync_system, metronome_tick_system,
+    mix_bus_sync_system, mixer_sync_system, named_param_sync_system, note_event_system,
+    occlusion_sync_system, output_settings_system, pan_sync_system, param_sync_system,
+    playback_rate_sync_system, playback_sync_system, rain_sync_system, reverb_send_sync_system,
+    sequencer_system, side_chain_system, spatial_sync_system, transient_system, wind_sync_system,
 };
-use crate::systems::lifecycle::{audio_cleanup_system, oneshot_lifetime_system};
-use crate::systems::sync::{ear_ringing_sync_system, heartbeat_sync_system, param_sync_system};
 
 /// Main plugin for bevy_proc_aud.
 ///
 /// Registers the `ProceduralAudio` asset type and all build/sync/lifecycle systems.
-pub struct BevyProcAudPlugin;
+/// Add `BevyProcAudPlugin::default()`, or [`BevyProcAudPlugin::with_seed`] for
+/// reproducible randomness.
+#[derive(Default)]
+pub struct BevyProcAudPlugin {
+    seed: Option<u64>,
+}
+
+impl BevyProcAudPlugin {
+    /// Seed the [`ProcAudRng`] so a session's per-spawn randomness is the
+    /// same on every run.
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seed: Some(seed) }
+    }
+}
 
 impl Plugin for BevyProcAudPlugin {
     fn build(&self, app: &mut App) {
+        match self.seed {
+            Some(seed) => app.insert_resource(ProcAudRng::seeded(seed)),
+            None => app.init_resource::<ProcAudRng>(),
+        };
         app.add_audio_source::<ProceduralAudio>()
+            .init_resource::<BakeCache>()
+            .init_resource::<VoiceLimit>()
+            .init_resource::<AudioConfig>()
+            .init_resource::<ReverbBus>()
+            .init_resource::<Mixer>()
+            .init_resource::<OutputSettings>()
+            .init_asset::<SynthConfig>()
+            .init_asset::<ImpulseResponse>()
+            .init_asset_loader::<ImpulseResponseLoader>()
+            .add_message::<HeartbeatPulse>()
+            .add_message::<MetronomeTick>()
+            .add_message::<TransientDetected>()
+            .add_systems(Startup, loudness_measure_system)
             .add_systems(
                 Update,
                 (
-                    // Build systems (react to Added<T>).
-                    arcane_attack_build_system,
-                    graph_build_system,
-                    heartbeat_build_system,
-                    ear_ringing_build_system,
-                    sword_slash_build_system,
-                    blunt_impact_build_system,
-                    lightning_zap_build_system,
-                    lightning_strike_build_system,
-                    explosion_build_system,
+                    // Build systems for one-shot presets (react to Added<T>).
+                    (
+                        arcane_attack_build_system,
+                        sword_slash_build_system,
+                        blunt_impact_build_system,
+                        lightning_zap_build_system,
+                        lightning_strike_build_system,
+                        explosion_build_system,
+                        footstep_build_system,
+                        gunshot_build_system,
+                        ui_blip_build_system,
+                        pickup_build_system,
+                        laser_build_system,
+                        teleport_build_system,
+                        power_up_build_system,
+                        glass_break_build_system,
+                        whoosh_build_system,
+                        splash_build_system,
+                        bell_build_system,
+                        pluck_build_system,
+                        drum_build_system,
+                    ),
+                    // Build systems for synths and continuous presets (react to Added<T>).
+                    (
+                        synth_config_system.before(graph_build_system),
+                        convolution_reload_system.before(graph_build_system),
+                        note_event_system.after(graph_build_system),
+                        graph_build_system,
+                        heartbeat_build_system,
+                        ear_ringing_build_system,
+                        wind_build_system,
+                        rain_build_system,
+                        fire_build_system,
+                        engine_build_system,
+                        alarm_build_system,
+                        metronome_build_system,
+                        test_tone_build_system,
+                    ),
                     // Sync systems (react to Changed<T>).
-                    param_sync_system,
-                    heartbeat_sync_system,
-                    ear_ringing_sync_system,
+                    (
+                        (
+                            param_sync_system,
+                            frequency_from_transform_system.before(param_sync_system),
+                        ),
+                        named_param_sync_system,
+                        automation_system.after(param_sync_system),
+                        heartbeat_sync_system,
+                        heartbeat_pulse_system,
+                        ear_ringing_sync_system,
+                        (pan_sync_system, occlusion_sync_system),
+                        playback_sync_system,
+                        reverb_send_sync_system,
+                        spatial_sync_system,
+                        wind_sync_system,
+                        rain_sync_system,
+                        fire_sync_system,
+                        engine_sync_system,
+                        alarm_sync_system,
+                        (audio_level_system, transient_system, side_chain_system),
+                        audio_config_system,
+                        mixer_sync_system,
+                        (metronome_sync_system, metronome_tick_system),
+                        (arcane_attack_sync_system, explosion_sync_system),
+                    ),
+                    // Musical sequencing, feeding this frame's notes to the synths
+                    // and spawning sequenced hits and bursts for the build systems.
+                    (
+                        arpeggiator_system.before(note_event_system),
+                        sequencer_system,
+                        burst_system,
+                    ),
                     // Lifecycle.
-                    audio_cleanup_system,
-                    oneshot_lifetime_system,
+                    (
+                        audio_cleanup_system,
+                        oneshot_lifetime_system,
+                        fade_system,
+                        voice_limit_system,
+                        retrigger_system.before(oneshot_lifetime_system),
+                        silence_stop_system.after(audio_level_system),
+                    ),
                 ),
             );
+
+        // Bus sends, mixer buses, output settings and playback rates attach to
+        // the audio asset, so they must be routed after this frame's players
+        // exist and before Bevy creates their decoders. Its playback set is private, but runs
+        // after transform propagation.
+        app.add_systems(
+            PostUpdate,
+            (
+                reverb_send_build_system,
+                mix_bus_sync_system,
+                output_settings_system,
+                playback_rate_sync_system,
+            )
+                .before(TransformSystems::Propagate),
+        );
+
+        #[cfg(feature = "serde")]
+        app.init_asset_loader::<SynthConfigLoader>();
     }
 }
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::dsp::param::ParamHandle;
+
+/// Modulation pattern of an [`Alarm`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlarmKind {
+    /// Siren wailing smoothly between 600 Hz and 1.2 kHz.
+    #[default]
+    Sweep,
+    /// Alternating high/low notes (960 Hz / 770 Hz), like a European siren.
+    TwoTone,
+    /// A single 1 kHz tone switched on and off, like a timer or smoke alarm.
+    Pulse,
+}
+
+impl AlarmKind {
+    /// Tone frequency in Hz at `phase` (0.0–1.0) through one cycle.
+    fn frequency(self, phase: f32) -> f32 {
+        match self {
+            AlarmKind::Sweep => {
+                let swell = 0.5 - 0.5 * (phase * core::f32::consts::TAU).cos();
+                600.0 + 600.0 * swell
+            }
+            AlarmKind::TwoTone => {
+                if phase < 0.5 {
+                    960.0
+                } else {
+                    770.0
+                }
+            }
+            AlarmKind::Pulse => 1000.0,
+        }
+    }
+
+    /// Amplitude gate at `phase`. Edges are ramped over 2% of the cycle to
+    /// avoid clicks.
+    fn gate(self, phase: f32) -> f32 {
+        match self {
+            AlarmKind::Sweep | AlarmKind::TwoTone => 1.0,
+            AlarmKind::Pulse => {
+                let on = (phase / 0.02).min(1.0);
+                let off = ((0.5 - phase) / 0.02).clamp(0.0, 1.0);
+                on * off
+            }
+        }
+    }
+}
+
+/// Looping siren/alarm — emergencies, timers, warning systems.
+///
+/// Mutate `rate_hz` and `intensity` at runtime; the sync system pushes
+/// changes to the audio thread. `kind` is read once when the graph is built.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alarm {
+    /// Pitch and gate pattern.
+    pub kind: AlarmKind,
+    /// Modulation cycles per second (clamped to 0.05–20.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub rate_hz: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+}
+
+impl Default for Alarm {
+    fn default() -> Self {
+        Self {
+            kind: AlarmKind::Sweep,
+            rate_hz: 0.5,
+            intensity: 0.5,
+        }
+    }
+}
+
+/// Runtime handles stored alongside the Alarm entity.
+#[derive(Component)]
+pub struct AlarmParams {
+    pub rate: ParamHandle,
+    pub intensity: ParamHandle,
+}
+
+/// Build the alarm DSP graph and return (graph, params).
+///
+/// A `ramp` phasor driven by the live rate feeds both the pitch and gate
+/// curves of the selected kind. Unlike computing the phase from `t` in an
+/// `lfo`, the phasor integrates the rate, so changing it mid-cycle doesn't
+/// jump the pitch. The tone is a sine with a little low-passed square for bite.
+pub fn build_alarm_graph(alarm: &Alarm) -> (Box<dyn AudioUnit>, AlarmParams) {
+    let rate_param = ParamHandle::new("rate", alarm.rate_hz, 0.05, 20.0);
+    let intensity_param = ParamHandle::new("intensity", alarm.intensity, 0.0, 1.0);
+    let kind = alarm.kind;
+
+    let phase = var(rate_param.shared()) >> ramp();
+    let pitch = map(move |f: &Frame<f32, U1>| -> f32 { kind.frequency(f[0]) });
+    let gate = map(move |f: &Frame<f32, U1>| -> f32 { kind.gate(f[0]) });
+    let voice = (sine() * dc(0.7)) & ((square() >> lowpole_hz(3000.0)) * dc(0.15));
+
+    let tone = phase
+        >> ((pitch >> voice) ^ gate)
+        >> map(|f: &Frame<f32, U2>| -> f32 { f[0] * f[1] });
+    let level = var(intensity_param.shared()) * dc(0.35);
+    let graph = (tone * level) >> split::<U2>();
+
+    let boxed: Box<dyn AudioUnit> = Box::new(graph);
+
+    let params = AlarmParams {
+        rate: rate_param,
+        intensity: intensity_param,
+    };
+
+    (boxed, params)
+}
@@ -1,23 +1,44 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
 
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::dsp::param::ParamHandle;
+use crate::presets::common::{
+    ad_env, apply_reverb, attack_ramp, live_lowpass, stutter_gate, ReverbShape,
+};
+
 /// One-shot arcane/magic attack sound effect.
 ///
 /// Five layers: shimmering detuned sine cluster, crystalline sparkle,
 /// rising frequency sweep, ethereal noise wash, and inharmonic bell-like
 /// harmonic cluster. Duration ~0.7s.
 ///
-/// Spawn an entity with this component to trigger the sound.
+/// Spawn an entity with this component to trigger the sound. `lowpass` can
+/// be changed while it plays, unless the sound is a `BakedOneShot`.
 #[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArcaneAttack {
     /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
     /// Pitch multiplier (1.0 = standard, >1 = higher, <1 = deeper).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub pitch_shift: f32,
     /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub reverb_mix: f32,
     /// Low-pass filter cutoff in Hz applied to the whole output (20_000 = effectively off).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub lowpass: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
 }
 
 impl Default for ArcaneAttack {
@@ -27,16 +48,32 @@ impl Default for ArcaneAttack {
             pitch_shift: 1.0,
             reverb_mix: 0.3,
             lowpass: 20_000.0,
+            duration_scale: 1.0,
         }
     }
 }
 
-/// Build the arcane attack DSP graph. One-shot, no runtime params.
-pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
+/// Runtime handles stored alongside a live `ArcaneAttack` entity.
+#[derive(Component)]
+pub struct ArcaneAttackParams {
+    pub lowpass: ParamHandle,
+}
+
+/// Build the arcane attack DSP graph and return (graph, params).
+pub fn build_arcane_attack_graph(
+    aa: &ArcaneAttack,
+    mut jitter: Jitter,
+) -> (Box<dyn AudioUnit>, ArcaneAttackParams) {
     let int = aa.intensity;
-    let pitch = aa.pitch_shift;
+    let pitch = aa.pitch_shift * jitter.scale();
+    let stretch = aa.duration_scale.max(0.01) * jitter.scale();
+    let shimmer_int = int * jitter.scale();
+    let sparkle_int = int * jitter.scale();
+    let sweep_int = int * jitter.scale();
+    let wash_int = int * jitter.scale();
+    let cluster_int = int * jitter.scale();
     let reverb_mix = aa.reverb_mix;
-    let lowpass = aa.lowpass;
+    let lowpass = ParamHandle::new("lowpass", aa.lowpass, 20.0, 20_000.0);
 
     // --- Layer 1: Shimmer Core ---
     // 6 detuned sines in two clusters around 880Hz and 1320Hz with +/-5 cent detune.
@@ -45,14 +82,7 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
     // 5 cents ≈ multiply by 2^(5/1200) ≈ 1.002893
     let detune_up = 1.002893_f32;
     let detune_dn = 1.0 / detune_up;
-    let shimmer_env = lfo(move |t: f32| -> f32 {
-        if t > 0.55 {
-            return 0.0;
-        }
-        let attack = (t * 40.0).min(1.0);
-        let decay = (-t * 5.5).exp();
-        attack * decay * 0.15 * int
-    });
+    let shimmer_env = ad_env(40.0, 5.5, 0.55, stretch) * (0.15 * shimmer_int);
     let shimmer_layer = (sine_hz(base_a)
         + sine_hz(base_a * detune_up)
         + sine_hz(base_a * detune_dn)
@@ -66,16 +96,15 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
     // Bandpassed noise with granular stuttering envelope.
     let sparkle_center = 6000.0 * pitch;
     let sparkle_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         if t > 0.6 {
             return 0.0;
         }
-        let onset = (t * 80.0).min(1.0);
+        let onset = attack_ramp(t, 80.0);
         let decay = (-t * 4.5).exp();
         // Granular stuttering via multiplied sines
-        let s1 = (t * 73.0 * std::f32::consts::TAU).sin();
-        let s2 = (t * 113.0 * std::f32::consts::TAU).sin();
-        let stutter = (s1 * s2).max(0.0);
-        onset * decay * stutter * 0.25 * int
+        let stutter = stutter_gate(t, &[73.0, 113.0]);
+        onset * decay * stutter * 0.25 * sparkle_int
     });
     let sparkle_layer = (noise() >> bandpass_hz(sparkle_center, 2.0)) * sparkle_env;
 
@@ -84,6 +113,7 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
     let sweep_lo = 300.0 * pitch;
     let sweep_hi = 1800.0 * pitch;
     let sweep_freq = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         if t > 0.45 {
             return 0.0;
         }
@@ -92,12 +122,13 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
         sweep_lo + (sweep_hi - sweep_lo) * ratio
     });
     let sweep_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         if t > 0.45 {
             return 0.0;
         }
-        let attack = (t * 30.0).min(1.0);
+        let attack = attack_ramp(t, 30.0);
         let decay = (-(t - 0.35).max(0.0) * 20.0).exp() * attack;
-        decay * 0.12 * int
+        decay * 0.12 * sweep_int
     });
     // FM: modulate the sweep with a small sine vibrato
     let fm_mod = sine_hz(7.0 * pitch) * dc(30.0 * pitch);
@@ -108,6 +139,7 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
     let wash_lo = 200.0 * pitch;
     let wash_hi = 1200.0 * pitch;
     let wash_cutoff = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         if t > 0.6 {
             return wash_lo;
         }
@@ -116,14 +148,7 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
         let curve = (-(x - 0.4).powi(2) * 12.0).exp();
         wash_lo + (wash_hi - wash_lo) * curve
     });
-    let wash_env = lfo(move |t: f32| -> f32 {
-        if t > 0.6 {
-            return 0.0;
-        }
-        let attack = (t * 20.0).min(1.0);
-        let decay = (-t * 3.5).exp();
-        attack * decay * 0.30 * int
-    });
+    let wash_env = ad_env(20.0, 3.5, 0.6, stretch) * (0.30 * wash_int);
     let wash_layer = ((noise() | wash_cutoff) >> lowpole()) * wash_env;
 
     // --- Layer 5: Harmonic Cluster ---
@@ -133,14 +158,7 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
     let h3 = 2150.0 * pitch;
     let h4 = 2680.0 * pitch;
     let h5 = 3200.0 * pitch;
-    let cluster_env = lfo(move |t: f32| -> f32 {
-        if t > 0.4 {
-            return 0.0;
-        }
-        let attack = (t * 120.0).min(1.0);
-        let decay = (-t * 8.0).exp();
-        attack * decay * 0.08 * int
-    });
+    let cluster_env = ad_env(120.0, 8.0, 0.4, stretch) * (0.08 * cluster_int);
     let cluster_layer = (sine_hz(h1)
         + sine_hz(h2) * dc(0.8)
         + sine_hz(h3) * dc(0.6)
@@ -152,16 +170,15 @@ pub fn build_arcane_attack_graph(aa: &ArcaneAttack) -> Box<dyn AudioUnit> {
     // --- Mix, lowpass, and stereo ---
     let mono_mix =
         shimmer_layer + sparkle_layer + sweep_layer + wash_layer + cluster_layer;
-    // Two-pole lowpass for a steeper roll-off. 20kHz = effectively transparent.
-    let graph = (mono_mix >> lowpole_hz(lowpass) >> lowpole_hz(lowpass)) >> split::<U2>();
+    let graph = (mono_mix >> live_lowpass(&lowpass)) >> split::<U2>();
 
-    if reverb_mix > 0.001 {
-        let reverb = reverb2_stereo(0.5, 1.0, 0.7, 1.0, lowpole_hz(3500.0));
-        let dry = 1.0 - reverb_mix;
-        let wet = reverb_mix;
-        let mixed = (graph.clone() * dc((dry, dry))) + (graph >> reverb) * dc((wet, wet));
-        Box::new(mixed)
-    } else {
-        Box::new(graph)
-    }
+    let room = ReverbShape {
+        room_size: 0.5,
+        time: 1.0,
+        diffusion: 0.7,
+        cutoff_hz: 3500.0,
+    };
+    let boxed: Box<dyn AudioUnit> =
+        Box::new(apply_reverb(Net::wrap(Box::new(graph)), reverb_mix, room));
+    (boxed, ArcaneAttackParams { lowpass })
 }
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::ad_env;
+
+/// One-shot struck bell or chime — notifications, quest cues, church bells.
+/// Additive inharmonic sine partials, each with its own decay rate.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~3s then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bell {
+    /// Strike tone (the "prime" partial) in Hz.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub fundamental_hz: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Stretch applied to the partial ratios (0.0 = tuned bell, 0.1–0.3 =
+    /// increasingly clangorous, gong-like). Clamped to 0.0–1.0.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub inharmonicity: f32,
+}
+
+impl Default for Bell {
+    fn default() -> Self {
+        Self {
+            fundamental_hz: 440.0,
+            intensity: 0.7,
+            inharmonicity: 0.0,
+        }
+    }
+}
+
+/// Partials of a tuned bell as (ratio to prime, level, decay rate in 1/s):
+/// hum, prime, minor-third tierce, quint, nominal, and upper partials.
+/// Higher partials are quieter and die away sooner, leaving the hum.
+const PARTIALS: [(f32, f32, f32); 8] = [
+    (0.5, 0.6, 0.9),
+    (1.0, 1.0, 1.4),
+    (1.2, 0.6, 2.0),
+    (1.5, 0.4, 2.6),
+    (2.0, 0.7, 3.0),
+    (2.51, 0.3, 4.5),
+    (3.01, 0.25, 6.0),
+    (4.17, 0.15, 9.0),
+];
+
+/// Build the bell DSP graph. One-shot, no runtime params.
+///
+/// Generalizes the blunt impact's clang cluster: every partial gets its own
+/// envelope, and `inharmonicity` stretches the ratios as `ratio^(1 + x)`, so
+/// the hum drops and the upper partials spread further apart.
+pub fn build_bell_graph(bell: &Bell, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = bell.intensity;
+    let fundamental = bell.fundamental_hz.max(1.0) * jitter.scale();
+    let stretch = 1.0 + bell.inharmonicity.clamp(0.0, 1.0);
+    let ring = jitter.scale();
+
+    let norm: f32 = PARTIALS.iter().map(|(_, level, _)| level).sum();
+    let mut mix = Net::wrap(Box::new(zero()));
+
+    for (ratio, level, decay_rate) in PARTIALS {
+        let freq = fundamental * ratio.powf(stretch);
+        let level = level / norm * jitter.scale();
+
+        let env = ad_env(800.0, decay_rate, 3.0, ring) * (level * 0.5 * int);
+        mix = mix + Net::wrap(Box::new(sine_hz(freq) * env));
+    }
+
+    let graph = mix >> Net::wrap(Box::new(split::<U2>()));
+    Box::new(graph)
+}
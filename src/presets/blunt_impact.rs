@@ -1,51 +1,106 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
 
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+#[cfg(debug_assertions)]
+use crate::dsp::validate::check_net;
+use crate::presets::common::{ad_env, apply_reverb, velocity_response, ReverbShape};
+
 /// One-shot blunt impact sound effect — mace, hammer, or club striking a body.
 /// Three layers: impact crack, body thud, metallic clang.
 ///
 /// Spawn an entity with this component to trigger the sound.
 /// The sound plays for ~0.3s then goes silent.
 #[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BluntImpact {
     /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
+    /// How hard the blow lands (0.0–1.0), e.g. from the contact impulse.
+    /// Softer blows are quieter, duller and less metallic; 1.0 plays the
+    /// impact as configured.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub velocity: f32,
     /// Pitch multiplier (1.0 = normal, <1 = lower, >1 = higher). Use for variance.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub pitch_shift: f32,
     /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet). Adds cave-like ambience.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub reverb_mix: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+    /// Noise seed. The same seed reproduces the same waveform; defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
 }
 
 impl Default for BluntImpact {
     fn default() -> Self {
         Self {
             intensity: 0.8,
+            velocity: 1.0,
             pitch_shift: 1.0,
             reverb_mix: 0.0,
+            duration_scale: 1.0,
+            seed: rand::random(),
         }
     }
 }
 
+/// Body thud oscillators: two low sines an octave apart, one output.
+pub fn thud_layer(pitch: f32) -> Box<dyn AudioUnit> {
+    let thud_lo = 45.0 * pitch;
+    let thud_hi = 90.0 * pitch;
+    // `+` binds tighter than `>>`, so each oscillator needs its own parens.
+    Box::new((dc(thud_lo) >> sine()) + (dc(thud_hi) >> sine()))
+}
+
+/// Weapon clang oscillators: an inharmonic sine cluster, one output.
+pub fn clang_layer(pitch: f32) -> Box<dyn AudioUnit> {
+    let c1 = 780.0 * pitch;
+    let c2 = 1850.0 * pitch;
+    let c3 = 3100.0 * pitch;
+    let c4 = 4700.0 * pitch;
+    Box::new(
+        (dc(c1) >> sine())
+            + (dc(c2) >> sine())
+            + (dc(c3) >> sine())
+            + (dc(c4) >> sine()),
+    )
+}
+
 /// Build the blunt impact DSP graph. One-shot, no runtime params.
-pub fn build_blunt_impact_graph(bi: &BluntImpact) -> Box<dyn AudioUnit> {
-    let intensity = bi.intensity;
-    let pitch = bi.pitch_shift;
+pub fn build_blunt_impact_graph(bi: &BluntImpact, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let (gain, brightness) = velocity_response(bi.velocity);
+    let intensity = bi.intensity * gain;
+    let pitch = bi.pitch_shift * jitter.scale();
     let reverb_mix = bi.reverb_mix;
+    let seed = bi.seed;
+    let stretch = bi.duration_scale.max(0.01) * jitter.scale();
+    let crack_int = intensity * jitter.scale();
+    let thud_int = intensity * jitter.scale();
+    let clang_int = intensity * brightness * jitter.scale();
 
     let mut net = Net::new(0, 2);
 
     // --- Layer 1: Impact crack (punchy broadband noise burst) ---
-    let crack_cutoff = 5000.0 * pitch;
+    let crack_cutoff = 5000.0 * pitch * brightness;
     let crack_src_id = net.push(Box::new(noise() >> lowpole_hz(crack_cutoff)));
 
-    let crack_env_id = net.push(Box::new(lfo(move |t: f32| -> f32 {
-        if t > 0.1 {
-            return 0.0;
-        }
-        let attack = (t * 500.0).min(1.0);
-        let decay = (-t * 35.0).exp();
-        attack * decay * 0.5 * intensity
-    })));
+    let crack_env_id = net.push(Box::new(
+        ad_env(500.0 * brightness, 35.0, 0.1, stretch) * (0.5 * crack_int),
+    ));
 
     let crack_id = net.push(Box::new(map(|f: &Frame<f32, U2>| -> f32 {
         f[0] * f[1]
@@ -54,20 +109,11 @@ pub fn build_blunt_impact_graph(bi: &BluntImpact) -> Box<dyn AudioUnit> {
     net.connect(crack_env_id, 0, crack_id, 1);
 
     // --- Layer 2: Body thud (low-frequency weight) ---
-    let thud_lo = 45.0 * pitch;
-    let thud_hi = 90.0 * pitch;
-    let thud_src_id = net.push(Box::new(
-        dc(thud_lo) >> sine() + dc(thud_hi) >> sine(),
-    ));
+    let thud_src_id = net.push(thud_layer(pitch));
 
-    let thud_env_id = net.push(Box::new(lfo(move |t: f32| -> f32 {
-        if t > 0.15 {
-            return 0.0;
-        }
-        let attack = (t * 200.0).min(1.0);
-        let decay = (-t * 20.0).exp();
-        attack * decay * 0.35 * intensity
-    })));
+    let thud_env_id = net.push(Box::new(
+        ad_env(200.0 * brightness, 20.0, 0.15, stretch) * (0.35 * thud_int),
+    ));
 
     let thud_id = net.push(Box::new(map(|f: &Frame<f32, U2>| -> f32 {
         f[0] * f[1]
@@ -76,25 +122,11 @@ pub fn build_blunt_impact_graph(bi: &BluntImpact) -> Box<dyn AudioUnit> {
     net.connect(thud_env_id, 0, thud_id, 1);
 
     // --- Layer 3: Weapon clang (inharmonic sine cluster) ---
-    let c1 = 780.0 * pitch;
-    let c2 = 1850.0 * pitch;
-    let c3 = 3100.0 * pitch;
-    let c4 = 4700.0 * pitch;
-    let clang_src_id = net.push(Box::new(
-        dc(c1) >> sine()
-            + dc(c2) >> sine()
-            + dc(c3) >> sine()
-            + dc(c4) >> sine(),
-    ));
+    let clang_src_id = net.push(clang_layer(pitch));
 
-    let clang_env_id = net.push(Box::new(lfo(move |t: f32| -> f32 {
-        if t > 0.2 {
-            return 0.0;
-        }
-        let attack = (t * 500.0).min(1.0);
-        let decay = (-t * 18.0).exp();
-        attack * decay * 0.08 * intensity
-    })));
+    let clang_env_id = net.push(Box::new(
+        ad_env(500.0 * brightness, 18.0, 0.2, stretch) * (0.08 * clang_int),
+    ));
 
     let clang_id = net.push(Box::new(map(|f: &Frame<f32, U2>| -> f32 {
         f[0] * f[1]
@@ -115,14 +147,19 @@ pub fn build_blunt_impact_graph(bi: &BluntImpact) -> Box<dyn AudioUnit> {
     net.connect_output(split_id, 0, 0);
     net.connect_output(split_id, 1, 1);
 
-    if reverb_mix > 0.001 {
-        let reverb = reverb2_stereo(0.4, 0.8, 0.5, 1.0, lowpole_hz(4000.0));
-        let dry = 1.0 - reverb_mix;
-        let wet = reverb_mix;
-        // dry/wet crossfade: stack dry + reverbed, mix per channel
-        let mixed = (net.clone() * dc((dry, dry))) + (net >> reverb) * dc((wet, wet));
-        Box::new(mixed)
-    } else {
-        Box::new(net)
+    let room = ReverbShape {
+        room_size: 0.4,
+        time: 0.8,
+        diffusion: 0.5,
+        cutoff_hz: 4000.0,
+    };
+    let net = apply_reverb(net, reverb_mix, room);
+    #[cfg(debug_assertions)]
+    if let Err(error) = check_net(&net) {
+        panic!("build_blunt_impact_graph wired an invalid net: {error}");
     }
+    let mut boxed: Box<dyn AudioUnit> = Box::new(net);
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    boxed
 }
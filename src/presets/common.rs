@@ -0,0 +1,130 @@
+use fundsp::prelude32::*;
+use std::f32::consts::TAU;
+
+use crate::dsp::param::ParamHandle;
+
+/// Shortest attack any preset envelope ramps over, in seconds. Starting a
+/// layer at full level clicks whenever its waveform isn't at zero at t = 0.
+pub const MIN_ATTACK_SECS: f32 = 0.001;
+
+/// Linear attack from 0 at `t = 0` to 1 after `1 / rate` seconds, held at 1
+/// afterwards. Rates above `1 / MIN_ATTACK_SECS` are slowed to it, so the
+/// ramp always lasts at least `MIN_ATTACK_SECS`.
+pub fn attack_ramp(t: f32, rate: f32) -> f32 {
+    (t * rate.min(1.0 / MIN_ATTACK_SECS)).min(1.0)
+}
+
+/// [`attack_ramp`] followed by an exponential decay of `exp(-t * decay)`,
+/// the shape of nearly every percussive preset layer.
+pub fn attack_decay(t: f32, attack_rate: f32, decay: f32) -> f32 {
+    attack_ramp(t, attack_rate) * (-t * decay).exp()
+}
+
+/// Pseudo-random gating from the product of sines at `rates` (Hz),
+/// half-wave rectified. Incommensurate rates never line up, so the result
+/// stutters like arcing, crackling, or debris. Ranges over 0–1.
+pub fn stutter_gate(t: f32, rates: &[f32]) -> f32 {
+    rates
+        .iter()
+        .map(|rate| (t * rate * TAU).sin())
+        .product::<f32>()
+        .max(0.0)
+}
+
+/// Envelope node for a percussive layer: [`attack_decay`] over time divided
+/// by `stretch` (1.0 = as written, as from a `Variation`), silent from
+/// `length` seconds of that stretched time on.
+pub fn ad_env(
+    attack_rate: f32,
+    decay: f32,
+    length: f32,
+    stretch: f32,
+) -> An<impl AudioNode<Inputs = U0, Outputs = U1>> {
+    lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > length {
+            return 0.0;
+        }
+        attack_decay(t, attack_rate, decay)
+    })
+}
+
+/// Envelope node for a crackling layer: [`stutter_gate`] at `rates` under an
+/// exponential decay of `exp(-t * decay)`, with time and `length` as in
+/// [`ad_env`].
+pub fn stutter_env<const N: usize>(
+    rates: [f32; N],
+    decay: f32,
+    length: f32,
+    stretch: f32,
+) -> An<impl AudioNode<Inputs = U0, Outputs = U1>> {
+    lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > length {
+            return 0.0;
+        }
+        stutter_gate(t, &rates) * (-t * decay).exp()
+    })
+}
+
+/// Two-pole low-pass node whose cutoff (Hz) is read live from `cutoff`, for
+/// a preset's `lowpass` field: a steeper roll-off than one pole, and
+/// effectively transparent at 20 kHz.
+pub fn live_lowpass(cutoff: &ParamHandle) -> An<impl AudioNode<Inputs = U1, Outputs = U1>> {
+    (((pass() | var(cutoff.shared())) >> lowpole()) | var(cutoff.shared())) >> lowpole()
+}
+
+/// Gain and brightness for a one-shot struck at `velocity` (0.0–1.0, clamped),
+/// such as a physics contact impulse scaled to that range. Gain falls with
+/// the square of velocity; brightness, a multiplier for filter cutoffs and
+/// attack rates, falls to 0.25 so soft hits are duller and rounder. Both are
+/// 1.0 at full velocity, leaving the preset as configured.
+pub fn velocity_response(velocity: f32) -> (f32, f32) {
+    let velocity = velocity.clamp(0.0, 1.0);
+    (velocity * velocity, 0.25 + 0.75 * velocity)
+}
+
+/// Room a preset's reverb models: arguments to `reverb2_stereo`, with the
+/// tail low-passed at `cutoff_hz`.
+#[derive(Debug, Clone, Copy)]
+pub struct ReverbShape {
+    pub room_size: f32,
+    pub time: f32,
+    pub diffusion: f32,
+    pub cutoff_hz: f32,
+}
+
+/// Crossfade the stereo `graph` with itself through the reverb `shape`:
+/// `mix` 0.0 is fully dry, 1.0 fully wet. Mixes that round to dry return
+/// `graph` unchanged, sparing the reverb's CPU.
+pub fn apply_reverb(graph: Net, mix: f32, shape: ReverbShape) -> Net {
+    if mix <= 0.001 {
+        return graph;
+    }
+    let reverb = reverb2_stereo(
+        shape.room_size,
+        shape.time,
+        shape.diffusion,
+        1.0,
+        lowpole_hz(shape.cutoff_hz),
+    );
+    let dry = 1.0 - mix;
+    (graph.clone() * dc((dry, dry))) + (graph >> reverb) * dc((mix, mix))
+}
+
+/// [`apply_reverb`] with the mix read live from `mix`, for continuous
+/// presets. The reverb always runs, so the mix can be raised from 0.0 later;
+/// changes are smoothed over ~20ms, and [`ParamHandle::current`] reads the
+/// smoothed mix.
+pub fn apply_live_reverb(graph: Net, mix: &mut ParamHandle, shape: ReverbShape) -> Net {
+    let reverb = reverb2_stereo(
+        shape.room_size,
+        shape.time,
+        shape.diffusion,
+        1.0,
+        lowpole_hz(shape.cutoff_hz),
+    );
+    let wet = var(mix.shared()) >> follow(0.02) >> monitor(&mix.output_monitor(), Meter::Sample);
+    let dry = (dc(1.0) - wet.clone()) >> split::<U2>();
+    (graph.clone() * dry) + (graph >> reverb) * (wet >> split::<U2>())
+}
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::ad_env;
+
+/// Which drum a [`Drum`] plays.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrumKind {
+    /// Bass drum: pitch-swept sine thump with a beater click.
+    #[default]
+    Kick,
+    /// Snare: two-partial shell tone plus band-passed noise rattle.
+    Snare,
+    /// Closed hi-hat: short burst of high band-passed noise.
+    HiHat,
+}
+
+impl DrumKind {
+    /// Length of the hit in seconds.
+    fn duration(self) -> f32 {
+        match self {
+            DrumKind::Kick => 0.6,
+            DrumKind::Snare => 0.35,
+            DrumKind::HiHat => 0.12,
+        }
+    }
+}
+
+/// One-shot drum hit — kicks, snares, and hats for rhythm games and music
+/// stingers.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for 0.12s (hat) to 0.6s (kick) then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Drum {
+    /// Which drum to hit.
+    pub kind: DrumKind,
+    /// Tuning multiplier (1.0 = standard kit, <1 = deeper, >1 = tighter).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Noise seed. The same seed reproduces the same waveform; defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
+}
+
+impl Default for Drum {
+    fn default() -> Self {
+        Self {
+            kind: DrumKind::default(),
+            pitch: 1.0,
+            intensity: 0.7,
+            seed: rand::random(),
+        }
+    }
+}
+
+impl Drum {
+    /// Total sound length in seconds.
+    pub fn duration(&self) -> f32 {
+        self.kind.duration()
+    }
+}
+
+/// Build the drum DSP graph. One-shot, no runtime params.
+pub fn build_drum_graph(dr: &Drum, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = dr.intensity;
+    let pitch = dr.pitch.max(0.1) * jitter.scale();
+    let seed = dr.seed;
+    let duration = dr.duration();
+    let stretch = jitter.scale();
+    // Main layer (boom, shell, hat) and secondary layer (click, rattle).
+    let body_int = int * jitter.scale();
+    let noise_int = int * jitter.scale();
+
+    let mix = match dr.kind {
+        DrumKind::Kick => {
+            // The explosion's boom envelope, tightened: a sine that drops from
+            // ~3.5x its resting pitch within a few tens of milliseconds.
+            let rest_hz = 55.0 * pitch;
+            let sweep = lfo(move |t: f32| -> f32 {
+                rest_hz * (1.0 + 2.5 * (-t / stretch * 35.0).exp())
+            });
+            let boom_env = ad_env(2000.0, 7.0, duration, stretch) * (0.8 * body_int);
+            let boom = (sweep >> sine()) * boom_env;
+
+            // Beater click: a couple of milliseconds of bright noise.
+            let click_env = lfo(move |t: f32| -> f32 {
+                let t = t / stretch;
+                if t > 0.003 {
+                    return 0.0;
+                }
+                (1.0 - t / 0.003) * 0.15 * noise_int
+            });
+            let click = (noise() >> highpole_hz(3000.0)) * click_env;
+            Net::wrap(Box::new(boom + click))
+        }
+        DrumKind::Snare => {
+            // Shell: two inharmonic drum-head modes, gone in ~0.15s.
+            let shell_env = ad_env(2000.0, 25.0, duration, stretch) * (0.35 * body_int);
+            let shell = (sine_hz(180.0 * pitch) + sine_hz(330.0 * pitch) * dc(0.6)) * shell_env;
+
+            // Rattle: snare wires buzzing, slower to die than the shell.
+            let rattle_env = ad_env(3000.0, 14.0, duration, stretch) * (0.6 * noise_int);
+            let rattle = (noise() >> bandpass_hz(3500.0 * pitch, 0.8)) * rattle_env;
+            Net::wrap(Box::new(shell + rattle))
+        }
+        DrumKind::HiHat => {
+            // Only the top octaves: band-pass then high-pass to thin the body.
+            let hat_env = ad_env(5000.0, 45.0, duration, stretch) * (0.7 * body_int);
+            let center = (9000.0 * pitch).min(16000.0);
+            let hat = (noise() >> bandpass_hz(center, 1.2) >> highpole_hz(center * 0.66)) * hat_env;
+            Net::wrap(Box::new(hat))
+        }
+    };
+
+    let graph = mix >> Net::wrap(Box::new(split::<U2>()));
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    boxed
+}
@@ -1,19 +1,33 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
 
+use crate::components::mixer::MixBus;
 use crate::dsp::param::ParamHandle;
+use crate::presets::common::{apply_live_reverb, ReverbShape};
 
 /// Ear ringing (tinnitus) preset — a cluster of high-frequency sine waves
 /// with slight detuning, creating a beating interference pattern.
 #[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EarRinging {
     /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
+    /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet). Live.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "crate::serde_util::finite")
+    )]
+    pub reverb_mix: f32,
 }
 
 impl Default for EarRinging {
     fn default() -> Self {
-        Self { intensity: 0.3 }
+        Self {
+            intensity: 0.3,
+            reverb_mix: 0.0,
+        }
     }
 }
 
@@ -21,6 +35,7 @@ impl Default for EarRinging {
 #[derive(Component)]
 pub struct EarRingingParams {
     pub intensity: ParamHandle,
+    pub reverb_mix: ParamHandle,
 }
 
 /// Build the ear ringing DSP graph and return (graph, params).
@@ -30,15 +45,16 @@ pub struct EarRingingParams {
 /// and stereo rotation to create a disorienting "head spinning" effect.
 pub fn build_ear_ringing_graph(er: &EarRinging) -> (Box<dyn AudioUnit>, EarRingingParams) {
     let intensity_param = ParamHandle::new("intensity", er.intensity, 0.0, 1.0);
+    let mut reverb_param = ParamHandle::new("reverb_mix", er.reverb_mix, 0.0, 1.0);
     let intensity_s = intensity_param.shared().clone();
 
     // Audio-rate tones: 3 detuned pairs creating beating interference.
-    let tones = (dc(4000.0) >> sine()
-        + dc(4015.0) >> sine()
-        + dc(5200.0) >> sine()
-        + dc(5230.0) >> sine()
-        + dc(6800.0) >> sine()
-        + dc(6790.0) >> sine())
+    let tones = ((dc(4000.0) >> sine())
+        + (dc(4015.0) >> sine())
+        + (dc(5200.0) >> sine())
+        + (dc(5230.0) >> sine())
+        + (dc(6800.0) >> sine())
+        + (dc(6790.0) >> sine()))
         * dc(1.0 / 6.0)
         * var(&intensity_s);
 
@@ -65,10 +81,19 @@ pub fn build_ear_ringing_graph(er: &EarRinging) -> (Box<dyn AudioUnit>, EarRingi
 
     let graph = stereo * (left_gain | right_gain);
 
-    let boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // A small, bright room: the ringing hangs in the air around the head.
+    let room = ReverbShape {
+        room_size: 0.4,
+        time: 1.5,
+        diffusion: 0.8,
+        cutoff_hz: 8000.0,
+    };
+    let boxed: Box<dyn AudioUnit> =
+        Box::new(apply_live_reverb(Net::wrap(Box::new(graph)), &mut reverb_param, room));
 
     let params = EarRingingParams {
         intensity: intensity_param,
+        reverb_mix: reverb_param,
     };
 
     (boxed, params)
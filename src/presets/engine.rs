@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::dsp::param::ParamHandle;
+
+/// Looping engine/motor — cars, boats, generators, drones.
+///
+/// Mutate fields at runtime; the sync system pushes changes to the audio thread,
+/// so a vehicle's speed can drive `rpm` every frame.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Engine {
+    /// Crankshaft speed in revolutions per minute (clamped to 300–12000).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub rpm: f32,
+    /// Throttle load (0.0 = coasting, 1.0 = full throttle). Adds roughness and brightness.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub load: f32,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Self {
+            rpm: 900.0,
+            load: 0.2,
+        }
+    }
+}
+
+/// Runtime handles stored alongside the Engine entity.
+#[derive(Component)]
+pub struct EngineParams {
+    pub rpm: ParamHandle,
+    pub load: ParamHandle,
+}
+
+/// Firing frequency in Hz at `rpm`: a four-stroke four-cylinder fires twice
+/// per revolution.
+fn firing_hz(rpm: f32) -> f32 {
+    rpm / 30.0
+}
+
+/// Build the engine DSP graph and return (graph, params).
+///
+/// Layers:
+/// - Tone: saw plus narrow pulse at the firing frequency, with a half-rate
+///   sine for the crank's lopsided rumble.
+/// - Roughness: noise band-passed around the 4th harmonic, scaled by `load`.
+///
+/// The mix goes through a low-pass that opens with load, so the engine gets
+/// brighter under throttle.
+pub fn build_engine_graph(engine: &Engine) -> (Box<dyn AudioUnit>, EngineParams) {
+    let rpm_param = ParamHandle::new("rpm", engine.rpm, 300.0, 12000.0);
+    let load_param = ParamHandle::new("load", engine.load, 0.0, 1.0);
+
+    let rpm_s = rpm_param.shared().clone();
+    let load_s = load_param.shared().clone();
+
+    let freq = |ratio: f32| {
+        let rpm = rpm_s.clone();
+        lfo(move |_t: f32| -> f32 { firing_hz(rpm.value()) * ratio })
+    };
+
+    // --- Layer 1: Tone ---
+    let tone = (freq(1.0) >> saw()) * dc(0.5)
+        + ((freq(1.0) | dc(0.2)) >> pulse()) * dc(0.3)
+        + (freq(0.5) >> sine()) * dc(0.4);
+
+    // --- Layer 2: Roughness ---
+    let rough_center = freq(4.0);
+    let rough_l = load_s.clone();
+    let rough_gain = lfo(move |_t: f32| -> f32 { 0.1 + 0.5 * rough_l.value() });
+    let roughness = ((noise() | rough_center | dc(1.0)) >> bandpass()) * rough_gain;
+
+    // --- Mix, load-dependent brightness, and stereo ---
+    let cutoff_l = load_s.clone();
+    let cutoff = lfo(move |_t: f32| -> f32 { 600.0 + 3400.0 * cutoff_l.value() });
+    let level_l = load_s.clone();
+    let level = lfo(move |_t: f32| -> f32 { 0.15 + 0.1 * level_l.value() });
+    let graph = ((((tone + roughness) | cutoff) >> lowpole()) * level) >> split::<U2>();
+
+    let boxed: Box<dyn AudioUnit> = Box::new(graph);
+
+    let params = EngineParams {
+        rpm: rpm_param,
+        load: load_param,
+    };
+
+    (boxed, params)
+}
@@ -1,25 +1,85 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
 
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::dsp::param::ParamHandle;
+use crate::presets::common::{ad_env, apply_reverb, live_lowpass, stutter_gate, ReverbShape};
+
 /// One-shot explosion / fireball sound effect.
 ///
-/// Five layers: initial broadband blast, sub-bass boom, mid-frequency body,
-/// pitch-swept "fireball whoosh", and high-frequency crackle tail.
+/// Six layers: initial broadband blast, tonal boom, sub-bass rumble,
+/// mid-frequency body, pitch-swept "fireball whoosh", and high-frequency
+/// crackle tail. Each layer has its own gain for rebalancing the mix.
 /// At low pitch_shift values it sounds like a deep detonation; at higher
 /// values the whoosh layer dominates for a fiery, swooshing fireball.
 /// Duration ~2s.
 ///
-/// Spawn an entity with this component to trigger the sound.
+/// Spawn an entity with this component to trigger the sound. `lowpass` can
+/// be changed while it plays, e.g. to muffle a blast as the listener ducks
+/// into cover, unless the sound is a `BakedOneShot`.
 #[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Explosion {
     /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
     /// Pitch multiplier (1.0 = standard explosion, >1 = fiery fireball, <1 = deep boom).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub pitch_shift: f32,
     /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub reverb_mix: f32,
     /// Low-pass filter cutoff in Hz applied to the whole output (20_000 = effectively off).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub lowpass: f32,
+    /// Gain of the initial blast layer (1.0 = standard mix).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub blast_gain: f32,
+    /// Gain of the tonal boom layer (1.0 = standard mix).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub boom_gain: f32,
+    /// Gain of the sub rumble layer (1.0 = standard mix).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub rumble_gain: f32,
+    /// Gain of the mid body layer (1.0 = standard mix).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub mid_gain: f32,
+    /// Gain of the fireball whoosh layer (1.0 = standard mix).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub whoosh_gain: f32,
+    /// Gain of the crackle tail layer (1.0 = standard mix).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub crackle_gain: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+    /// Noise seed. The same seed reproduces the same waveform; defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
 }
 
 impl Default for Explosion {
@@ -29,16 +89,41 @@ impl Default for Explosion {
             pitch_shift: 1.0,
             reverb_mix: 0.1,
             lowpass: 20_000.0,
+            blast_gain: 1.0,
+            boom_gain: 1.0,
+            rumble_gain: 1.0,
+            mid_gain: 1.0,
+            whoosh_gain: 1.0,
+            crackle_gain: 1.0,
+            duration_scale: 1.0,
+            seed: rand::random(),
         }
     }
 }
 
-/// Build the explosion DSP graph. One-shot, no runtime params.
-pub fn build_explosion_graph(ex: &Explosion) -> Box<dyn AudioUnit> {
+/// Runtime handles stored alongside a live `Explosion` entity.
+#[derive(Component)]
+pub struct ExplosionParams {
+    pub lowpass: ParamHandle,
+}
+
+/// Build the explosion DSP graph and return (graph, params).
+pub fn build_explosion_graph(
+    ex: &Explosion,
+    mut jitter: Jitter,
+) -> (Box<dyn AudioUnit>, ExplosionParams) {
     let int = ex.intensity;
-    let pitch = ex.pitch_shift;
+    let pitch = ex.pitch_shift * jitter.scale();
     let reverb_mix = ex.reverb_mix;
-    let lowpass = ex.lowpass;
+    let lowpass = ParamHandle::new("lowpass", ex.lowpass, 20.0, 20_000.0);
+    let seed = ex.seed;
+    let blast_gain = ex.blast_gain * jitter.scale();
+    let boom_gain = ex.boom_gain * jitter.scale();
+    let rumble_gain = ex.rumble_gain * jitter.scale();
+    let mid_gain = ex.mid_gain * jitter.scale();
+    let whoosh_gain = ex.whoosh_gain * jitter.scale();
+    let crackle_gain = ex.crackle_gain * jitter.scale();
+    let stretch = ex.duration_scale.max(0.01) * jitter.scale();
 
     // Decay speed scales with pitch: higher pitch = faster decay (small fireball),
     // lower pitch = slower decay (massive explosion).
@@ -46,53 +131,29 @@ pub fn build_explosion_graph(ex: &Explosion) -> Box<dyn AudioUnit> {
 
     // --- Layer 1: Initial blast (broadband transient) ---
     // Lowpassed noise burst — pitch controls how bright the crack is.
-    let blast_env = lfo(move |t: f32| -> f32 {
-        if t > 0.2 / decay_scale {
-            return 0.0;
-        }
-        let attack = (t * 5000.0).min(1.0);
-        let decay = (-t * 18.0 * decay_scale).exp();
-        attack * decay * 0.2 * int
-    });
+    let blast_env = ad_env(5000.0, 18.0 * decay_scale, 0.2 / decay_scale, stretch)
+        * (0.2 * int * blast_gain);
     let blast_layer = (noise() >> lowpole_hz(3000.0 * pitch)) * blast_env;
 
     // --- Layer 2: Tonal boom (pitched sine thump) ---
     // Low sine tone that shifts with pitch — subtle pitch cue under the noise.
     let boom_freq = 80.0 * pitch;
     let boom_harm = 130.0 * pitch;
-    let boom_env = lfo(move |t: f32| -> f32 {
-        if t > 2.5 / decay_scale {
-            return 0.0;
-        }
-        let attack = (t * 60.0).min(1.0);
-        let decay = (-t * 1.5 * decay_scale).exp();
-        attack * decay * 0.12 * int
-    });
+    let boom_env = ad_env(60.0, 1.5 * decay_scale, 2.5 / decay_scale, stretch)
+        * (0.12 * int * boom_gain);
     let boom_layer = (sine_hz(boom_freq) + sine_hz(boom_harm) * dc(0.5)) * boom_env;
 
     // --- Layer 3: Sub rumble (noise-based low end) ---
     let rumble_cutoff = 250.0 * pitch;
-    let rumble_env = lfo(move |t: f32| -> f32 {
-        if t > 3.0 / decay_scale {
-            return 0.0;
-        }
-        let attack = (t * 80.0).min(1.0);
-        let decay = (-t * 1.0 * decay_scale).exp();
-        attack * decay * 0.6 * int
-    });
+    let rumble_env = ad_env(80.0, 1.0 * decay_scale, 3.0 / decay_scale, stretch)
+        * (0.6 * int * rumble_gain);
     let rumble_layer =
         (noise() >> lowpole_hz(rumble_cutoff) >> lowpole_hz(rumble_cutoff)) * rumble_env;
 
     // --- Layer 4: Mid body ---
     let mid_cutoff = 800.0 * pitch;
-    let mid_env = lfo(move |t: f32| -> f32 {
-        if t > 1.5 / decay_scale {
-            return 0.0;
-        }
-        let attack = (t * 150.0).min(1.0);
-        let decay = (-t * 2.5 * decay_scale).exp();
-        attack * decay * 0.4 * int
-    });
+    let mid_env = ad_env(150.0, 2.5 * decay_scale, 1.5 / decay_scale, stretch)
+        * (0.4 * int * mid_gain);
     let mid_layer = (noise() >> lowpole_hz(mid_cutoff)) * mid_env;
 
     // --- Layer 5: Fireball whoosh (pitch-swept noise) ---
@@ -100,46 +161,48 @@ pub fn build_explosion_graph(ex: &Explosion) -> Box<dyn AudioUnit> {
     let whoosh_lo = 200.0 * pitch;
     let whoosh_src = noise();
     let whoosh_cutoff = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         whoosh_lo + (whoosh_hi - whoosh_lo) * (-t * 3.0 * decay_scale).exp()
     });
     let whoosh_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         if t > 1.5 / decay_scale {
             return 0.0;
         }
         let onset = ((t - 0.02) * 60.0).clamp(0.0, 1.0);
         let decay = (-t * 2.0 * decay_scale).exp();
-        onset * decay * 0.35 * int
+        onset * decay * 0.35 * int * whoosh_gain
     });
     let whoosh_layer = ((whoosh_src | whoosh_cutoff) >> lowpole()) * whoosh_env;
 
     // --- Layer 6: Crackle tail (debris/sparks) ---
     let crackle_bp = 5000.0 * pitch;
     let crackle_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         if t > 1.8 / decay_scale {
             return 0.0;
         }
         let onset = ((t - 0.05) * 20.0).clamp(0.0, 1.0);
-        let s1 = (t * 97.3 * std::f32::consts::TAU).sin();
-        let s2 = (t * 143.7 * std::f32::consts::TAU).sin();
-        let stutter = (s1 * s2).max(0.0);
+        let stutter = stutter_gate(t, &[97.3, 143.7]);
         let decay = (-t * 2.0 * decay_scale).exp();
-        onset * stutter * decay * 0.04 * int
+        onset * stutter * decay * 0.04 * int * crackle_gain
     });
     let crackle_layer = (noise() >> bandpass_hz(crackle_bp, 1.5)) * crackle_env;
 
     // --- Mix, lowpass, and stereo ---
     let mono_mix = blast_layer + boom_layer + rumble_layer + mid_layer + whoosh_layer
         + crackle_layer;
-    // Two-pole lowpass for a steeper roll-off. 20kHz = effectively transparent.
-    let graph = (mono_mix >> lowpole_hz(lowpass) >> lowpole_hz(lowpass)) >> split::<U2>();
+    let graph = (mono_mix >> live_lowpass(&lowpass)) >> split::<U2>();
 
-    if reverb_mix > 0.001 {
-        let reverb = reverb2_stereo(0.6, 1.5, 0.5, 1.0, lowpole_hz(2500.0));
-        let dry = 1.0 - reverb_mix;
-        let wet = reverb_mix;
-        let mixed = (graph.clone() * dc((dry, dry))) + (graph >> reverb) * dc((wet, wet));
-        Box::new(mixed)
-    } else {
-        Box::new(graph)
-    }
+    let room = ReverbShape {
+        room_size: 0.6,
+        time: 1.5,
+        diffusion: 0.5,
+        cutoff_hz: 2500.0,
+    };
+    let mut boxed: Box<dyn AudioUnit> =
+        Box::new(apply_reverb(Net::wrap(Box::new(graph)), reverb_mix, room));
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    (boxed, ExplosionParams { lowpass })
 }
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::dsp::param::ParamHandle;
+
+/// Looping fire ambience — campfire, torch, or burning building depending on
+/// intensity.
+///
+/// Mutate fields at runtime; the sync system pushes changes to the audio thread.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Ambience)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fire {
+    /// Overall intensity (0.0–1.0). Low = crackling torch, high = roaring blaze.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+}
+
+impl Default for Fire {
+    fn default() -> Self {
+        Self { intensity: 0.5 }
+    }
+}
+
+/// Runtime handles stored alongside the Fire entity.
+#[derive(Component)]
+pub struct FireParams {
+    pub intensity: ParamHandle,
+}
+
+/// Build the fire DSP graph and return (graph, params).
+///
+/// Layers:
+/// - Roar: low-passed brown noise, grows fastest with intensity.
+/// - Body: band-passed noise with a slow amplitude wander (flames licking).
+/// - Crackle: sparse high-frequency bursts gated by stuttered sines.
+pub fn build_fire_graph(fire: &Fire) -> (Box<dyn AudioUnit>, FireParams) {
+    let intensity_param = ParamHandle::new("intensity", fire.intensity, 0.0, 1.0);
+    let intensity_s = intensity_param.shared().clone();
+
+    let tau = core::f32::consts::TAU;
+
+    // --- Layer 1: Roar ---
+    let roar_i = intensity_s.clone();
+    let roar_gain = lfo(move |_t: f32| -> f32 {
+        let i = roar_i.value();
+        0.5 * i * i
+    });
+    let roar = (brown() >> lowpole_hz(250.0) >> lowpole_hz(250.0)) * roar_gain;
+
+    // --- Layer 2: Body ---
+    let body_i = intensity_s.clone();
+    let body_gain = lfo(move |t: f32| -> f32 {
+        let wander = 0.6
+            + 0.25 * (tau * 0.31 * t).sin()
+            + 0.15 * (tau * 0.77 * t).sin();
+        wander * 0.15 * body_i.value()
+    });
+    let body = (noise() >> bandpass_hz(700.0, 0.8)) * body_gain;
+
+    // --- Layer 3: Crackle ---
+    // Same stuttered-sine gating as the explosion's debris tail, but thresholded
+    // so only occasional peaks get through as pops.
+    let crackle_i = intensity_s.clone();
+    let crackle_env = lfo(move |t: f32| -> f32 {
+        let s1 = (t * 7.3 * tau).sin();
+        let s2 = (t * 11.9 * tau).sin();
+        let s3 = (t * 3.1 * tau).sin();
+        let stutter = (s1 * s2 * s3 - 0.35).max(0.0) / 0.65;
+        stutter * stutter * 0.5 * (0.3 + 0.7 * crackle_i.value())
+    });
+    let crackle = (noise() >> highpole_hz(3000.0) >> bandpass_hz(5500.0, 1.2)) * crackle_env;
+
+    let graph = (roar + body + crackle) >> split::<U2>();
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Give every noise source its own seed so the layers are uncorrelated.
+    boxed.ping(false, AttoHash::new(0));
+
+    let params = FireParams {
+        intensity: intensity_param,
+    };
+
+    (boxed, params)
+}
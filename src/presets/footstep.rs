@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::{ad_env, attack_decay};
+
+/// Ground material under a footstep. Selects the noise color and body resonance.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SurfaceType {
+    Grass,
+    Gravel,
+    Wood,
+    #[default]
+    Stone,
+    Water,
+}
+
+/// Per-surface tuning for the footstep layers.
+struct SurfaceProfile {
+    /// Scuff noise band center in Hz.
+    scuff_hz: f32,
+    /// Scuff band-pass Q (low = broad hiss, high = focused).
+    scuff_q: f32,
+    /// Scuff envelope decay rate (higher = shorter).
+    scuff_decay: f32,
+    scuff_gain: f32,
+    /// Resonant body frequency in Hz (the "thock" of the surface).
+    body_hz: f32,
+    body_decay: f32,
+    body_gain: f32,
+    /// Rising bubble chirp level (water only).
+    bubble_gain: f32,
+}
+
+impl SurfaceType {
+    fn profile(self) -> SurfaceProfile {
+        match self {
+            // Soft, airy rustle with almost no body.
+            SurfaceType::Grass => SurfaceProfile {
+                scuff_hz: 3500.0,
+                scuff_q: 0.7,
+                scuff_decay: 18.0,
+                scuff_gain: 0.35,
+                body_hz: 110.0,
+                body_decay: 40.0,
+                body_gain: 0.05,
+                bubble_gain: 0.0,
+            },
+            // Broad, long crunch of many small stones.
+            SurfaceType::Gravel => SurfaceProfile {
+                scuff_hz: 2200.0,
+                scuff_q: 0.5,
+                scuff_decay: 12.0,
+                scuff_gain: 0.5,
+                body_hz: 140.0,
+                body_decay: 35.0,
+                body_gain: 0.1,
+                bubble_gain: 0.0,
+            },
+            // Hollow resonant knock of boards.
+            SurfaceType::Wood => SurfaceProfile {
+                scuff_hz: 1200.0,
+                scuff_q: 1.5,
+                scuff_decay: 45.0,
+                scuff_gain: 0.2,
+                body_hz: 190.0,
+                body_decay: 22.0,
+                body_gain: 0.45,
+                bubble_gain: 0.0,
+            },
+            // Hard, short heel click with a dull low thud.
+            SurfaceType::Stone => SurfaceProfile {
+                scuff_hz: 4500.0,
+                scuff_q: 1.2,
+                scuff_decay: 60.0,
+                scuff_gain: 0.3,
+                body_hz: 85.0,
+                body_decay: 30.0,
+                body_gain: 0.35,
+                bubble_gain: 0.0,
+            },
+            // Low splash with a rising bubble.
+            SurfaceType::Water => SurfaceProfile {
+                scuff_hz: 900.0,
+                scuff_q: 0.8,
+                scuff_decay: 10.0,
+                scuff_gain: 0.45,
+                body_hz: 70.0,
+                body_decay: 25.0,
+                body_gain: 0.1,
+                bubble_gain: 0.12,
+            },
+        }
+    }
+}
+
+/// One-shot footstep sound effect on a chosen surface.
+/// Three layers: filtered-noise scuff, resonant body, and (on water) a bubble.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.4s then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Footstep {
+    /// Ground material.
+    pub surface: SurfaceType,
+    /// Overall intensity (0.0–1.0). Light tiptoe to heavy stomp.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Pitch multiplier (1.0 = normal, <1 = heavier, >1 = lighter). Use for variance.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch_shift: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+    /// Noise seed. The same seed reproduces the same waveform; defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
+}
+
+impl Default for Footstep {
+    fn default() -> Self {
+        Self {
+            surface: SurfaceType::default(),
+            intensity: 0.6,
+            pitch_shift: 1.0,
+            duration_scale: 1.0,
+            seed: rand::random(),
+        }
+    }
+}
+
+/// Build the footstep DSP graph. One-shot, no runtime params.
+pub fn build_footstep_graph(fs: &Footstep, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = fs.intensity;
+    let pitch = fs.pitch_shift * jitter.scale();
+    let stretch = fs.duration_scale.max(0.01) * jitter.scale();
+    let seed = fs.seed;
+    let p = fs.surface.profile();
+
+    // --- Layer 1: Scuff (band-passed noise burst) ---
+    // The contact texture — what tells grass from gravel.
+    let scuff_decay = p.scuff_decay;
+    let scuff_gain = p.scuff_gain * jitter.scale();
+    let scuff_env = ad_env(800.0, scuff_decay, 0.4, stretch) * (scuff_gain * int);
+    let scuff_layer = (noise() >> bandpass_hz(p.scuff_hz * pitch, p.scuff_q)) * scuff_env;
+
+    // --- Layer 2: Body (short resonant sine) ---
+    // The weight of the foot landing, pitched by the surface.
+    let body_decay = p.body_decay;
+    let body_gain = p.body_gain * jitter.scale();
+    let body_env = ad_env(400.0, body_decay, 0.3, stretch) * (body_gain * int);
+    let body_layer = sine_hz(p.body_hz * pitch) * body_env;
+
+    // --- Layer 3: Bubble (rising sine chirp, water only) ---
+    let bubble_base = 450.0 * pitch;
+    let bubble_gain = p.bubble_gain * jitter.scale();
+    let bubble_sweep = lfo(move |t: f32| -> f32 { bubble_base * (1.0 + t / stretch * 8.0) });
+    let bubble_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if !(0.05..=0.25).contains(&t) {
+            return 0.0;
+        }
+        let local = t - 0.05;
+        attack_decay(local, 200.0, 25.0) * bubble_gain * int
+    });
+    let bubble_layer = (bubble_sweep >> sine()) * bubble_env;
+
+    // --- Mix and stereo ---
+    let graph = (scuff_layer + body_layer + bubble_layer) >> split::<U2>();
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    boxed
+}
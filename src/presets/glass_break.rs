@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::{ad_env, attack_decay, stutter_gate};
+
+/// One-shot glass break sound effect — window, bottle, or potion shattering.
+/// Three layers: sharp crack, scattered shard tinkles, fine debris sizzle.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.8s then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlassBreak {
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Number of tinkling shards (clamped to 1–32). More shards = denser scatter.
+    pub shards: u8,
+    /// Noise and shard seed. The same seed reproduces the same waveform;
+    /// defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
+}
+
+impl Default for GlassBreak {
+    fn default() -> Self {
+        Self {
+            intensity: 0.8,
+            shards: 10,
+            seed: rand::random(),
+        }
+    }
+}
+
+/// Partial ratios of a shard's tinkle. Stretched and inharmonic like a
+/// small free plate, so each grain reads as glass rather than a note.
+const SHARD_PARTIALS: [(f32, f32); 3] = [(1.0, 1.0), (2.32, 0.6), (4.25, 0.35)];
+
+/// Window in seconds over which shard onsets are scattered.
+const SCATTER: f32 = 0.45;
+
+/// Build the glass break DSP graph. One-shot, no runtime params.
+///
+/// Shard pitches, onsets, and levels are drawn from `seed`, so the same
+/// component always produces the same break.
+pub fn build_glass_break_graph(gb: &GlassBreak, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = gb.intensity;
+    let pitch = jitter.scale();
+    let stretch = jitter.scale();
+    let crack_int = int * jitter.scale();
+    let tinkle_int = int * jitter.scale();
+    let debris_int = int * jitter.scale();
+    let seed = gb.seed;
+    let shards = gb.shards.clamp(1, 32);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // --- Layer 1: Crack (bright broadband snap) ---
+    let crack_env = ad_env(4000.0, 60.0, 0.08, stretch) * (0.5 * crack_int);
+    let crack_layer = (noise() >> highpole_hz(3000.0 * pitch)) * crack_env;
+
+    // --- Layer 2: Shard tinkles (inharmonic sine grains) ---
+    // Onsets bunch up right after the crack and thin out toward the end of
+    // the scatter window, like pieces landing.
+    let shard_level = 0.12 / (shards as f32).sqrt();
+    let mut tinkle_layer = Net::wrap(Box::new(zero()));
+    for _ in 0..shards {
+        let freq = rng.random_range(2500.0..7500.0_f32) * pitch;
+        let onset = 0.01 + SCATTER * rng.random::<f32>().powi(2);
+        let decay_rate = rng.random_range(25.0..60.0_f32);
+        let level = shard_level * rng.random_range(0.5..1.0_f32);
+
+        let env = lfo(move |t: f32| -> f32 {
+            let local = t / stretch - onset;
+            if !(0.0..=0.3).contains(&local) {
+                return 0.0;
+            }
+            attack_decay(local, 3000.0, decay_rate) * level * tinkle_int
+        });
+        let mut grain = Net::wrap(Box::new(zero()));
+        for (ratio, weight) in SHARD_PARTIALS {
+            grain = grain + Net::wrap(Box::new(sine_hz(freq * ratio) * dc(weight)));
+        }
+        tinkle_layer = tinkle_layer + (grain * Net::wrap(Box::new(env)));
+    }
+
+    // --- Layer 3: Debris sizzle (stutter-gated high noise) ---
+    let debris_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > 0.7 {
+            return 0.0;
+        }
+        let onset = ((t - 0.02) * 40.0).clamp(0.0, 1.0);
+        let stutter = stutter_gate(t, &[83.1, 129.7]);
+        let decay = (-t * 6.0).exp();
+        onset * stutter * decay * 0.08 * debris_int
+    });
+    let debris_layer = (noise() >> bandpass_hz(6500.0 * pitch, 1.5)) * debris_env;
+
+    // --- Mix and stereo ---
+    let graph = (Net::wrap(Box::new(crack_layer))
+        + tinkle_layer
+        + Net::wrap(Box::new(debris_layer)))
+        >> Net::wrap(Box::new(split::<U2>()));
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    boxed
+}
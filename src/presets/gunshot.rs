@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+#[cfg(debug_assertions)]
+use crate::dsp::validate::check_net;
+use crate::presets::common::{ad_env, apply_reverb, velocity_response, ReverbShape};
+
+/// One-shot gunshot sound effect.
+/// Three layers: broadband muzzle blast, low body thump, bright supersonic crack.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.5s (longer for large calibers) then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gunshot {
+    /// Weapon size (1.0 = rifle, <1 = pistol, >1 = heavy gun).
+    /// Larger calibers have a lower body and a longer decay.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub caliber: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Strength of the shot (0.0–1.0), e.g. for suppressed or distant fire.
+    /// Weaker shots are quieter, with a duller blast and a softer crack;
+    /// 1.0 plays the shot as configured.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub velocity: f32,
+    /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet). Adds an outdoor slap-back.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub reverb_mix: f32,
+    /// Noise seed. The same seed reproduces the same waveform; defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
+}
+
+impl Default for Gunshot {
+    fn default() -> Self {
+        Self {
+            caliber: 1.0,
+            intensity: 0.8,
+            velocity: 1.0,
+            reverb_mix: 0.1,
+            seed: rand::random(),
+        }
+    }
+}
+
+/// Build the gunshot DSP graph. One-shot, no runtime params.
+pub fn build_gunshot_graph(gs: &Gunshot, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let (gain, brightness) = velocity_response(gs.velocity);
+    let intensity = gs.intensity * gain;
+    let caliber = gs.caliber.max(0.1);
+    let reverb_mix = gs.reverb_mix;
+    let seed = gs.seed;
+    let pitch = jitter.scale();
+    let blast_int = intensity * jitter.scale();
+    let thump_int = intensity * jitter.scale();
+    let crack_int = intensity * brightness * jitter.scale();
+
+    // Bigger guns ring longer: decay rates shrink as caliber grows.
+    let decay_scale = 1.0 / (caliber.sqrt() * jitter.scale());
+
+    let mut net = Net::new(0, 2);
+
+    // --- Layer 1: Muzzle blast (broadband noise transient) ---
+    let blast_cutoff = 6000.0 * pitch * brightness / caliber.sqrt();
+    let blast_src_id = net.push(Box::new(noise() >> lowpole_hz(blast_cutoff)));
+
+    let blast_env_id = net.push(Box::new(
+        ad_env(4000.0 * brightness, 30.0 * decay_scale, 0.25 / decay_scale, 1.0)
+            * (0.55 * blast_int),
+    ));
+
+    let blast_id = net.push(Box::new(map(|f: &Frame<f32, U2>| -> f32 {
+        f[0] * f[1]
+    })));
+    net.connect(blast_src_id, 0, blast_id, 0);
+    net.connect(blast_env_id, 0, blast_id, 1);
+
+    // --- Layer 2: Body thump (low sine with a falling pitch) ---
+    let thump_hi = 160.0 * pitch / caliber;
+    let thump_lo = 55.0 * pitch / caliber;
+    let thump_freq_id = net.push(Box::new(lfo(move |t: f32| -> f32 {
+        thump_lo + (thump_hi - thump_lo) * (-t * 40.0).exp()
+    })));
+    let thump_osc_id = net.push(Box::new(sine()));
+    net.connect(thump_freq_id, 0, thump_osc_id, 0);
+
+    let thump_env_id = net.push(Box::new(
+        ad_env(600.0 * brightness, 14.0 * decay_scale, 0.4 / decay_scale, 1.0) * (0.5 * thump_int),
+    ));
+
+    let thump_id = net.push(Box::new(map(|f: &Frame<f32, U2>| -> f32 {
+        f[0] * f[1]
+    })));
+    net.connect(thump_osc_id, 0, thump_id, 0);
+    net.connect(thump_env_id, 0, thump_id, 1);
+
+    // --- Layer 3: Crack (bright high-passed noise snap) ---
+    let crack_src_id = net.push(Box::new(noise() >> highpole_hz(3500.0 * pitch)));
+
+    let crack_env_id = net.push(Box::new(
+        ad_env(10000.0 * brightness, 90.0, 0.05, 1.0) * (0.35 * crack_int),
+    ));
+
+    let crack_id = net.push(Box::new(map(|f: &Frame<f32, U2>| -> f32 {
+        f[0] * f[1]
+    })));
+    net.connect(crack_src_id, 0, crack_id, 0);
+    net.connect(crack_env_id, 0, crack_id, 1);
+
+    // --- Mix ---
+    let mix_id = net.push(Box::new(map(|f: &Frame<f32, U3>| -> f32 {
+        f[0] + f[1] + f[2]
+    })));
+    net.connect(blast_id, 0, mix_id, 0);
+    net.connect(thump_id, 0, mix_id, 1);
+    net.connect(crack_id, 0, mix_id, 2);
+
+    let split_id = net.push(Box::new(split::<U2>()));
+    net.connect(mix_id, 0, split_id, 0);
+    net.connect_output(split_id, 0, 0);
+    net.connect_output(split_id, 1, 1);
+
+    let room = ReverbShape {
+        room_size: 0.7,
+        time: 1.2,
+        diffusion: 0.6,
+        cutoff_hz: 3000.0,
+    };
+    let net = apply_reverb(net, reverb_mix, room);
+    #[cfg(debug_assertions)]
+    if let Err(error) = check_net(&net) {
+        panic!("build_gunshot_graph wired an invalid net: {error}");
+    }
+    let mut boxed: Box<dyn AudioUnit> = Box::new(net);
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    boxed
+}
@@ -1,19 +1,99 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
 
+use crate::components::mixer::MixBus;
 use crate::dsp::param::ParamHandle;
+use crate::presets::common::{apply_live_reverb, attack_decay, ReverbShape};
+
+/// Rhythm and beat shape of a [`Heartbeat`], on top of its `heart_rate`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeartbeatState {
+    /// Regular "lub-dub" at `heart_rate`.
+    #[default]
+    Normal,
+    /// Racing heart: at least 150 BPM, with tighter, sharper beats and the
+    /// "dub" crowding the "lub".
+    Tachycardia,
+    /// No real beats: rapid, chaotic, low-amplitude quivers.
+    Fibrillation,
+    /// No pulse at all, only the monitor's steady tone.
+    Flatline,
+}
+
+impl HeartbeatState {
+    pub const ALL: [HeartbeatState; 4] = [
+        HeartbeatState::Normal,
+        HeartbeatState::Tachycardia,
+        HeartbeatState::Fibrillation,
+        HeartbeatState::Flatline,
+    ];
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+
+    pub(crate) fn from_index(index: usize) -> Self {
+        Self::ALL.get(index).copied().unwrap_or_default()
+    }
+
+    /// Beats per minute and arrhythmia the pulses actually run at, given the
+    /// component's `heart_rate` and `arrhythmic_strength`.
+    pub(crate) fn rhythm(self, bpm: f32, arrhythmia: f32) -> (f32, f32) {
+        match self {
+            HeartbeatState::Tachycardia => (bpm.max(TACHYCARDIA_MIN_BPM), arrhythmia),
+            HeartbeatState::Fibrillation => (FIBRILLATION_BPM, 1.0),
+            HeartbeatState::Normal | HeartbeatState::Flatline => (bpm, arrhythmia),
+        }
+    }
+}
+
+/// Slowest rate a `Tachycardia` heart beats at.
+const TACHYCARDIA_MIN_BPM: f32 = 150.0;
+
+/// Quiver rate of `Fibrillation`, around 7 per second.
+const FIBRILLATION_BPM: f32 = 420.0;
+
+/// Pitch of the `Flatline` monitor tone.
+const MONITOR_HZ: f32 = 1000.0;
 
 /// Heartbeat preset — spawns an ECG-like rhythmic thump.
 ///
 /// Mutate fields at runtime; the sync system pushes changes to the audio thread.
+/// Read [`HeartbeatPulse`] messages to sync visuals to the beat; a
+/// `Flatline` heart sends none.
+///
+/// ```ignore
+/// commands.spawn(Heartbeat { state: HeartbeatState::Fibrillation, ..default() });
+/// ```
 #[derive(Component, Debug, Clone)]
+#[require(HeartbeatClock, MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Heartbeat {
     /// Beats per minute (30–220).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub heart_rate: f32,
     /// Random jitter on beat timing (0.0 = perfectly regular, 1.0 = chaotic).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub arrhythmic_strength: f32,
     /// Overall intensity (0.0–1.0). Controls volume and low-pass cutoff.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
+    /// Breaths per minute for the inhale/exhale layer (0.0 = off, up to 40).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "crate::serde_util::finite")
+    )]
+    pub breath_rate: f32,
+    /// Beat morphology, from a healthy rhythm to a flatline. Live.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub state: HeartbeatState,
+    /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet). Live.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "crate::serde_util::finite")
+    )]
+    pub reverb_mix: f32,
 }
 
 impl Default for Heartbeat {
@@ -22,6 +102,9 @@ impl Default for Heartbeat {
             heart_rate: 72.0,
             arrhythmic_strength: 0.0,
             intensity: 0.5,
+            breath_rate: 0.0,
+            state: HeartbeatState::Normal,
+            reverb_mix: 0.0,
         }
     }
 }
@@ -32,6 +115,53 @@ pub struct HeartbeatParams {
     pub rate: ParamHandle,
     pub intensity: ParamHandle,
     pub arrhythmia: ParamHandle,
+    pub breath_rate: ParamHandle,
+    /// [`HeartbeatState`] index.
+    pub state: ParamHandle,
+    pub reverb_mix: ParamHandle,
+}
+
+/// Sent on each S1 ("lub") of a playing `Heartbeat`, for syncing visuals
+/// such as screen flashes or camera shake to the beat.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct HeartbeatPulse {
+    /// The `Heartbeat` entity that beat.
+    pub entity: Entity,
+}
+
+/// Main-thread copy of a `Heartbeat`'s beat phase, advanced every frame by
+/// `heartbeat_pulse_system` to detect S1 without reading the audio thread.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HeartbeatClock {
+    pub(crate) elapsed: f32,
+    pub(crate) phase: f32,
+}
+
+impl Default for HeartbeatClock {
+    fn default() -> Self {
+        // Start at the end of a cycle so the S1 at t = 0 is reported.
+        Self {
+            elapsed: 0.0,
+            phase: 1.0,
+        }
+    }
+}
+
+/// Beat phase (0.0–1.0, S1 at 0.0) at time `t`. Shared by the audio graph and
+/// `heartbeat_pulse_system` so visuals land on the same beats as the sound.
+pub(crate) fn beat_phase(t: f32, bpm: f32, arrhythmia: f32) -> f32 {
+    let beat_period = 60.0 / bpm.max(30.0);
+    let tau = core::f32::consts::TAU;
+
+    // Arrhythmia: multiple incommensurate sine waves create a
+    // chaotic-feeling phase jitter. At 0.0 beats are perfectly
+    // regular; at 1.0 they're sporadic (~±40% timing variation).
+    let phase_jitter = arrhythmia * 0.4 * (
+        (tau * 0.37 * t).sin() * 0.5
+        + (tau * 0.83 * t).sin() * 0.3
+        + (tau * 1.71 * t).sin() * 0.2
+    );
+    (t / beat_period + phase_jitter).fract()
 }
 
 /// A damped oscillation burst for a single heart sound.
@@ -41,61 +171,143 @@ fn heart_sound(local_t: f32, freq_lo: f32, freq_hi: f32, decay: f32) -> f32 {
         return 0.0;
     }
     // Short 2ms attack ramp to avoid click, then exponential decay.
-    let attack = (local_t * 500.0).min(1.0);
-    let env = attack * (-decay * local_t).exp();
+    let env = attack_decay(local_t, 500.0, decay);
     let lo = (core::f32::consts::TAU * freq_lo * local_t).sin();
     let hi = (core::f32::consts::TAU * freq_hi * local_t).sin() * 0.4;
     (lo + hi) * env
 }
 
+/// Fraction of each breath cycle spent inhaling; the rest is the exhale.
+const INHALE: f32 = 0.4;
+
+/// Breath swell at `phase` (0.0–1.0) through one breath cycle.
+/// Returns (gain, brightness): the inhale is a shorter, brighter half-sine,
+/// the exhale a longer, softer and duller one.
+fn breath_shape(phase: f32) -> (f32, f32) {
+    let pi = core::f32::consts::PI;
+    if phase < INHALE {
+        ((phase / INHALE * pi).sin(), 1.0)
+    } else {
+        let local = (phase - INHALE) / (1.0 - INHALE);
+        ((local * pi).sin() * 0.7, 0.0)
+    }
+}
+
 /// Build the heartbeat DSP graph and return (graph, params).
 ///
 /// Synthesizes a "lub-dub" heartbeat using two damped oscillation bursts:
 /// - S1 ("lub"): lower-pitched, longer decay
 /// - S2 ("dub"): higher-pitched, shorter decay, ~0.33 beat periods later
+///
+/// `state` reshapes this live: tachycardia tightens both sounds and moves S2
+/// to ~0.45, fibrillation swaps the beats for short, unevenly loud quivers,
+/// and flatline silences them under a steady 1 kHz monitor tone.
+///
+/// When `breath_rate` is above zero, band-passed noise swells in and out
+/// underneath at the breathing rate. `reverb_mix` sends it all into a large,
+/// dark hall.
 pub fn build_heartbeat_graph(hb: &Heartbeat) -> (Box<dyn AudioUnit>, HeartbeatParams) {
     let rate_param = ParamHandle::new("heart_rate", hb.heart_rate, 30.0, 220.0);
     let intensity_param = ParamHandle::new("intensity", hb.intensity, 0.0, 1.0);
     let arrhythmia_param = ParamHandle::new("arrhythmia", hb.arrhythmic_strength, 0.0, 1.0);
+    let breath_param = ParamHandle::new("breath_rate", hb.breath_rate, 0.0, 40.0);
+    let last_state = (HeartbeatState::ALL.len() - 1) as f32;
+    let state_param = ParamHandle::new("state", hb.state.index() as f32, 0.0, last_state);
+    let mut reverb_param = ParamHandle::new("reverb_mix", hb.reverb_mix, 0.0, 1.0);
 
     let rate_s = rate_param.shared().clone();
     let intensity_s = intensity_param.shared().clone();
     let arrhythmia_s = arrhythmia_param.shared().clone();
+    let breath_s = breath_param.shared().clone();
+    let state_s = state_param.shared().clone();
 
-    let graph = lfo(move |t: f32| -> f32 {
-        let bpm = rate_s.value().max(30.0);
+    let heart_intensity = intensity_s.clone();
+    let heart_state = state_s.clone();
+    let heart = lfo(move |t: f32| -> f32 {
+        let state = HeartbeatState::from_index(heart_state.value().round() as usize);
+        let (bpm, arrhythmia) = state.rhythm(rate_s.value().max(30.0), arrhythmia_s.value());
         let beat_period = 60.0 / bpm;
-        let tau = core::f32::consts::TAU;
+        let phase = beat_phase(t, bpm, arrhythmia);
 
-        // Arrhythmia: multiple incommensurate sine waves create a
-        // chaotic-feeling phase jitter. At 0.0 beats are perfectly
-        // regular; at 1.0 they're sporadic (~±40% timing variation).
-        let arr = arrhythmia_s.value();
-        let phase_jitter = arr * 0.4 * (
-            (tau * 0.37 * t).sin() * 0.5
-            + (tau * 0.83 * t).sin() * 0.3
-            + (tau * 1.71 * t).sin() * 0.2
-        );
-        let phase = (t / beat_period + phase_jitter).fract();
+        let beat = match state {
+            HeartbeatState::Normal | HeartbeatState::Tachycardia => {
+                // A racing heart's diastole shortens: S2 lands later in the
+                // cycle and both sounds die away faster so they don't smear.
+                let (s2_phase, tighten) = if state == HeartbeatState::Tachycardia {
+                    (0.45, 1.6)
+                } else {
+                    (0.33, 1.0)
+                };
 
-        // S1 ("lub") at phase 0.0 — deep thump.
-        let s1_t = phase * beat_period;
-        let s1 = heart_sound(s1_t, 45.0, 90.0, 25.0);
+                // S1 ("lub") at phase 0.0 — deep thump.
+                let s1_t = phase * beat_period;
+                let s1 = heart_sound(s1_t, 45.0, 90.0, 25.0 * tighten);
 
-        // S2 ("dub") at phase 0.33 — higher, sharper.
-        let s2_t = (phase - 0.33) * beat_period;
-        let s2 = heart_sound(s2_t, 65.0, 130.0, 35.0) * 0.7;
+                // S2 ("dub") — higher, sharper.
+                let s2_t = (phase - s2_phase) * beat_period;
+                let s2 = heart_sound(s2_t, 65.0, 130.0, 35.0 * tighten) * 0.7;
+                s1 + s2
+            }
+            HeartbeatState::Fibrillation => {
+                // Weak quivers whose strength wanders with no pattern.
+                let tau = core::f32::consts::TAU;
+                let chaos = 0.6 + 0.25 * (tau * 2.3 * t).sin() + 0.15 * (tau * 5.9 * t).sin();
+                heart_sound(phase * beat_period, 60.0, 120.0, 60.0) * chaos * 0.3
+            }
+            HeartbeatState::Flatline => 0.0,
+        };
+        beat * heart_intensity.value()
+    }) >> lowpole_hz(150.0);
 
-        (s1 + s2) * intensity_s.value()
-    }) >> lowpole_hz(150.0)
-       >> split::<U2>();
+    // Monitor tone, faded in and out so switching to and from a flatline
+    // doesn't click.
+    let monitor_intensity = intensity_s.clone();
+    let monitor_gain = lfo(move |_t: f32| -> f32 {
+        let state = HeartbeatState::from_index(state_s.value().round() as usize);
+        if state == HeartbeatState::Flatline {
+            0.15 * monitor_intensity.value()
+        } else {
+            0.0
+        }
+    }) >> follow(0.02);
+    let monitor = sine_hz(MONITOR_HZ) * monitor_gain;
 
-    let boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Breathing: same `t`-based phase as the pulse, at breaths per minute.
+    let center_breath = breath_s.clone();
+    let breath_center = lfo(move |t: f32| -> f32 {
+        let phase = (t * center_breath.value() / 60.0).fract();
+        let (_, brightness) = breath_shape(phase);
+        700.0 + 600.0 * brightness
+    });
+    let breath_gain = lfo(move |t: f32| -> f32 {
+        let rate = breath_s.value();
+        if rate <= 0.0 {
+            return 0.0;
+        }
+        let phase = (t * rate / 60.0).fract();
+        let (swell, _) = breath_shape(phase);
+        swell * swell * 0.12 * intensity_s.value()
+    });
+    let breath = ((noise() | breath_center | dc(0.8)) >> bandpass()) * breath_gain;
+
+    let graph = (heart + monitor + breath) >> split::<U2>();
+
+    let room = ReverbShape {
+        room_size: 0.8,
+        time: 2.5,
+        diffusion: 0.6,
+        cutoff_hz: 2000.0,
+    };
+    let boxed: Box<dyn AudioUnit> =
+        Box::new(apply_live_reverb(Net::wrap(Box::new(graph)), &mut reverb_param, room));
 
     let params = HeartbeatParams {
         rate: rate_param,
         intensity: intensity_param,
         arrhythmia: arrhythmia_param,
+        breath_rate: breath_param,
+        state: state_param,
+        reverb_mix: reverb_param,
     };
 
     (boxed, params)
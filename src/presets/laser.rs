@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::ad_env;
+
+/// One-shot sci-fi laser shot.
+/// Three layers: falling FM square sweep, saw sub-sweep, short noise zap.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.2s then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Laser {
+    /// Pitch multiplier (1.0 = standard blaster, >1 = small zapper, <1 = heavy cannon).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch_shift: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+}
+
+impl Default for Laser {
+    fn default() -> Self {
+        Self {
+            pitch_shift: 1.0,
+            intensity: 0.7,
+            duration_scale: 1.0,
+        }
+    }
+}
+
+/// Build the laser DSP graph. One-shot, no runtime params.
+pub fn build_laser_graph(la: &Laser, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = la.intensity;
+    let pitch = la.pitch_shift * jitter.scale();
+    let stretch = la.duration_scale.max(0.01) * jitter.scale();
+    let sweep_int = int * jitter.scale();
+    let sub_int = int * jitter.scale();
+    let zap_int = int * jitter.scale();
+
+    // --- Layer 1: Falling sweep ---
+    // The arcane attack's rising sweep, inverted and much faster: 2400 → 200 Hz
+    // over 0.18s with an exponential curve so most of the drop happens early.
+    let sweep_hi = 2400.0 * pitch;
+    let sweep_lo = 200.0 * pitch;
+    let sweep_freq = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > 0.18 {
+            return sweep_lo;
+        }
+        sweep_lo + (sweep_hi - sweep_lo) * (-t * 18.0).exp()
+    });
+    let sweep_env = ad_env(2000.0, 14.0, 0.2, stretch) * (0.18 * sweep_int);
+    // FM: a fast sine wobble on the frequency for a buzzy, "charged" edge.
+    let fm_mod = sine_hz(90.0 * pitch) * dc(120.0 * pitch);
+    let sweep_layer = ((sweep_freq.clone() + fm_mod) >> square()) * sweep_env;
+
+    // --- Layer 2: Sub-sweep ---
+    // Saw an octave down for body.
+    let sub_env = ad_env(1000.0, 18.0, 0.2, stretch) * (0.12 * sub_int);
+    let sub_layer = ((sweep_freq * dc(0.5)) >> saw()) * sub_env;
+
+    // --- Layer 3: Zap ---
+    // Short bright noise burst at the trigger.
+    let zap_env = ad_env(5000.0, 120.0, 0.03, stretch) * (0.2 * zap_int);
+    let zap_layer = (noise() >> bandpass_hz(5000.0 * pitch, 1.0)) * zap_env;
+
+    // --- Mix and stereo ---
+    // Lowpass tames the square's upper harmonics.
+    let graph = ((sweep_layer + sub_layer + zap_layer) >> lowpole_hz(9000.0)) >> split::<U2>();
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Fixed seed: identical shots render identically, so baking shares one buffer.
+    boxed.ping(false, AttoHash::new(0));
+    boxed
+}
@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
 
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::{ad_env, apply_reverb, attack_decay, stutter_env, ReverbShape};
+
 /// One-shot electrical zap — sustained buzzy arc discharge.
 ///
 /// Three layers: buzzy sawtooth-like FM tone with downward pitch sweep for
@@ -9,13 +13,25 @@ use fundsp::prelude32::*;
 ///
 /// Spawn an entity with this component to trigger the sound.
 #[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightningZap {
     /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
     /// Pitch multiplier (1.0 = normal, <1 = lower, >1 = higher).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub pitch_shift: f32,
     /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub reverb_mix: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
 }
 
 impl Default for LightningZap {
@@ -24,15 +40,20 @@ impl Default for LightningZap {
             intensity: 0.8,
             pitch_shift: 1.0,
             reverb_mix: 0.0,
+            duration_scale: 1.0,
         }
     }
 }
 
 /// Build the lightning zap DSP graph. One-shot, no runtime params.
-pub fn build_lightning_zap_graph(zap: &LightningZap) -> Box<dyn AudioUnit> {
+pub fn build_lightning_zap_graph(zap: &LightningZap, mut jitter: Jitter) -> Box<dyn AudioUnit> {
     let int = zap.intensity;
-    let pitch = zap.pitch_shift;
+    let pitch = zap.pitch_shift * jitter.scale();
     let reverb_mix = zap.reverb_mix;
+    let stretch = zap.duration_scale.max(0.01) * jitter.scale();
+    let zap_int = int * jitter.scale();
+    let sizzle_int = int * jitter.scale();
+    let mid_int = int * jitter.scale();
 
     // Reference analysis: spectral centroid ~5400Hz, 95%+ energy above 2kHz,
     // erratic stuttering envelope, ~500ms duration, peak RMS ~0.3.
@@ -41,65 +62,31 @@ pub fn build_lightning_zap_graph(zap: &LightningZap) -> Box<dyn AudioUnit> {
     // Wide bandpass noise centered around 5kHz — this is the main sizzle.
     // Chaotically stuttering envelope from overlapping inharmonic sine gates.
     let bp1 = 5000.0 * pitch;
-    let zap_env = lfo(move |t: f32| -> f32 {
-        if t > 0.55 {
-            return 0.0;
-        }
-        // Chaotic stutter: product of sines at inharmonic rates
-        // creates pseudo-random gating (arc making/breaking contact)
-        let s1 = (t * 127.3 * std::f32::consts::TAU).sin();
-        let s2 = (t * 89.7 * std::f32::consts::TAU).sin();
-        let s3 = (t * 211.1 * std::f32::consts::TAU).sin();
-        let stutter = (s1 * s2 * s3).max(0.0); // half-wave rectified product
-        let overall = (-t * 3.5).exp();
-        stutter * overall * 0.65 * int
-    });
+    let zap_env = stutter_env([127.3, 89.7, 211.1], 3.5, 0.55, stretch) * (0.65 * zap_int);
     let zap_layer = (noise() >> bandpass_hz(bp1, 1.5)) * zap_env;
 
     // --- Layer 2: High sizzle (noise above 5kHz) ---
     // Additional high-frequency content for brightness and air.
     let bp2 = 7000.0 * pitch;
-    let sizzle_env = lfo(move |t: f32| -> f32 {
-        if t > 0.5 {
-            return 0.0;
-        }
-        // Different stutter pattern (different frequencies)
-        let s1 = (t * 173.9 * std::f32::consts::TAU).sin();
-        let s2 = (t * 67.3 * std::f32::consts::TAU).sin();
-        let stutter = (s1 * s2).max(0.0);
-        let overall = (-t * 4.0).exp();
-        stutter * overall * 0.4 * int
-    });
+    let sizzle_env = stutter_env([173.9, 67.3], 4.0, 0.5, stretch) * (0.4 * sizzle_int);
     let sizzle_layer = (noise() >> bandpass_hz(bp2, 1.0)) * sizzle_env;
 
     // --- Layer 3: Mid crackle (~3-4kHz) ---
     // Fills out the spectrum in the 2-5kHz range.
     let bp3 = 3500.0 * pitch;
-    let mid_env = lfo(move |t: f32| -> f32 {
-        if t > 0.5 {
-            return 0.0;
-        }
-        let s1 = (t * 151.7 * std::f32::consts::TAU).sin();
-        let s2 = (t * 103.3 * std::f32::consts::TAU).sin();
-        let s3 = (t * 197.9 * std::f32::consts::TAU).sin();
-        let stutter = (s1 * s2 * s3).max(0.0);
-        let overall = (-t * 3.0).exp();
-        stutter * overall * 0.35 * int
-    });
+    let mid_env = stutter_env([151.7, 103.3, 197.9], 3.0, 0.5, stretch) * (0.35 * mid_int);
     let mid_layer = (noise() >> bandpass_hz(bp3, 1.5)) * mid_env;
 
     // --- Mix and stereo ---
     let graph = (zap_layer + sizzle_layer + mid_layer) >> split::<U2>();
 
-    if reverb_mix > 0.001 {
-        let reverb = reverb2_stereo(0.2, 0.4, 0.3, 1.0, lowpole_hz(8000.0));
-        let dry = 1.0 - reverb_mix;
-        let wet = reverb_mix;
-        let mixed = (graph.clone() * dc((dry, dry))) + (graph >> reverb) * dc((wet, wet));
-        Box::new(mixed)
-    } else {
-        Box::new(graph)
-    }
+    let room = ReverbShape {
+        room_size: 0.2,
+        time: 0.4,
+        diffusion: 0.3,
+        cutoff_hz: 8000.0,
+    };
+    Box::new(apply_reverb(Net::wrap(Box::new(graph)), reverb_mix, room))
 }
 
 /// One-shot lightning strike — massive thunder boom with electrical crack.
@@ -109,13 +96,28 @@ pub fn build_lightning_zap_graph(zap: &LightningZap) -> Box<dyn AudioUnit> {
 ///
 /// Spawn an entity with this component to trigger the sound.
 #[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightningStrike {
     /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
     /// Pitch multiplier (1.0 = normal, <1 = lower, >1 = higher).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub pitch_shift: f32,
     /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet). Adds distant-storm ambience.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub reverb_mix: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+    /// Noise seed. The same seed reproduces the same waveform; defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
 }
 
 impl Default for LightningStrike {
@@ -124,26 +126,30 @@ impl Default for LightningStrike {
             intensity: 0.8,
             pitch_shift: 1.0,
             reverb_mix: 0.15,
+            duration_scale: 1.0,
+            seed: rand::random(),
         }
     }
 }
 
 /// Build the lightning strike DSP graph. One-shot, no runtime params.
-pub fn build_lightning_strike_graph(ls: &LightningStrike) -> Box<dyn AudioUnit> {
+pub fn build_lightning_strike_graph(
+    ls: &LightningStrike,
+    mut jitter: Jitter,
+) -> Box<dyn AudioUnit> {
     let int = ls.intensity;
-    let pitch = ls.pitch_shift;
+    let pitch = ls.pitch_shift * jitter.scale();
     let reverb_mix = ls.reverb_mix;
+    let seed = ls.seed;
+    let stretch = ls.duration_scale.max(0.01) * jitter.scale();
+    let crack_int = int * jitter.scale();
+    let boom_int = int * jitter.scale();
+    let mid_int = int * jitter.scale();
+    let crackle_int = int * jitter.scale();
 
     // --- Layer 1: Initial crack (bright broadband transient) ---
     // Full-spectrum noise burst — the sharp CRACK at the instant of the strike.
-    let crack_env = lfo(move |t: f32| -> f32 {
-        if t > 0.15 {
-            return 0.0;
-        }
-        let attack = (t * 5000.0).min(1.0);
-        let decay = (-t * 20.0).exp();
-        attack * decay * 0.5 * int
-    });
+    let crack_env = ad_env(5000.0, 20.0, 0.15, stretch) * (0.5 * crack_int);
     let crack_layer = noise() * crack_env;
 
     // --- Layer 2: Low boom (dominant thunder body) ---
@@ -151,13 +157,12 @@ pub fn build_lightning_strike_graph(ls: &LightningStrike) -> Box<dyn AudioUnit>
     // Two cascaded lowpole filters for steep rolloff. High amplitude.
     let boom_cutoff = 80.0 * pitch;
     let boom_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
         if t > 2.5 {
             return 0.0;
         }
         // Fast attack, long sustain/decay for rolling thunder
-        let attack = (t * 100.0).min(1.0);
-        let decay = (-t * 1.2).exp();
-        attack * decay * 0.7 * int
+        attack_decay(t, 100.0, 1.2) * 0.7 * boom_int
     });
     let boom_layer =
         (noise() >> lowpole_hz(boom_cutoff) >> lowpole_hz(boom_cutoff)) * boom_env;
@@ -165,14 +170,7 @@ pub fn build_lightning_strike_graph(ls: &LightningStrike) -> Box<dyn AudioUnit>
     // --- Layer 3: Mid body (fills out the thunder) ---
     // Mid-frequency noise gives body between crack and boom.
     let mid_cutoff = 400.0 * pitch;
-    let mid_env = lfo(move |t: f32| -> f32 {
-        if t > 1.5 {
-            return 0.0;
-        }
-        let attack = (t * 200.0).min(1.0);
-        let decay = (-t * 2.0).exp();
-        attack * decay * 0.3 * int
-    });
+    let mid_env = ad_env(200.0, 2.0, 1.5, stretch) * (0.3 * mid_int);
     let mid_layer = (noise() >> lowpole_hz(mid_cutoff)) * mid_env;
 
     // --- Layer 4: Electrical crackle (FM chaos, secondary) ---
@@ -181,29 +179,24 @@ pub fn build_lightning_strike_graph(ls: &LightningStrike) -> Box<dyn AudioUnit>
     let c1_mod = 1270.0 * pitch;
     let fm1 = (dc(c1_carrier)
         + sine_hz(c1_mod)
-            * lfo(move |t: f32| -> f32 { 30.0 * c1_mod * (-t * 6.0).exp() }))
+            * lfo(move |t: f32| -> f32 { 30.0 * c1_mod * (-t / stretch * 6.0).exp() }))
         >> sine();
 
-    let crackle_env = lfo(move |t: f32| -> f32 {
-        if t > 0.8 {
-            return 0.0;
-        }
-        let attack = (t * 1000.0).min(1.0);
-        let decay = (-t * 4.0).exp();
-        attack * decay * 0.06 * int
-    });
+    let crackle_env = ad_env(1000.0, 4.0, 0.8, stretch) * (0.06 * crackle_int);
     let crackle_layer = fm1 * crackle_env;
 
     // --- Mix and stereo ---
     let graph = (crack_layer + boom_layer + mid_layer + crackle_layer) >> split::<U2>();
 
-    if reverb_mix > 0.001 {
-        let reverb = reverb2_stereo(0.6, 1.5, 0.5, 1.0, lowpole_hz(2000.0));
-        let dry = 1.0 - reverb_mix;
-        let wet = reverb_mix;
-        let mixed = (graph.clone() * dc((dry, dry))) + (graph >> reverb) * dc((wet, wet));
-        Box::new(mixed)
-    } else {
-        Box::new(graph)
-    }
+    let room = ReverbShape {
+        room_size: 0.6,
+        time: 1.5,
+        diffusion: 0.5,
+        cutoff_hz: 2000.0,
+    };
+    let mut boxed: Box<dyn AudioUnit> =
+        Box::new(apply_reverb(Net::wrap(Box::new(graph)), reverb_mix, room));
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    boxed
 }
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::dsp::param::ParamHandle;
+use crate::presets::common::attack_decay;
+
+/// Metronome preset — a click on every beat, for rhythm games and music
+/// sequencing.
+///
+/// Mutate fields at runtime; the sync system pushes changes to the audio thread.
+/// Read [`MetronomeTick`] messages to drive gameplay from the beat.
+#[derive(Component, Debug, Clone)]
+#[require(MetronomeClock, MixBus = MixBus::Music)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metronome {
+    /// Beats per minute (20–400).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub bpm: f32,
+    /// Play a higher, louder click on every Nth beat, starting with the first
+    /// (4 = 4/4 downbeats). 0 disables the accent.
+    pub accent_every: u8,
+}
+
+impl Default for Metronome {
+    fn default() -> Self {
+        Self {
+            bpm: 120.0,
+            accent_every: 4,
+        }
+    }
+}
+
+/// Runtime handles stored alongside the Metronome entity.
+#[derive(Component)]
+pub struct MetronomeParams {
+    pub bpm: ParamHandle,
+    pub accent_every: ParamHandle,
+}
+
+/// Sent on each beat of a playing `Metronome`.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct MetronomeTick {
+    /// The `Metronome` entity that ticked.
+    pub entity: Entity,
+    /// Beats since the metronome started, from 0.
+    pub beat: u64,
+    /// Whether this beat has the accented click.
+    pub accent: bool,
+}
+
+/// Main-thread copy of a `Metronome`'s beat count, advanced every frame by
+/// `metronome_tick_system` to detect beats without reading the audio thread.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct MetronomeClock {
+    pub(crate) elapsed: f32,
+    /// Last beat reported, `None` until the first.
+    pub(crate) beat: Option<u64>,
+}
+
+/// Beats elapsed at time `t`; the fractional part is the phase through the
+/// current beat. Shared by the audio graph and `metronome_tick_system` so
+/// messages land on the audible clicks.
+pub(crate) fn beat_position(t: f32, bpm: f32) -> f32 {
+    t * bpm.clamp(20.0, 400.0) / 60.0
+}
+
+/// Current beat number at time `t`, and the seconds since its click.
+fn beat_at(t: f32, bpm: f32) -> (u64, f32) {
+    let position = beat_position(t, bpm);
+    let beat_period = 60.0 / bpm.clamp(20.0, 400.0);
+    (position as u64, position.fract() * beat_period)
+}
+
+/// Whether beat number `beat` is accented.
+pub(crate) fn is_accent(beat: u64, accent_every: u8) -> bool {
+    accent_every > 0 && beat.is_multiple_of(accent_every as u64)
+}
+
+/// Build the metronome DSP graph and return (graph, params).
+///
+/// Each click is a short sine blip with a 1ms attack and a fast exponential
+/// decay, so it stays crisp even at high tempos. Accented beats are a fifth
+/// higher and a little louder.
+pub fn build_metronome_graph(mt: &Metronome) -> (Box<dyn AudioUnit>, MetronomeParams) {
+    let bpm_param = ParamHandle::new("bpm", mt.bpm, 20.0, 400.0);
+    let accent_param = ParamHandle::new("accent_every", mt.accent_every as f32, 0.0, 255.0);
+
+    let bpm_s = bpm_param.shared().clone();
+    let accent_s = accent_param.shared().clone();
+
+    let pitch_bpm = bpm_s.clone();
+    let pitch_accent = accent_s.clone();
+    let pitch = lfo(move |t: f32| -> f32 {
+        let (beat, _) = beat_at(t, pitch_bpm.value());
+        if is_accent(beat, pitch_accent.value() as u8) {
+            1500.0
+        } else {
+            1000.0
+        }
+    });
+
+    let env = lfo(move |t: f32| -> f32 {
+        let (beat, local_t) = beat_at(t, bpm_s.value());
+        if local_t > 0.08 {
+            return 0.0;
+        }
+        let level = if is_accent(beat, accent_s.value() as u8) {
+            0.5
+        } else {
+            0.35
+        };
+        attack_decay(local_t, 1000.0, 60.0) * level
+    });
+
+    let click = (pitch >> sine()) * env;
+    let graph = click >> split::<U2>();
+
+    let boxed: Box<dyn AudioUnit> = Box::new(graph);
+
+    let params = MetronomeParams {
+        bpm: bpm_param,
+        accent_every: accent_param,
+    };
+
+    (boxed, params)
+}
@@ -1,7 +1,113 @@
+pub mod alarm;
 pub mod arcane_attack;
+pub mod bell;
 pub mod blunt_impact;
+pub mod common;
+pub mod drum;
 pub mod ear_ringing;
+pub mod engine;
 pub mod explosion;
+pub mod fire;
+pub mod footstep;
+pub mod glass_break;
+pub mod gunshot;
 pub mod heartbeat;
+pub mod laser;
 pub mod lightning;
+pub mod metronome;
+pub mod pickup;
+pub mod pluck;
+pub mod power_up;
+pub mod rain;
+pub mod splash;
 pub mod sword_slash;
+pub mod teleport;
+pub mod test_tone;
+pub mod ui_blip;
+pub mod whoosh;
+pub mod wind;
+
+use fundsp::audiounit::AudioUnit;
+use std::any::TypeId;
+
+use crate::components::variation::Jitter;
+
+/// One-shot presets whose loudness is normalized to
+/// [`REFERENCE_RMS`](crate::dsp::analysis::REFERENCE_RMS).
+///
+/// The build systems scale every spawn by the gain that brings
+/// [`LoudnessReference::reference`] to the reference, so `intensity: 1.0`
+/// sounds about as loud across presets. The plugin measures every preset
+/// type's gain at startup (see [`reference_graphs`]).
+pub trait LoudnessReference: Default {
+    /// The configuration that is measured: full intensity, a fixed seed, and
+    /// defaults otherwise.
+    fn reference() -> Self;
+
+    /// The graph of [`LoudnessReference::reference`], without jitter.
+    fn reference_graph() -> Box<dyn AudioUnit>;
+}
+
+/// A preset builder's result, with or without live params.
+trait BuiltGraph {
+    fn into_graph(self) -> Box<dyn AudioUnit>;
+}
+
+impl BuiltGraph for Box<dyn AudioUnit> {
+    fn into_graph(self) -> Box<dyn AudioUnit> {
+        self
+    }
+}
+
+impl<Q> BuiltGraph for (Box<dyn AudioUnit>, Q) {
+    fn into_graph(self) -> Box<dyn AudioUnit> {
+        self.0
+    }
+}
+
+macro_rules! loudness_reference {
+    ($($preset:ty { $($field:ident: $value:expr),* } => $build:path),* $(,)?) => {
+        $(
+            impl LoudnessReference for $preset {
+                fn reference() -> Self {
+                    Self {
+                        $($field: $value,)*
+                        ..Default::default()
+                    }
+                }
+
+                fn reference_graph() -> Box<dyn AudioUnit> {
+                    $build(&Self::reference(), Jitter::NONE).into_graph()
+                }
+            }
+        )*
+
+        /// Every [`LoudnessReference`] preset type, with its reference graph.
+        pub fn reference_graphs() -> Vec<(TypeId, fn() -> Box<dyn AudioUnit>)> {
+            vec![$((TypeId::of::<$preset>(), <$preset>::reference_graph as fn() -> _)),*]
+        }
+    };
+}
+
+loudness_reference! {
+    arcane_attack::ArcaneAttack { intensity: 1.0 } => arcane_attack::build_arcane_attack_graph,
+    bell::Bell { intensity: 1.0 } => bell::build_bell_graph,
+    blunt_impact::BluntImpact { intensity: 1.0, seed: 0 } => blunt_impact::build_blunt_impact_graph,
+    drum::Drum { intensity: 1.0, seed: 0 } => drum::build_drum_graph,
+    explosion::Explosion { intensity: 1.0, seed: 0 } => explosion::build_explosion_graph,
+    footstep::Footstep { intensity: 1.0, seed: 0 } => footstep::build_footstep_graph,
+    glass_break::GlassBreak { intensity: 1.0, seed: 0 } => glass_break::build_glass_break_graph,
+    gunshot::Gunshot { intensity: 1.0, seed: 0 } => gunshot::build_gunshot_graph,
+    laser::Laser { intensity: 1.0 } => laser::build_laser_graph,
+    lightning::LightningStrike { intensity: 1.0, seed: 0 } =>
+        lightning::build_lightning_strike_graph,
+    lightning::LightningZap { intensity: 1.0 } => lightning::build_lightning_zap_graph,
+    pickup::Pickup {} => pickup::build_pickup_graph,
+    pluck::Pluck { intensity: 1.0 } => pluck::build_pluck_graph,
+    power_up::PowerUp { intensity: 1.0 } => power_up::build_power_up_graph,
+    splash::Splash { intensity: 1.0 } => splash::build_splash_graph,
+    sword_slash::SwordSlash { intensity: 1.0, seed: 0 } => sword_slash::build_sword_slash_graph,
+    teleport::Teleport {} => teleport::build_teleport_graph,
+    ui_blip::UiBlip {} => ui_blip::build_ui_blip_graph,
+    whoosh::Whoosh { intensity: 1.0 } => whoosh::build_whoosh_graph,
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::attack_decay;
+
+/// Major-pentatonic ladder in semitones above the root, two octaves deep.
+const PENTATONIC: [f32; 10] = [0.0, 2.0, 4.0, 7.0, 9.0, 12.0, 14.0, 16.0, 19.0, 21.0];
+
+/// Time between note onsets in seconds.
+const NOTE_SPACING: f32 = 0.06;
+
+/// Ring-out of the final note in seconds.
+const TAIL: f32 = 0.25;
+
+/// One-shot coin/pickup jingle — a quick ascending pentatonic arpeggio.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// Length grows with `steps`: ~60ms per note plus a short ring-out.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pickup {
+    /// Pitch multiplier (1.0 = normal, <1 = lower, >1 = higher).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch: f32,
+    /// Number of notes in the arpeggio (1–10).
+    pub steps: u8,
+}
+
+impl Default for Pickup {
+    fn default() -> Self {
+        Self {
+            pitch: 1.0,
+            steps: 2,
+        }
+    }
+}
+
+impl Pickup {
+    /// Note count clamped to the length of the interval ladder.
+    fn note_count(&self) -> usize {
+        (self.steps as usize).clamp(1, PENTATONIC.len())
+    }
+
+    /// Total sound length in seconds, including the last note's ring-out.
+    pub fn duration(&self) -> f32 {
+        (self.note_count() - 1) as f32 * NOTE_SPACING + TAIL
+    }
+}
+
+/// Build the pickup DSP graph. One-shot, no runtime params.
+///
+/// Each note is a sine/square blend gated on at its onset time, and the
+/// notes are summed in a `Net`. Earlier notes are cut short by the next one
+/// so the arpeggio stays crisp; only the last note rings out.
+pub fn build_pickup_graph(pu: &Pickup, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let root = 988.0 * pu.pitch * jitter.scale();
+    let stretch = jitter.scale();
+    let count = pu.note_count();
+
+    let mut mix = Net::wrap(Box::new(zero()));
+
+    for (i, semitones) in PENTATONIC.iter().take(count).enumerate() {
+        let freq = root * 2.0_f32.powf(semitones / 12.0);
+        let onset = i as f32 * NOTE_SPACING;
+        let last = i + 1 == count;
+        let length = if last { TAIL } else { NOTE_SPACING };
+        let level = 0.3 * jitter.scale();
+
+        let env = lfo(move |t: f32| -> f32 {
+            let local = t / stretch - onset;
+            if !(0.0..=length).contains(&local) {
+                return 0.0;
+            }
+            attack_decay(local, 1000.0, if last { 14.0 } else { 25.0 }) * level
+        });
+        let note = (sine_hz(freq) * dc(0.8) + square_hz(freq) * dc(0.12)) * env;
+
+        mix = mix + Net::wrap(Box::new(note));
+    }
+
+    let graph = mix >> Net::wrap(Box::new(split::<U2>()));
+    Box::new(graph)
+}
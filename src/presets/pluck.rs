@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+
+/// One-shot plucked string — guitar, harp, koto, pizzicato.
+/// Karplus-Strong: a noise burst circulating in a tuned, damped delay loop.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.3s (fully damped) to ~4s (undamped) then goes
+/// silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pluck {
+    /// String pitch in Hz. Clamped to 30–4000 Hz.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub freq_hz: f32,
+    /// How quickly the string is choked (0.0 = rings ~4s and bright,
+    /// 1.0 = dead, muted thunk). Clamped to 0.0–1.0.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub damping: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+}
+
+impl Default for Pluck {
+    fn default() -> Self {
+        Self {
+            freq_hz: 220.0,
+            damping: 0.3,
+            intensity: 0.7,
+        }
+    }
+}
+
+impl Pluck {
+    /// `damping` clamped to the supported range.
+    fn clamped_damping(&self) -> f32 {
+        self.damping.clamp(0.0, 1.0)
+    }
+
+    /// Time for the string to decay by 60 dB, in seconds.
+    pub fn duration(&self) -> f32 {
+        4.0 - 3.7 * self.clamped_damping()
+    }
+}
+
+/// Build the pluck DSP graph. One-shot, no runtime params.
+///
+/// The loop is `delay >> lowpole` inside `feedback`. The delay sets the
+/// period (less one sample for the feedback's own delay at 44.1 kHz); the
+/// low-pass dulls the tone a little more every pass, like a real string;
+/// and the loop gain is chosen so the tone falls 60 dB over `duration()`
+/// whatever the pitch.
+pub fn build_pluck_graph(pl: &Pluck, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = pl.intensity * jitter.scale();
+    let freq = (pl.freq_hz * jitter.scale()).clamp(30.0, 4000.0);
+    let damping = pl.clamped_damping();
+    let decay = pl.duration() * jitter.scale();
+
+    let period = 1.0 / freq;
+    let loop_delay = (period - 1.0 / 44100.0).max(0.0);
+    let loop_gain = 10.0_f32.powf(-3.0 * period / decay);
+    let brightness = (freq * (24.0 - 20.0 * damping)).min(16000.0);
+
+    // --- Excitation: one period of noise, the "pick" ---
+    let burst = lfo(move |t: f32| -> f32 {
+        if t < period {
+            int
+        } else {
+            0.0
+        }
+    });
+    let excitation = noise() * burst;
+
+    // --- String: tuned, damped feedback loop ---
+    let string = feedback((delay(loop_delay) >> lowpole_hz(brightness)) * loop_gain);
+
+    let graph = (excitation >> string) >> split::<U2>();
+    Box::new(graph)
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::{attack_ramp, stutter_gate};
+
+/// One-shot power-up/level-up reward cue.
+/// Two layers: an accelerating rising major chord and a climbing sparkle.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~1s then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerUp {
+    /// Pitch multiplier (1.0 = standard, >1 = higher, <1 = deeper).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch_shift: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+}
+
+impl Default for PowerUp {
+    fn default() -> Self {
+        Self {
+            pitch_shift: 1.0,
+            intensity: 0.7,
+            duration_scale: 1.0,
+        }
+    }
+}
+
+/// Length of the gliss in seconds; the chord holds its top note after this.
+const GLISS: f32 = 0.8;
+
+/// Pitch ratio of the gliss at time `t`: climbs two octaves, slow at first
+/// and accelerating toward the top (quadratic in time).
+fn gliss_ratio(t: f32) -> f32 {
+    let x = (t / GLISS).min(1.0);
+    2.0_f32.powf(2.0 * x * x)
+}
+
+/// Build the power-up DSP graph. One-shot, no runtime params.
+pub fn build_power_up_graph(pu: &PowerUp, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = pu.intensity;
+    let pitch = pu.pitch_shift * jitter.scale();
+    let stretch = pu.duration_scale.max(0.01) * jitter.scale();
+    let chord_int = int * jitter.scale();
+    let sparkle_int = int * jitter.scale();
+
+    // --- Layer 1: Rising chord ---
+    // Major-triad cluster (root, third, fifth, octave) gliding together,
+    // weighted like the arcane attack's harmonic cluster.
+    let root = 330.0 * pitch;
+    let partial = |ratio: f32| {
+        lfo(move |t: f32| -> f32 { root * ratio * gliss_ratio(t / stretch) }) >> sine()
+    };
+    let chord_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > 1.0 {
+            return 0.0;
+        }
+        let attack = attack_ramp(t, 40.0);
+        // Hold through the gliss, then fade out over the last 0.2s, squared
+        // so the tail is inaudible well before the end.
+        let release = ((1.0 - t) / 0.2).clamp(0.0, 1.0).powi(2);
+        attack * release * 0.12 * chord_int
+    });
+    let chord_layer =
+        (partial(1.0) + partial(1.25) * dc(0.8) + partial(1.5) * dc(0.6) + partial(2.0) * dc(0.4))
+            * dc(1.0 / 2.8)
+            * chord_env;
+
+    // --- Layer 2: Sparkle ---
+    // Stutter-gated band-passed noise (as in the arcane attack) whose center
+    // climbs with the chord, and whose grains speed up as it rises.
+    let sparkle_base = 3000.0 * pitch;
+    let sparkle_center =
+        lfo(move |t: f32| -> f32 { sparkle_base * gliss_ratio(t / stretch).sqrt() });
+    let sparkle_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > 1.0 {
+            return 0.0;
+        }
+        let onset = attack_ramp(t, 10.0);
+        let release = ((1.0 - t) / 0.3).clamp(0.0, 1.0);
+        // Grain rate follows the gliss, so sparkles accelerate with it.
+        let rate = gliss_ratio(t);
+        let stutter = stutter_gate(t * rate, &[41.0, 67.0]);
+        onset * release * stutter * 0.2 * sparkle_int
+    });
+    let sparkle_layer = ((noise() | sparkle_center | dc(2.0)) >> bandpass()) * sparkle_env;
+
+    // --- Mix and stereo ---
+    let graph = (chord_layer + sparkle_layer) >> split::<U2>();
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Fixed seed: identical cues render identically, so baking shares one buffer.
+    boxed.ping(false, AttoHash::new(0));
+    boxed
+}
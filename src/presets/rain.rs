@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::dsp::param::ParamHandle;
+
+/// Looping rain ambience — a high-passed hiss bed with scattered droplets.
+///
+/// Mutate fields at runtime; the sync system pushes changes to the audio thread.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Ambience)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rain {
+    /// Droplet rate (0.0 = sparse drizzle, 1.0 = downpour).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub density: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+}
+
+impl Default for Rain {
+    fn default() -> Self {
+        Self {
+            density: 0.5,
+            intensity: 0.5,
+        }
+    }
+}
+
+/// Runtime handles stored alongside the Rain entity.
+#[derive(Component)]
+pub struct RainParams {
+    pub density: ParamHandle,
+    pub intensity: ParamHandle,
+}
+
+/// Droplet gate: the product of two incommensurate sines is thresholded so
+/// only its peaks pass. A higher `density` lowers the threshold, letting
+/// more (and longer) peaks through. The result is cubed for sharp onsets.
+fn droplet_gate(t: f32, rate_a: f32, rate_b: f32, density: f32) -> f32 {
+    let tau = core::f32::consts::TAU;
+    let stutter = (t * rate_a * tau).sin() * (t * rate_b * tau).sin();
+    let threshold = 0.9 - 0.7 * density.clamp(0.0, 1.0);
+    let gate = ((stutter - threshold) / (1.0 - threshold)).max(0.0);
+    gate * gate * gate
+}
+
+/// Build the rain DSP graph and return (graph, params).
+///
+/// Layers:
+/// - Hiss: dense high-passed noise, the constant wash of rainfall.
+/// - Droplets: three band-passed noise layers at different pitches, each
+///   gated by stuttered sines at unrelated rates so hits never line up.
+pub fn build_rain_graph(rain: &Rain) -> (Box<dyn AudioUnit>, RainParams) {
+    let density_param = ParamHandle::new("density", rain.density, 0.0, 1.0);
+    let intensity_param = ParamHandle::new("intensity", rain.intensity, 0.0, 1.0);
+
+    let density_s = density_param.shared().clone();
+    let intensity_s = intensity_param.shared().clone();
+
+    // --- Hiss bed ---
+    // Heavier rain is louder; density adds to the bed as well.
+    let hiss_density = density_s.clone();
+    let hiss_gain = lfo(move |_t: f32| -> f32 { 0.12 + 0.1 * hiss_density.value() });
+    let hiss = (noise() >> highpole_hz(2500.0) >> lowpole_hz(9000.0)) * hiss_gain;
+
+    // --- Droplets ---
+    let droplet = |center: f32, rate_a: f32, rate_b: f32, level: f32| {
+        let d = density_s.clone();
+        let env = lfo(move |t: f32| -> f32 {
+            droplet_gate(t, rate_a, rate_b, d.value()) * level
+        });
+        (noise() >> bandpass_hz(center, 4.0)) * env
+    };
+    let droplets = droplet(2800.0, 13.7, 7.3, 0.5)
+        + droplet(4200.0, 17.9, 11.1, 0.4)
+        + droplet(1700.0, 9.1, 5.3, 0.45);
+
+    // Droplets alternate slightly between channels for width.
+    let left = (hiss.clone() + droplets.clone() * dc(0.8)) * var(&intensity_s);
+    let right = (hiss + droplets * dc(1.2)) * var(&intensity_s);
+    let graph = left | right;
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Give every noise source its own seed so the cloned layers and the two
+    // channels don't play identical noise.
+    boxed.ping(false, AttoHash::new(0));
+
+    let params = RainParams {
+        density: density_param,
+        intensity: intensity_param,
+    };
+
+    (boxed, params)
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::ad_env;
+
+/// One-shot water splash — objects, footsteps, or bodies hitting water.
+/// Two layers: band-passed spray burst and a pitched body "bloop".
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.4s (small) to ~1.2s (large) then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Splash {
+    /// Size of the object hitting the water (0.1 = pebble, 1.0 = rock, 3.0 = body).
+    /// Larger splashes have a lower body and a longer decay.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub size: f32,
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+}
+
+impl Default for Splash {
+    fn default() -> Self {
+        Self {
+            size: 1.0,
+            intensity: 0.7,
+        }
+    }
+}
+
+impl Splash {
+    /// `size` clamped to the supported range.
+    fn clamped_size(&self) -> f32 {
+        self.size.clamp(0.1, 3.0)
+    }
+
+    /// Total sound length in seconds, scaled by `size`.
+    pub fn duration(&self) -> f32 {
+        0.4 + 0.4 * self.clamped_size()
+    }
+}
+
+/// Build the splash DSP graph. One-shot, no runtime params.
+pub fn build_splash_graph(sp: &Splash, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = sp.intensity;
+    let size = sp.clamped_size();
+    let length = sp.duration();
+    let pitch = jitter.scale();
+    let stretch = jitter.scale();
+    let spray_int = int * jitter.scale();
+    let bloop_int = int * jitter.scale();
+
+    // Bigger splashes ring lower and decay slower.
+    let decay_scale = 1.0 / size.sqrt();
+
+    // --- Layer 1: Spray (broadband burst through a moving band-pass) ---
+    // The band opens fast from 600 Hz up to ~4 kHz as the surface breaks,
+    // then closes back down as the spray falls.
+    let spray_lo = 600.0 * pitch;
+    let spray_range = 3400.0 * pitch * decay_scale.min(1.5);
+    let spray_center = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        let open = (t * 60.0).min(1.0);
+        let close = (-t * 6.0 * decay_scale).exp();
+        spray_lo + spray_range * open * close
+    });
+    let spray_env = ad_env(400.0, 7.0 * decay_scale, length, stretch) * (0.45 * spray_int);
+    let spray_layer = ((noise() | spray_center | dc(1.2)) >> bandpass()) * spray_env;
+
+    // --- Layer 2: Bloop (pitched body, like the explosion boom but higher) ---
+    // Sine whose frequency drops quickly from its strike pitch, giving the
+    // hollow "plunk" of an object entering the water.
+    let bloop_hi = 520.0 * pitch * decay_scale;
+    let bloop_lo = 180.0 * pitch * decay_scale;
+    let bloop_freq = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        bloop_lo + (bloop_hi - bloop_lo) * (-t * 25.0 * decay_scale).exp()
+    });
+    let bloop_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > 0.4 / decay_scale {
+            return 0.0;
+        }
+        let attack = ((t - 0.01) * 300.0).clamp(0.0, 1.0);
+        let decay = (-t * 14.0 * decay_scale).exp();
+        attack * decay * 0.3 * bloop_int
+    });
+    let bloop_layer = (bloop_freq >> sine()) * bloop_env;
+
+    // --- Mix and stereo ---
+    let graph = (spray_layer + bloop_layer) >> split::<U2>();
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Fixed seed: identical splashes render identically, so baking shares one buffer.
+    boxed.ping(false, AttoHash::new(0));
+    boxed
+}
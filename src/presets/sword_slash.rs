@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 use fundsp::prelude32::*;
 
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::{ad_env, apply_reverb, ReverbShape};
+
 /// One-shot sword slash — FM synthesis of a metal blade impact.
 ///
 /// Uses FM (frequency modulation) synthesis with high modulation indices
@@ -11,13 +15,28 @@ use fundsp::prelude32::*;
 ///
 /// Spawn an entity with this component to trigger the sound.
 #[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SwordSlash {
     /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub intensity: f32,
     /// Pitch multiplier (1.0 = normal, <1 = lower, >1 = higher). Use for variance.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub pitch_shift: f32,
     /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet). Adds cave-like ambience.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
     pub reverb_mix: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+    /// Noise seed. The same seed reproduces the same waveform; defaults to random.
+    #[cfg_attr(feature = "serde", serde(default = "rand::random"))]
+    pub seed: u64,
 }
 
 impl Default for SwordSlash {
@@ -26,15 +45,23 @@ impl Default for SwordSlash {
             intensity: 0.8,
             pitch_shift: 1.0,
             reverb_mix: 0.0,
+            duration_scale: 1.0,
+            seed: rand::random(),
         }
     }
 }
 
 /// Build the sword slash DSP graph. One-shot, no runtime params.
-pub fn build_sword_slash_graph(ss: &SwordSlash) -> Box<dyn AudioUnit> {
+pub fn build_sword_slash_graph(ss: &SwordSlash, mut jitter: Jitter) -> Box<dyn AudioUnit> {
     let int = ss.intensity;
-    let pitch = ss.pitch_shift;
+    let pitch = ss.pitch_shift * jitter.scale();
     let reverb_mix = ss.reverb_mix;
+    let seed = ss.seed;
+    let stretch = ss.duration_scale.max(0.01) * jitter.scale();
+    let v1_int = int * jitter.scale();
+    let v2_int = int * jitter.scale();
+    let v3_int = int * jitter.scale();
+    let noise_int = int * jitter.scale();
 
     // --- FM Voice 1: Low metallic body ---
     // Carrier 720 Hz, modulator 487 Hz (inharmonic ratio ~1.48).
@@ -45,14 +72,9 @@ pub fn build_sword_slash_graph(ss: &SwordSlash) -> Box<dyn AudioUnit> {
     let v1 = {
         let fm = (dc(v1_carrier)
             + sine_hz(v1_mod)
-                * lfo(move |t: f32| -> f32 { 20.0 * v1_mod * (-t * 3.0).exp() }))
+                * lfo(move |t: f32| -> f32 { 20.0 * v1_mod * (-t / stretch * 3.0).exp() }))
             >> sine();
-        let env = lfo(move |t: f32| -> f32 {
-            if t > 1.2 {
-                return 0.0;
-            }
-            (t * 500.0).min(1.0) * (-t * 6.0).exp() * 0.02 * int
-        });
+        let env = ad_env(500.0, 6.0, 1.2, stretch) * (0.02 * v1_int);
         fm * env
     };
 
@@ -63,14 +85,9 @@ pub fn build_sword_slash_graph(ss: &SwordSlash) -> Box<dyn AudioUnit> {
     let v2 = {
         let fm = (dc(v2_carrier)
             + sine_hz(v2_mod)
-                * lfo(move |t: f32| -> f32 { 18.0 * v2_mod * (-t * 5.0).exp() }))
+                * lfo(move |t: f32| -> f32 { 18.0 * v2_mod * (-t / stretch * 5.0).exp() }))
             >> sine();
-        let env = lfo(move |t: f32| -> f32 {
-            if t > 0.6 {
-                return 0.0;
-            }
-            (t * 500.0).min(1.0) * (-t * 10.0).exp() * 0.015 * int
-        });
+        let env = ad_env(500.0, 10.0, 0.6, stretch) * (0.015 * v2_int);
         fm * env
     };
 
@@ -81,14 +98,9 @@ pub fn build_sword_slash_graph(ss: &SwordSlash) -> Box<dyn AudioUnit> {
     let v3 = {
         let fm = (dc(v3_carrier)
             + sine_hz(v3_mod)
-                * lfo(move |t: f32| -> f32 { 12.0 * v3_mod * (-t * 8.0).exp() }))
+                * lfo(move |t: f32| -> f32 { 12.0 * v3_mod * (-t / stretch * 8.0).exp() }))
             >> sine();
-        let env = lfo(move |t: f32| -> f32 {
-            if t > 0.3 {
-                return 0.0;
-            }
-            (t * 500.0).min(1.0) * (-t * 15.0).exp() * 0.008 * int
-        });
+        let env = ad_env(500.0, 15.0, 0.3, stretch) * (0.008 * v3_int);
         fm * env
     };
 
@@ -96,25 +108,25 @@ pub fn build_sword_slash_graph(ss: &SwordSlash) -> Box<dyn AudioUnit> {
     // Dynamic cutoff 10kHz -> 300Hz creates the "whoosh" quality.
     let noise_base = 300.0 * pitch;
     let noise_range = 9700.0 * pitch;
-    let cutoff = lfo(move |t: f32| -> f32 { noise_base + noise_range * (-t * 8.0).exp() });
-    let noise_env = lfo(move |t: f32| -> f32 {
-        if t > 0.5 {
-            return 0.0;
-        }
-        (t * 1000.0).min(1.0) * (-t * 10.0).exp() * 0.07 * int
+    let cutoff = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        noise_base + noise_range * (-t * 8.0).exp()
     });
+    let noise_env = ad_env(1000.0, 10.0, 0.5, stretch) * (0.07 * noise_int);
     let noise_layer = ((noise() | cutoff) >> lowpole()) * noise_env;
 
     // --- Mix all layers and split to stereo ---
     let graph = (v1 + v2 + v3 + noise_layer) >> split::<U2>();
 
-    if reverb_mix > 0.001 {
-        let reverb = reverb2_stereo(0.3, 0.6, 0.4, 1.0, lowpole_hz(5000.0));
-        let dry = 1.0 - reverb_mix;
-        let wet = reverb_mix;
-        let mixed = (graph.clone() * dc((dry, dry))) + (graph >> reverb) * dc((wet, wet));
-        Box::new(mixed)
-    } else {
-        Box::new(graph)
-    }
+    let room = ReverbShape {
+        room_size: 0.3,
+        time: 0.6,
+        diffusion: 0.4,
+        cutoff_hz: 5000.0,
+    };
+    let mut boxed: Box<dyn AudioUnit> =
+        Box::new(apply_reverb(Net::wrap(Box::new(graph)), reverb_mix, room));
+    // Reseed every noise source in the graph so the seed fully determines the output.
+    boxed.ping(false, AttoHash::new(seed));
+    boxed
 }
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::{apply_reverb, attack_decay, ReverbShape};
+
+/// One-shot teleport/warp sound effect.
+/// Two phases: a rising detuned shimmer that collapses into a filtered whoosh.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.6s then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Teleport {
+    /// Pitch multiplier (1.0 = standard, >1 = higher, <1 = deeper).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch_shift: f32,
+    /// Reverb wet/dry mix (0.0 = dry, 1.0 = fully wet).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub reverb_mix: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+}
+
+impl Default for Teleport {
+    fn default() -> Self {
+        Self {
+            pitch_shift: 1.0,
+            reverb_mix: 0.25,
+            duration_scale: 1.0,
+        }
+    }
+}
+
+/// Build the teleport DSP graph. One-shot, no runtime params.
+pub fn build_teleport_graph(tp: &Teleport, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let pitch = tp.pitch_shift * jitter.scale();
+    let reverb_mix = tp.reverb_mix;
+    let stretch = tp.duration_scale.max(0.01) * jitter.scale();
+    let shimmer_level = 0.12 * jitter.scale();
+    let whoosh_level = 0.35 * jitter.scale();
+
+    // --- Layer 1: Rising shimmer ---
+    // Two detuned sine clusters (like the arcane attack's shimmer core) that
+    // glide up an octave over 0.35s, swelling in as they rise.
+    let base_a = 660.0 * pitch;
+    let base_b = 990.0 * pitch;
+    // 7 cents ≈ multiply by 2^(7/1200)
+    let detune_up = 1.004052_f32;
+    let detune_dn = 1.0 / detune_up;
+    let glide = |freq: f32| {
+        lfo(move |t: f32| -> f32 {
+            let rise = (t / stretch / 0.35).min(1.0);
+            freq * (1.0 + rise * rise)
+        }) >> sine()
+    };
+    let shimmer_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if t > 0.4 {
+            return 0.0;
+        }
+        // Swell up to the collapse point, then cut away fast.
+        let swell = (t / 0.35).min(1.0);
+        let collapse = (-(t - 0.35).max(0.0) * 60.0).exp();
+        swell * swell * collapse * shimmer_level
+    });
+    let shimmer_layer = (glide(base_a)
+        + glide(base_a * detune_up)
+        + glide(base_a * detune_dn)
+        + glide(base_b)
+        + glide(base_b * detune_up)
+        + glide(base_b * detune_dn))
+        * dc(1.0 / 6.0)
+        * shimmer_env;
+
+    // --- Layer 2: Collapse whoosh ---
+    // Noise through a closing lowpass (like the sword slash), starting as the
+    // shimmer collapses: 8kHz → 250Hz.
+    let whoosh_base = 250.0 * pitch;
+    let whoosh_range = 7750.0 * pitch;
+    let cutoff = lfo(move |t: f32| -> f32 {
+        let local = (t / stretch - 0.3).max(0.0);
+        whoosh_base + whoosh_range * (-local * 9.0).exp()
+    });
+    let whoosh_env = lfo(move |t: f32| -> f32 {
+        let t = t / stretch;
+        if !(0.3..=0.6).contains(&t) {
+            return 0.0;
+        }
+        let local = t - 0.3;
+        attack_decay(local, 200.0, 12.0) * whoosh_level
+    });
+    let whoosh_layer = ((noise() | cutoff) >> lowpole()) * whoosh_env;
+
+    // --- Mix and stereo ---
+    let graph = (shimmer_layer + whoosh_layer) >> split::<U2>();
+
+    let room = ReverbShape {
+        room_size: 0.5,
+        time: 1.2,
+        diffusion: 0.6,
+        cutoff_hz: 4000.0,
+    };
+    let mut boxed: Box<dyn AudioUnit> =
+        Box::new(apply_reverb(Net::wrap(Box::new(graph)), reverb_mix, room));
+    // Fixed seed: identical teleports render identically, so baking shares one buffer.
+    boxed.ping(false, AttoHash::new(0));
+    boxed
+}
@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+use std::f32::consts::FRAC_1_SQRT_2;
+use std::sync::OnceLock;
+
+use crate::components::mixer::MixBus;
+use crate::dsp::analysis::measure_rms;
+
+/// Level of the reference signal: a sine peaking at -18 dBFS, and noise at
+/// the same RMS.
+pub const TEST_TONE_DB: f32 = -18.0;
+
+/// Length of one `TestKind::Sweep` pass, in seconds.
+const SWEEP_SECS: f32 = 10.0;
+/// Range of `TestKind::Sweep`, in Hz.
+const SWEEP_HZ: (f32, f32) = (20.0, 20000.0);
+
+/// What a [`TestTone`] plays.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TestKind {
+    /// Steady sine at `freq_hz`.
+    #[default]
+    Sine,
+    /// Flat-spectrum noise.
+    WhiteNoise,
+    /// Noise falling 3 dB per octave, closer to how program material sits.
+    PinkNoise,
+    /// Logarithmic sine sweep from 20 Hz to 20 kHz over 10 s, repeating.
+    Sweep,
+}
+
+/// Continuous calibration signal at a fixed -18 dBFS, for checking that
+/// audio comes out at all, which speaker it comes out of, and at what level.
+///
+/// Plays identically on both channels until despawned. The level is
+/// measured before the mixer, so it is exact with every `Mixer` volume at
+/// 1.0. Read when spawned.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TestTone {
+    /// Frequency of `TestKind::Sine` in Hz; the other kinds ignore it.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub freq_hz: f32,
+    pub kind: TestKind,
+}
+
+impl Default for TestTone {
+    fn default() -> Self {
+        Self {
+            freq_hz: 1000.0,
+            kind: TestKind::Sine,
+        }
+    }
+}
+
+/// Gain bringing unit pink noise to the RMS of a unit sine, measured once.
+fn pink_gain() -> f32 {
+    static GAIN: OnceLock<f32> = OnceLock::new();
+    *GAIN.get_or_init(|| {
+        let mut reference: Box<dyn AudioUnit> = Box::new(pink());
+        reference.ping(false, AttoHash::new(0));
+        FRAC_1_SQRT_2 / measure_rms(&mut *reference, 2.0).max(f32::EPSILON)
+    })
+}
+
+/// Build the test tone DSP graph. Continuous, no runtime params.
+pub fn build_test_tone_graph(tone: &TestTone) -> Box<dyn AudioUnit> {
+    let level = db_amp(TEST_TONE_DB);
+    let mono: Box<dyn AudioUnit> = match tone.kind {
+        TestKind::Sine => Box::new(sine_hz(tone.freq_hz.clamp(1.0, 20000.0)) * level),
+        // Uniform white noise has an RMS of 1/sqrt(3).
+        TestKind::WhiteNoise => Box::new(noise() * (level * FRAC_1_SQRT_2 * 3.0_f32.sqrt())),
+        TestKind::PinkNoise => Box::new(pink() * (level * pink_gain())),
+        TestKind::Sweep => {
+            let ratio = SWEEP_HZ.1 / SWEEP_HZ.0;
+            let freq = lfo(move |t: f32| -> f32 {
+                SWEEP_HZ.0 * ratio.powf((t % SWEEP_SECS) / SWEEP_SECS)
+            });
+            Box::new((freq >> sine()) * level)
+        }
+    };
+    Box::new(Net::wrap(mono) >> Net::wrap(Box::new(split::<U2>())))
+}
@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+use crate::presets::common::ad_env;
+
+/// Which UI cue a [`UiBlip`] plays.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlipKind {
+    /// Short bright tick for button presses.
+    #[default]
+    Click,
+    /// Two-note rising chirp.
+    Confirm,
+    /// Two-note falling chirp.
+    Cancel,
+    /// Soft, quiet tone for focus changes.
+    Hover,
+}
+
+/// Per-kind tuning for the blip.
+struct BlipProfile {
+    /// Tone frequency for the first half of the blip, in Hz.
+    start_hz: f32,
+    /// Tone frequency for the second half, in Hz.
+    end_hz: f32,
+    /// Total length in seconds.
+    duration: f32,
+    /// Crossfade between sine (0.0) and triangle (1.0).
+    triangle: f32,
+    level: f32,
+    tick: f32,
+}
+
+impl BlipKind {
+    fn profile(self) -> BlipProfile {
+        match self {
+            BlipKind::Click => BlipProfile {
+                start_hz: 1800.0,
+                end_hz: 1800.0,
+                duration: 0.03,
+                triangle: 0.0,
+                level: 0.35,
+                tick: 0.15,
+            },
+            BlipKind::Confirm => BlipProfile {
+                start_hz: 880.0,
+                end_hz: 1320.0,
+                duration: 0.11,
+                triangle: 1.0,
+                level: 0.3,
+                tick: 0.05,
+            },
+            BlipKind::Cancel => BlipProfile {
+                start_hz: 660.0,
+                end_hz: 440.0,
+                duration: 0.11,
+                triangle: 1.0,
+                level: 0.3,
+                tick: 0.05,
+            },
+            BlipKind::Hover => BlipProfile {
+                start_hz: 1200.0,
+                end_hz: 1200.0,
+                duration: 0.04,
+                triangle: 0.0,
+                level: 0.15,
+                tick: 0.0,
+            },
+        }
+    }
+}
+
+/// One-shot UI sound — menu clicks, confirms, cancels, and hovers.
+/// A short shaped sine/triangle tone with a tiny noise tick on the attack.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for under 120ms then goes silent. The output is fully
+/// determined by the fields, so pair with `BakedOneShot` for busy menus.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Ui)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiBlip {
+    /// Pitch multiplier (1.0 = normal, <1 = lower, >1 = higher).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch: f32,
+    /// Which cue to play.
+    pub kind: BlipKind,
+}
+
+impl Default for UiBlip {
+    fn default() -> Self {
+        Self {
+            pitch: 1.0,
+            kind: BlipKind::default(),
+        }
+    }
+}
+
+/// Build the UI blip DSP graph. One-shot, no runtime params.
+pub fn build_ui_blip_graph(ub: &UiBlip, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let pitch = ub.pitch * jitter.scale();
+    let p = ub.kind.profile();
+    let duration = p.duration * jitter.scale();
+    let level = p.level * jitter.scale();
+
+    // Step from the first note to the second halfway through.
+    let start_hz = p.start_hz * pitch;
+    let end_hz = p.end_hz * pitch;
+    let freq = lfo(move |t: f32| -> f32 {
+        if t < duration * 0.5 {
+            start_hz
+        } else {
+            end_hz
+        }
+    });
+    let tone = (freq.clone() >> sine()) * dc(1.0 - p.triangle)
+        + (freq >> triangle()) * dc(p.triangle);
+
+    // Single envelope: 1ms attack ramp to avoid a click, exponential decay,
+    // hard stop at the end of the blip.
+    let env = ad_env(1000.0, 4.0 / duration, duration, 1.0) * level;
+
+    // Tiny noise tick on the attack for definition.
+    let tick_level = p.tick * jitter.scale();
+    let tick_env = lfo(move |t: f32| -> f32 {
+        if t > 0.005 {
+            return 0.0;
+        }
+        (1.0 - t / 0.005) * tick_level
+    });
+    let tick = (noise() >> highpole_hz(4000.0)) * tick_env;
+
+    let graph = (tone * env + tick) >> split::<U2>();
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Fixed seed: identical blips render identically, so baking shares one buffer.
+    boxed.ping(false, AttoHash::new(0));
+    boxed
+}
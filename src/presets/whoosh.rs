@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::components::variation::Jitter;
+
+/// One-shot air whoosh — dodges, empty melee swings, camera transitions.
+/// A single layer of band-passed noise whose band sweeps up and back down.
+///
+/// Spawn an entity with this component to trigger the sound.
+/// The sound plays for ~0.35s then goes silent.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Sfx)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Whoosh {
+    /// Overall intensity (0.0–1.0).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// Pitch multiplier (1.0 = standard, >1 = thinner/faster blade, <1 = heavier swing).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub pitch_shift: f32,
+    /// Time stretch for the envelopes (1.0 = normal, 2.0 = twice as long,
+    /// "slow-motion"). Pitch is unaffected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "crate::serde_util::one", deserialize_with = "crate::serde_util::finite")
+    )]
+    pub duration_scale: f32,
+}
+
+impl Default for Whoosh {
+    fn default() -> Self {
+        Self {
+            intensity: 0.7,
+            pitch_shift: 1.0,
+            duration_scale: 1.0,
+        }
+    }
+}
+
+/// Length of the swing in seconds.
+const SWING: f32 = 0.35;
+
+/// Swing progress curve: 0 at both ends, 1 at the midpoint.
+fn swing_bell(t: f32) -> f32 {
+    if !(0.0..=SWING).contains(&t) {
+        return 0.0;
+    }
+    (t / SWING * std::f32::consts::PI).sin()
+}
+
+/// Build the whoosh DSP graph. One-shot, no runtime params.
+pub fn build_whoosh_graph(wh: &Whoosh, mut jitter: Jitter) -> Box<dyn AudioUnit> {
+    let int = wh.intensity * jitter.scale();
+    let pitch = wh.pitch_shift * jitter.scale();
+    let stretch = wh.duration_scale.max(0.01) * jitter.scale();
+
+    // Band center rises from 350 Hz to 2.4 kHz at mid-swing, then falls back.
+    // Unlike the sword slash there are no FM partials, so it stays airy.
+    let center_lo = 350.0 * pitch;
+    let center_range = 2050.0 * pitch;
+    let center = move |t: f32| center_lo + center_range * swing_bell(t / stretch);
+
+    // Band-pass from a lowpole above the center and a highpole below it.
+    let lp_cutoff = lfo(move |t: f32| -> f32 { center(t) * 1.6 });
+    let hp_cutoff = lfo(move |t: f32| -> f32 { center(t) * 0.6 });
+
+    // Bell-shaped swell; squaring narrows it so the peak feels like a pass-by.
+    let env = lfo(move |t: f32| -> f32 {
+        let bell = swing_bell(t / stretch);
+        bell * bell * 0.5 * int
+    });
+    let air = (((noise() | lp_cutoff) >> lowpole()) | hp_cutoff) >> highpole();
+
+    let graph = (air * env) >> split::<U2>();
+
+    let mut boxed: Box<dyn AudioUnit> = Box::new(graph);
+    // Fixed seed: identical whooshes render identically, so baking shares one buffer.
+    boxed.ping(false, AttoHash::new(0));
+    boxed
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use fundsp::prelude32::*;
+
+use crate::components::mixer::MixBus;
+use crate::dsp::param::ParamHandle;
+
+/// Looping wind ambience — brown noise through a wandering band-pass.
+///
+/// Mutate fields at runtime; the sync system pushes changes to the audio thread.
+#[derive(Component, Debug, Clone)]
+#[require(MixBus = MixBus::Ambience)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wind {
+    /// Overall intensity (0.0–1.0). Controls volume and brightness.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub intensity: f32,
+    /// How much the wind gusts (0.0 = steady breeze, 1.0 = strong irregular gusts).
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::serde_util::finite"))]
+    pub gustiness: f32,
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self {
+            intensity: 0.5,
+            gustiness: 0.5,
+        }
+    }
+}
+
+/// Runtime handles stored alongside the Wind entity.
+#[derive(Component)]
+pub struct WindParams {
+    pub intensity: ParamHandle,
+    pub gustiness: ParamHandle,
+}
+
+/// Slow gust contour in -1.0..1.0 from incommensurate sines, so the
+/// pattern never audibly repeats.
+fn gust(t: f32, offset: f32) -> f32 {
+    let tau = core::f32::consts::TAU;
+    (tau * 0.07 * t + offset).sin() * 0.5
+        + (tau * 0.19 * t + offset * 1.7).sin() * 0.3
+        + (tau * 0.43 * t + offset * 2.3).sin() * 0.2
+}
+
+/// Build the wind DSP graph and return (graph, params).
+///
+/// Brown noise is band-passed with a center frequency and level that wander
+/// together: gusts are louder and brighter. `gustiness` scales the wander
+/// depth; the left and right channels use offset contours for width.
+pub fn build_wind_graph(wind: &Wind) -> (Box<dyn AudioUnit>, WindParams) {
+    let intensity_param = ParamHandle::new("intensity", wind.intensity, 0.0, 1.0);
+    let gustiness_param = ParamHandle::new("gustiness", wind.gustiness, 0.0, 1.0);
+
+    let intensity_s = intensity_param.shared().clone();
+    let gustiness_s = gustiness_param.shared().clone();
+
+    let channel = |offset: f32| {
+        let center_i = intensity_s.clone();
+        let center_g = gustiness_s.clone();
+        let center = lfo(move |t: f32| -> f32 {
+            let base = 300.0 + 500.0 * center_i.value();
+            base * (1.0 + 0.6 * center_g.value() * gust(t, offset))
+        });
+
+        let gain_i = intensity_s.clone();
+        let gain_g = gustiness_s.clone();
+        let gain = lfo(move |t: f32| -> f32 {
+            let swell = 1.0 + 0.8 * gain_g.value() * gust(t, offset);
+            swell.max(0.05) * gain_i.value() * 0.6
+        });
+
+        ((brown() | center | dc(1.2)) >> bandpass()) * gain
+    };
+
+    let graph = channel(0.0) | channel(1.3);
+
+    let boxed: Box<dyn AudioUnit> = Box::new(graph);
+
+    let params = WindParams {
+        intensity: intensity_param,
+        gustiness: gustiness_param,
+    };
+
+    (boxed, params)
+}
@@ -0,0 +1,62 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::Arc;
+
+/// Deserialize an `f32`, rejecting NaN and infinities.
+///
+/// A non-finite parameter would poison the DSP graph (every sample after it
+/// becomes NaN), so it is refused at load time instead.
+pub(crate) fn finite<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f32, D::Error> {
+    let value = f32::deserialize(deserializer)?;
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(D::Error::custom(format!(
+            "expected a finite number, got {value}"
+        )))
+    }
+}
+
+/// Default for gain and scale fields added after their struct shipped, so
+/// older files deserialize unchanged.
+pub(crate) fn one() -> f32 {
+    1.0
+}
+
+/// Deserialize a wavetable's samples, rejecting an empty table and
+/// non-finite samples.
+pub(crate) fn finite_table<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Arc<Vec<f32>>, D::Error> {
+    let table = Vec::<f32>::deserialize(deserializer)?;
+    if table.is_empty() {
+        return Err(D::Error::custom("expected at least one wavetable sample"));
+    }
+    if let Some(value) = table.iter().find(|value| !value.is_finite()) {
+        return Err(D::Error::custom(format!(
+            "expected finite wavetable samples, got {value}"
+        )));
+    }
+    Ok(Arc::new(table))
+}
+
+/// Deserialize a list of `f32`s, rejecting NaN and infinities.
+pub(crate) fn finite_list<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<f32>, D::Error> {
+    let list = Vec::<f32>::deserialize(deserializer)?;
+    if let Some(value) = list.iter().find(|value| !value.is_finite()) {
+        return Err(D::Error::custom(format!(
+            "expected finite numbers, got {value}"
+        )));
+    }
+    Ok(list)
+}
+
+/// Serialize a shared wavetable as a plain list of samples.
+pub(crate) fn serialize_table<S: Serializer>(
+    table: &Arc<Vec<f32>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    table.as_slice().serialize(serializer)
+}
@@ -1,23 +1,415 @@
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
+use fundsp::audiounit::AudioUnit;
+use fundsp::prelude32::{dc, join, Net, U2};
+use rand::Rng;
+use std::any::TypeId;
+use std::collections::HashSet;
+use std::fmt::Debug;
 
-use crate::components::lifetime::OneShotLifetime;
-use crate::components::effect::{Delay, Distortion, Reverb};
-use crate::components::filter::{BandPass, HighPass, LowPass};
-use crate::components::synth::{Amplitude, Frequency, OscillatorType, Synth};
-use crate::dsp::graph_builder::build_synth_graph;
-use crate::dsp::source::ProceduralAudio;
+use crate::components::effect::{
+    BassEnhancer, ConvolutionReverb, Delay, Distortion, Eq3, Gate, Reverb, ReverbSend, RingMod,
+};
+use crate::components::fade::{Crossfade, Fade};
+use crate::components::filter::{AllPass, BandPass, Formant, HighPass, LowPass, Resonator};
+use crate::components::level::{AudioLevel, TransientDetect};
+use crate::components::lifetime::{BakedOneShot, OneShotLifetime, Retrigger};
+use crate::components::mixer::{MixBus, SideChain};
+use crate::components::playback::PlaybackRate;
+use crate::components::polyphony::Polyphony;
+use crate::components::spatial::{Occlusion, Pan, SpatialEmitter};
+use crate::components::synth::{
+    Amplitude, Frequency, HarmonicOsc, OscSync, OscillatorType, StereoSynth, Synth,
+    SynthBundle, SynthConfigHandle, Wavetable,
+};
+use crate::components::variation::{Jitter, Variation};
+use crate::config::{AudioConfig, ProcAudRng, SynthConfig};
+use crate::dsp::analysis::normalization_gain;
+use crate::dsp::bake::BakeCache;
+use crate::dsp::bass::build_bass_enhancer_stage;
+use crate::dsp::convolution::ImpulseResponse;
+use crate::dsp::fade::{build_fade_stage, FadeParams};
+use crate::dsp::graph_builder::{GraphSpec, SynthParams};
+use crate::dsp::param::ParamHandle;
+use crate::dsp::playback::{build_playback_stage, PlaybackParams};
+use crate::dsp::reverb_bus::{build_reverb_bus_graph, ReverbBus, ReverbSendParams, ReverbSendTap};
+use crate::dsp::side_chain::{build_side_chain_stage, SideChainParams};
+use crate::dsp::source::{OutputSettings, ProceduralAudio};
+use crate::dsp::spatial::{
+    build_occlusion_stage, build_pan_stage, build_spatial_stage, OcclusionParams, PanParams,
+    SpatialParams,
+};
+#[cfg(debug_assertions)]
+use crate::dsp::validate::check_graph;
+use crate::presets::alarm::{build_alarm_graph, Alarm};
 use crate::presets::arcane_attack::{build_arcane_attack_graph, ArcaneAttack};
+use crate::presets::bell::{build_bell_graph, Bell};
 use crate::presets::blunt_impact::{build_blunt_impact_graph, BluntImpact};
+use crate::presets::drum::{build_drum_graph, Drum};
 use crate::presets::ear_ringing::{build_ear_ringing_graph, EarRinging};
+use crate::presets::engine::{build_engine_graph, Engine};
 use crate::presets::explosion::{build_explosion_graph, Explosion};
+use crate::presets::fire::{build_fire_graph, Fire};
+use crate::presets::footstep::{build_footstep_graph, Footstep};
+use crate::presets::glass_break::{build_glass_break_graph, GlassBreak};
+use crate::presets::gunshot::{build_gunshot_graph, Gunshot};
 use crate::presets::heartbeat::{build_heartbeat_graph, Heartbeat};
+use crate::presets::laser::{build_laser_graph, Laser};
 use crate::presets::lightning::{
     build_lightning_strike_graph, build_lightning_zap_graph, LightningStrike, LightningZap,
 };
+use crate::presets::metronome::{build_metronome_graph, Metronome};
+use crate::presets::pickup::{build_pickup_graph, Pickup};
+use crate::presets::pluck::{build_pluck_graph, Pluck};
+use crate::presets::power_up::{build_power_up_graph, PowerUp};
+use crate::presets::rain::{build_rain_graph, Rain};
+use crate::presets::splash::{build_splash_graph, Splash};
 use crate::presets::sword_slash::{build_sword_slash_graph, SwordSlash};
+use crate::presets::teleport::{build_teleport_graph, Teleport};
+use crate::presets::test_tone::{build_test_tone_graph, TestTone};
+use crate::presets::ui_blip::{build_ui_blip_graph, UiBlip};
+use crate::presets::whoosh::{build_whoosh_graph, Whoosh};
+use crate::presets::wind::{build_wind_graph, Wind};
+use crate::presets::{reference_graphs, LoudnessReference};
 
 const SAMPLE_RATE: u32 = 44100;
-const CHANNELS: u16 = 2;
+
+/// Components that request live stages on a graph's stereo output.
+type OutputStageQuery = (
+    Option<&'static BassEnhancer>,
+    Option<&'static Pan>,
+    Option<&'static SpatialEmitter>,
+    Option<&'static Occlusion>,
+    Option<&'static SideChain>,
+    Option<&'static Fade>,
+);
+
+/// Item of [`OutputStageQuery`].
+type OutputStageRefs<'a> = (
+    Option<&'a BassEnhancer>,
+    Option<&'a Pan>,
+    Option<&'a SpatialEmitter>,
+    Option<&'a Occlusion>,
+    Option<&'a SideChain>,
+    Option<&'a Fade>,
+);
+
+/// Components that give a baked one-shot's audio asset per-entity state, so
+/// it can't share one with identical spawns: the `MixBus` it plays on, and
+/// a `PlaybackRate`, `Retrigger`, `ReverbSend`, `AudioLevel`, or
+/// `TransientDetect`, which would otherwise reach every sharer.
+type AssetSharingQuery = (
+    &'static MixBus,
+    Has<PlaybackRate>,
+    Has<Retrigger>,
+    Has<ReverbSend>,
+    Has<AudioLevel>,
+    Has<TransientDetect>,
+);
+
+/// Item of [`AssetSharingQuery`].
+type AssetSharingRefs<'a> = (&'a MixBus, bool, bool, bool, bool, bool);
+
+/// Newly spawned entities of one-shot preset `P`, with what its build system
+/// needs to play them: baking, `Variation`, output stages and asset sharing.
+type OneShotQuery<'w, 's, P> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static P,
+        Has<BakedOneShot>,
+        Option<&'static Variation>,
+        OutputStageQuery,
+        AssetSharingQuery,
+    ),
+    Added<P>,
+>;
+
+/// The bus to share an asset on, or `None` when the entity needs its own.
+fn shared_bus(sharing: AssetSharingRefs) -> Option<MixBus> {
+    let (bus, rate, retrigger, send, level, transients) = sharing;
+    let own = rate || retrigger || send || level || transients;
+    (!own).then_some(*bus)
+}
+
+/// Params of the live stages appended to an entity's graph output.
+#[derive(Default)]
+struct OutputStages {
+    pan: Option<PanParams>,
+    spatial: Option<SpatialParams>,
+    occlusion: Option<OcclusionParams>,
+    side_chain: Option<SideChainParams>,
+    fade: Option<FadeParams>,
+    playback: Option<PlaybackParams>,
+}
+
+impl OutputStages {
+    /// Append the bass enhancer, pan, spatial, occlusion, side-chain, and
+    /// fade stages requested by the entity's components, then the pause
+    /// stage every live graph gets so `Playback` can be added later. The
+    /// stereo result is then fitted to `channels` (see [`fit_channels`]).
+    ///
+    /// In debug builds, first panics if `graph` isn't a stereo generator,
+    /// reporting the caller's location.
+    #[track_caller]
+    fn build(
+        graph: Box<dyn AudioUnit>,
+        (bass, pan, spatial, occlusion, side_chain, fade): OutputStageRefs,
+        channels: u16,
+    ) -> (Box<dyn AudioUnit>, Self) {
+        #[cfg(debug_assertions)]
+        if let Err(error) = check_graph(graph.as_ref()) {
+            panic!("invalid graph: {error}");
+        }
+        let mut graph = graph;
+        let mut stages = Self::default();
+        if let Some(bass) = bass {
+            graph = build_bass_enhancer_stage(graph, bass.amount);
+        }
+        if let Some(pan) = pan {
+            let (panned, params) = build_pan_stage(graph, pan.0);
+            graph = panned;
+            stages.pan = Some(params);
+        }
+        if let Some(emitter) = spatial {
+            let (spatialized, params) = build_spatial_stage(graph, emitter.air_absorption);
+            graph = spatialized;
+            stages.spatial = Some(params);
+        }
+        if let Some(occlusion) = occlusion {
+            let (occluded, params) = build_occlusion_stage(graph, occlusion.0);
+            graph = occluded;
+            stages.occlusion = Some(params);
+        }
+        if side_chain.is_some() {
+            let (ducked, params) = build_side_chain_stage(graph);
+            graph = ducked;
+            stages.side_chain = Some(params);
+        }
+        if let Some(fade) = fade {
+            let (faded, params) = build_fade_stage(graph, fade.in_secs, fade.out_secs);
+            graph = faded;
+            stages.fade = Some(params);
+        }
+        let (graph, params) = build_playback_stage(graph);
+        stages.playback = Some(params);
+        let graph = fit_channels(graph, channels);
+        (graph, stages)
+    }
+
+    /// Whether any live stage is requested. Live stages can't be applied to PCM.
+    fn requested((bass, pan, spatial, occlusion, side_chain, fade): OutputStageRefs) -> bool {
+        bass.is_some()
+            || pan.is_some()
+            || spatial.is_some()
+            || occlusion.is_some()
+            || side_chain.is_some()
+            || fade.is_some()
+    }
+
+    fn insert(self, entity: &mut EntityCommands) {
+        if let Some(pan) = self.pan {
+            entity.insert(pan);
+        }
+        if let Some(spatial) = self.spatial {
+            entity.insert(spatial);
+        }
+        if let Some(occlusion) = self.occlusion {
+            entity.insert(occlusion);
+        }
+        if let Some(side_chain) = self.side_chain {
+            entity.insert(side_chain);
+        }
+        if let Some(fade) = self.fade {
+            entity.insert(fade);
+        }
+        if let Some(playback) = self.playback {
+            entity.insert(playback);
+        }
+    }
+}
+
+/// An entity's current player, and its `Crossfade` if it has one.
+type CrossfadeQuery = (
+    Option<&'static AudioPlayer<ProceduralAudio>>,
+    Option<&'static Crossfade>,
+);
+
+/// Item of [`CrossfadeQuery`].
+type CrossfadeRefs<'a> = (Option<&'a AudioPlayer<ProceduralAudio>>, Option<&'a Crossfade>);
+
+/// Play a continuous graph on `entity`. When the entity has a `Crossfade`
+/// and is already playing a live graph, the new graph is crossfaded into
+/// that audio; otherwise it gets a new player.
+fn play_graph(
+    entity: &mut EntityCommands,
+    graph: Box<dyn AudioUnit>,
+    (player, crossfade): CrossfadeRefs,
+    assets: &mut Assets<ProceduralAudio>,
+    channels: u16,
+) {
+    let current = player.zip(crossfade).and_then(|(player, crossfade)| {
+        Some((assets.get(&player.0)?, crossfade.secs))
+    });
+    let graph = match current {
+        Some((audio, secs)) => match audio.crossfade_to(graph, secs) {
+            Ok(()) => return,
+            Err(graph) => graph,
+        },
+        None => graph,
+    };
+    let handle = assets.add(ProceduralAudio::new(graph, SAMPLE_RATE, channels));
+    entity.insert(AudioPlayer::<ProceduralAudio>(handle));
+}
+
+/// Measure every one-shot preset's loudness normalization gain at startup,
+/// in parallel, so the first spawn of a preset doesn't stall the frame
+/// rendering its reference.
+pub fn loudness_measure_system(mut bake_cache: ResMut<BakeCache>) {
+    let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+    let gains = pool.scope(|scope| {
+        for (preset, build) in reference_graphs() {
+            scope.spawn(async move { (preset, normalization_gain(&mut *build())) });
+        }
+    });
+    for (preset, gain) in gains {
+        bake_cache.insert_normalization_gain(preset, gain);
+    }
+}
+
+/// Create the audio asset for a one-shot preset.
+///
+/// The graph is scaled by the preset type's loudness normalization gain (see
+/// [`LoudnessReference`]). When the entity is marked `BakedOneShot`, it is
+/// rendered once for `lifetime` seconds into the bake cache (keyed by the
+/// preset's `Debug` representation, i.e. type + params, plus the channel
+/// count) and played back from PCM. Spawns jittered by a `Variation` can't
+/// share a render, so they run live like unbaked ones. Entities with
+/// live output stages (`BassEnhancer`, `Pan`, `SpatialEmitter`, `Occlusion`,
+/// `SideChain`, `Fade`) always run the graph, until it has sounded and then
+/// gone silent.
+///
+/// Baked spawns also share one asset per render and `MixBus`, unless
+/// `sharing` shows the entity needs its own (see [`AssetSharingQuery`]), so
+/// a burst of identical spawns adds no assets after the first.
+#[allow(clippy::too_many_arguments)]
+fn one_shot_audio<P: Debug + LoudnessReference + 'static>(
+    preset: &P,
+    baked: bool,
+    jitter: Jitter,
+    output: OutputStageRefs,
+    sharing: AssetSharingRefs,
+    channels: u16,
+    lifetime: f32,
+    bake_cache: &mut BakeCache,
+    assets: &mut Assets<ProceduralAudio>,
+    build: fn(&P, Jitter) -> Box<dyn AudioUnit>,
+) -> (Handle<ProceduralAudio>, OutputStages) {
+    let (handle, stages, _) = one_shot_audio_with_params(
+        preset,
+        baked,
+        jitter,
+        output,
+        sharing,
+        channels,
+        lifetime,
+        bake_cache,
+        assets,
+        |preset, jitter| (build(preset, jitter), ()),
+    );
+    (handle, stages)
+}
+
+/// [`one_shot_audio`] for a preset whose graph has live params, returned
+/// alongside the audio when the graph runs live. A baked spawn has none.
+#[allow(clippy::too_many_arguments)]
+fn one_shot_audio_with_params<P: Debug + LoudnessReference + 'static, Q>(
+    preset: &P,
+    baked: bool,
+    jitter: Jitter,
+    output: OutputStageRefs,
+    sharing: AssetSharingRefs,
+    channels: u16,
+    lifetime: f32,
+    bake_cache: &mut BakeCache,
+    assets: &mut Assets<ProceduralAudio>,
+    build: impl Fn(&P, Jitter) -> (Box<dyn AudioUnit>, Q),
+) -> (Handle<ProceduralAudio>, OutputStages, Option<Q>) {
+    // Checked here too, as normalizing a miswired graph would panic first.
+    let build = |preset: &P, jitter| {
+        let (graph, params) = build(preset, jitter);
+        #[cfg(debug_assertions)]
+        if let Err(error) = check_graph(graph.as_ref()) {
+            panic!("invalid {} graph: {error}", std::any::type_name::<P>());
+        }
+        (graph, params)
+    };
+    let gain = bake_cache.normalization_gain(TypeId::of::<P>(), || {
+        build(&P::reference(), Jitter::NONE).0
+    });
+    let normalize = |graph: Box<dyn AudioUnit>| -> Box<dyn AudioUnit> {
+        Box::new(Net::wrap(graph) * Net::wrap(Box::new(dc((gain, gain)))))
+    };
+
+    // A jittered spawn never repeats its key, so baking it would only churn
+    // the cache; it runs live instead.
+    let baked = baked && !OutputStages::requested(output) && {
+        if !jitter.is_none() {
+            bake_cache.refuse_jitter(TypeId::of::<P>(), std::any::type_name::<P>());
+        }
+        jitter.is_none()
+    };
+    if baked {
+        let key = format!("{preset:?}/{channels}");
+        let hit = bake_cache.contains(&key);
+        bake_cache.count_lookup(TypeId::of::<P>(), std::any::type_name::<P>(), hit);
+        let bake = |bake_cache: &mut BakeCache| {
+            let (samples, channels) =
+                bake_cache.get_or_bake(key.clone(), lifetime, SAMPLE_RATE, || {
+                    fit_channels(normalize(build(preset, jitter).0), channels)
+                });
+            ProceduralAudio::baked(samples, SAMPLE_RATE, channels)
+        };
+        let handle = match shared_bus(sharing) {
+            Some(bus) => bake_cache.get_or_add_asset(format!("{key}/{bus:?}"), assets, bake),
+            None => {
+                let audio = bake(bake_cache);
+                assets.add(audio)
+            }
+        };
+        (handle, OutputStages::default(), None)
+    } else {
+        let (graph, params) = build(preset, jitter);
+        let (graph, stages) = OutputStages::build(normalize(graph), output, channels);
+        let mut audio = ProceduralAudio::new(graph, SAMPLE_RATE, channels);
+        audio.set_skip_silence();
+        (assets.add(audio), stages, Some(params))
+    }
+}
+
+/// Jitter for one spawn of a one-shot: a fresh draw from `rng` when the
+/// entity has a `Variation`, none otherwise.
+fn spawn_jitter(variation: Option<&Variation>, rng: &mut ProcAudRng) -> Jitter {
+    variation.map_or(Jitter::NONE, |variation| variation.jitter(rng.0.random()))
+}
+
+/// Fit a stereo graph to the `AudioConfig` channel count: averaged down to
+/// mono for `force_mono`, or padded with silent channels after left and right
+/// for wider layouts. Graphs that already have `channels` outputs pass
+/// through unchanged.
+fn fit_channels(graph: Box<dyn AudioUnit>, channels: u16) -> Box<dyn AudioUnit> {
+    let channels = channels as usize;
+    if graph.outputs() == channels {
+        return graph;
+    }
+    if channels == 1 {
+        return Box::new(Net::wrap(graph) >> Net::wrap(Box::new(join::<U2>())));
+    }
+    let silent = (graph.outputs()..channels).map(|_| Net::wrap(Box::new(dc(0.0))));
+    Box::new(silent.fold(Net::wrap(graph), |net, channel| net | channel))
+}
 
 /// Build DSP graphs for newly-added `Synth` entities.
 pub fn graph_build_system(
@@ -28,176 +420,1024 @@ pub fn graph_build_system(
             Option<&OscillatorType>,
             Option<&Frequency>,
             Option<&Amplitude>,
-            Option<&LowPass>,
-            Option<&HighPass>,
-            Option<&BandPass>,
-            Option<&Reverb>,
-            Option<&Delay>,
-            Option<&Distortion>,
+            (
+                Option<&Wavetable>,
+                Option<&OscSync>,
+                Option<&HarmonicOsc>,
+                Option<&StereoSynth>,
+                Option<&Polyphony>,
+            ),
+            (
+                Option<&Formant>,
+                Option<&LowPass>,
+                Option<&HighPass>,
+                Option<&BandPass>,
+                Option<&Resonator>,
+                Option<&AllPass>,
+            ),
+            (
+                Option<&Reverb>,
+                Option<&Delay>,
+                Option<&Distortion>,
+                Option<&RingMod>,
+                Option<&Gate>,
+                Option<&Eq3>,
+                Option<&ConvolutionReverb>,
+            ),
+            Has<ReverbSend>,
+            OutputStageQuery,
+            CrossfadeQuery,
         ),
         Added<Synth>,
     >,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    impulses: Res<Assets<ImpulseResponse>>,
+    config: Res<AudioConfig>,
 ) {
-    for (entity, osc, freq, amp, lp, hp, bp, reverb, delay, dist) in &query {
+    for (entity, osc, freq, amp, osc_mods, filters, effects, send, output, current) in &query {
+        let (table, sync, harmonic, stereo, poly) = osc_mods;
+        let (formant, lp, hp, bp, res, ap) = filters;
+        let (reverb, delay, dist, ring, gate, eq, conv) = effects;
         let osc_type = osc.copied().unwrap_or_default();
         let frequency = freq.copied().unwrap_or_default();
         let amplitude = amp.copied().unwrap_or_default();
+        // Until a convolution's impulse response loads, stand in an
+        // algorithmic reverb; `convolution_reload_system` swaps it in later.
+        let convolution = conv.and_then(|conv| Some((impulses.get(&conv.ir)?, conv.mix)));
+        let fallback = Reverb::default();
+        let reverb = match (conv, convolution) {
+            (Some(_), None) => reverb.or(Some(&fallback)),
+            _ => reverb,
+        };
+        // A bus send takes the place of the synth's own reverb.
+        let (reverb, convolution) = if send {
+            (None, None)
+        } else {
+            (reverb, convolution)
+        };
 
-        let (graph, params) = build_synth_graph(
-            &osc_type, &frequency, &amplitude, lp, hp, bp, reverb, delay, dist,
-        );
+        let (graph, params) = GraphSpec::new(&osc_type, &frequency, &amplitude)
+            .with(table, GraphSpec::wavetable)
+            .with(sync, GraphSpec::osc_sync)
+            .with(harmonic, GraphSpec::harmonic)
+            .with(stereo, GraphSpec::stereo)
+            .with(poly, GraphSpec::polyphony)
+            .with(formant, GraphSpec::formant)
+            .with(lp, GraphSpec::low_pass)
+            .with(hp, GraphSpec::high_pass)
+            .with(bp, GraphSpec::band_pass)
+            .with(res, GraphSpec::resonator)
+            .with(ap, GraphSpec::all_pass)
+            .with(reverb, GraphSpec::reverb)
+            .with(convolution, |spec, (ir, mix)| spec.convolution(ir, mix))
+            .with(delay, GraphSpec::delay)
+            .with(dist, GraphSpec::distortion)
+            .with(ring, GraphSpec::ring_mod)
+            .with(gate, GraphSpec::gate)
+            .with(eq, GraphSpec::eq)
+            .build();
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
+    }
+}
+
+/// Route the audio of `ReverbSend` entities into the shared `ReverbBus`.
+///
+/// Runs in `PostUpdate` whenever an entity's audio player is (re)created,
+/// before its decoder starts, and spawns the bus player the first time a send
+/// appears.
+#[allow(clippy::type_complexity)]
+pub fn reverb_send_build_system(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &ReverbSend, &AudioPlayer<ProceduralAudio>),
+        Changed<AudioPlayer<ProceduralAudio>>,
+    >,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    output: Res<OutputSettings>,
+    mut bus: ResMut<ReverbBus>,
+) {
+    if query.is_empty() {
+        return;
+    }
 
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
+    if bus.player.is_none() {
+        let graph = build_reverb_bus_graph(&bus.reverb);
+        let graph = fit_channels(graph, config.channels());
+        let mut audio =
+            ProceduralAudio::reverb_bus(bus.input.clone(), graph, SAMPLE_RATE, config.channels());
+        // Spawned after this frame's settings are shared, so share them here.
+        audio.set_output_settings(output.clone());
         let handle = assets.add(audio);
+        bus.player = Some(commands.spawn(AudioPlayer::<ProceduralAudio>(handle)).id());
+    }
 
-        commands.entity(entity).insert((
-            AudioPlayer::<ProceduralAudio>(handle),
-            params,
+    for (entity, send, player) in &query {
+        let Some(audio) = assets.get_mut(&player.0) else {
+            continue;
+        };
+        let level = ParamHandle::new("reverb_send", send.0, 0.0, 1.0);
+        audio.set_reverb_send(ReverbSendTap::new(
+            bus.input.clone(),
+            level.shared().clone(),
         ));
+        commands.entity(entity).insert(ReverbSendParams { level });
+    }
+}
+
+/// Insert synth components from `SynthConfig` assets.
+///
+/// Runs when a `SynthConfigHandle` is added with its asset already loaded, and
+/// whenever the asset finishes loading or is modified (hot-reload). The old
+/// components and audio player are removed first so `Synth` is re-added and
+/// `graph_build_system` rebuilds the graph from the new config.
+pub fn synth_config_system(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<SynthConfig>>,
+    query: Query<(Entity, Ref<SynthConfigHandle>, Has<Crossfade>)>,
+    configs: Res<Assets<SynthConfig>>,
+) {
+    let mut changed = HashSet::new();
+    for event in events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+                changed.insert(*id);
+            }
+            _ => {}
+        }
+    }
+
+    for (entity, handle, crossfade) in &query {
+        if !handle.is_added() && !changed.contains(&handle.0.id()) {
+            continue;
+        }
+        let Some(config) = configs.get(&handle.0) else {
+            continue;
+        };
+        let mut entity = commands.entity(entity);
+        entity.remove::<(
+            Synth,
+            OscillatorType,
+            Frequency,
+            Amplitude,
+            (Wavetable, OscSync, HarmonicOsc, StereoSynth, Polyphony),
+            Formant,
+            LowPass,
+            HighPass,
+            BandPass,
+            Resonator,
+            AllPass,
+            (Reverb, Delay, Distortion, RingMod, Gate, Eq3),
+        )>();
+        SynthBundle::from(config.clone()).insert(&mut entity);
+        // A crossfade swaps the new graph into the playing audio.
+        if !crossfade {
+            entity.remove::<(AudioPlayer<ProceduralAudio>, AudioSink)>();
+        }
+    }
+}
+
+/// Rebuild synths whose `ConvolutionReverb` impulse response has finished
+/// loading or was modified (hot-reload), replacing the stand-in reverb they
+/// were built with. Re-adding `Synth` makes `graph_build_system` pick them
+/// up again; synths with a `Crossfade` keep their player to fade into the
+/// new graph.
+pub fn convolution_reload_system(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<ImpulseResponse>>,
+    query: Query<(Entity, &ConvolutionReverb, Has<Crossfade>), With<SynthParams>>,
+) {
+    let mut changed = HashSet::new();
+    for event in events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => {
+                changed.insert(*id);
+            }
+            _ => {}
+        }
+    }
+    if changed.is_empty() {
+        return;
+    }
+
+    for (entity, conv, crossfade) in &query {
+        if changed.contains(&conv.ir.id()) {
+            let mut entity = commands.entity(entity);
+            entity.remove::<Synth>().insert(Synth);
+            if !crossfade {
+                entity.remove::<(AudioPlayer<ProceduralAudio>, AudioSink)>();
+            }
+        }
     }
 }
 
 /// Build DSP graph for newly-added `Heartbeat` entities.
 pub fn heartbeat_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &Heartbeat), Added<Heartbeat>>,
+    query: Query<(Entity, &Heartbeat, OutputStageQuery, CrossfadeQuery), Added<Heartbeat>>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
 ) {
-    for (entity, hb) in &query {
+    for (entity, hb, output, current) in &query {
         let (graph, params) = build_heartbeat_graph(hb);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
-
-        commands.entity(entity).insert((
-            AudioPlayer::<ProceduralAudio>(handle),
-            params,
-        ));
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
     }
 }
 
 /// Build DSP graph for newly-added `SwordSlash` entities.
 pub fn sword_slash_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &SwordSlash), Added<SwordSlash>>,
+    query: OneShotQuery<SwordSlash>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
 ) {
-    for (entity, ss) in &query {
-        let graph = build_sword_slash_graph(ss);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
+    for (entity, ss, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 1.5 * ss.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            ss,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_sword_slash_graph,
+        );
 
-        commands.entity(entity).insert((
+        let mut entity = commands.entity(entity);
+        entity.insert((
             AudioPlayer::<ProceduralAudio>(handle),
-            OneShotLifetime::new(1.5),
+            OneShotLifetime::new(lifetime),
         ));
+        stages.insert(&mut entity);
     }
 }
 
 /// Build DSP graph for newly-added `BluntImpact` entities.
 pub fn blunt_impact_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &BluntImpact), Added<BluntImpact>>,
+    query: OneShotQuery<BluntImpact>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
 ) {
-    for (entity, bi) in &query {
-        let graph = build_blunt_impact_graph(bi);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
+    for (entity, bi, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 0.5 * bi.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            bi,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_blunt_impact_graph,
+        );
 
-        commands.entity(entity).insert((
+        let mut entity = commands.entity(entity);
+        entity.insert((
             AudioPlayer::<ProceduralAudio>(handle),
-            OneShotLifetime::new(0.5),
+            OneShotLifetime::new(lifetime),
         ));
+        stages.insert(&mut entity);
     }
 }
 
 /// Build DSP graph for newly-added `LightningZap` entities.
 pub fn lightning_zap_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &LightningZap), Added<LightningZap>>,
+    query: OneShotQuery<LightningZap>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
 ) {
-    for (entity, zap) in &query {
-        let graph = build_lightning_zap_graph(zap);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
+    for (entity, zap, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 0.7 * zap.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            zap,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_lightning_zap_graph,
+        );
 
-        commands.entity(entity).insert((
+        let mut entity = commands.entity(entity);
+        entity.insert((
             AudioPlayer::<ProceduralAudio>(handle),
-            OneShotLifetime::new(0.7),
+            OneShotLifetime::new(lifetime),
         ));
+        stages.insert(&mut entity);
     }
 }
 
 /// Build DSP graph for newly-added `LightningStrike` entities.
 pub fn lightning_strike_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &LightningStrike), Added<LightningStrike>>,
+    query: OneShotQuery<LightningStrike>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
 ) {
-    for (entity, ls) in &query {
-        let graph = build_lightning_strike_graph(ls);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
+    for (entity, ls, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 3.0 * ls.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            ls,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_lightning_strike_graph,
+        );
 
-        commands.entity(entity).insert((
+        let mut entity = commands.entity(entity);
+        entity.insert((
             AudioPlayer::<ProceduralAudio>(handle),
-            OneShotLifetime::new(3.0),
+            OneShotLifetime::new(lifetime),
         ));
+        stages.insert(&mut entity);
     }
 }
 
 /// Build DSP graph for newly-added `Explosion` entities.
 pub fn explosion_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &Explosion), Added<Explosion>>,
+    query: OneShotQuery<Explosion>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
 ) {
-    for (entity, ex) in &query {
-        let graph = build_explosion_graph(ex);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
+    for (entity, ex, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 3.0 * ex.duration_scale * jitter.max_scale();
+        let (handle, stages, params) = one_shot_audio_with_params(
+            ex,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_explosion_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        if let Some(params) = params {
+            entity.insert(params);
+        }
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Footstep` entities.
+pub fn footstep_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Footstep>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, fs, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 0.4 * fs.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            fs,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_footstep_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Gunshot` entities.
+pub fn gunshot_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Gunshot>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, gs, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        // Reverb tail and large calibers ring past the dry transient.
+        let lifetime = (0.5 * gs.caliber.max(1.0) + gs.reverb_mix) * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            gs,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_gunshot_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `UiBlip` entities.
+pub fn ui_blip_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<UiBlip>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, ub, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 0.15 * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            ub,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_ui_blip_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Pickup` entities.
+pub fn pickup_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Pickup>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, pu, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = pu.duration() * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            pu,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_pickup_graph,
+        );
 
-        commands.entity(entity).insert((
+        let mut entity = commands.entity(entity);
+        entity.insert((
             AudioPlayer::<ProceduralAudio>(handle),
-            OneShotLifetime::new(3.0),
+            OneShotLifetime::new(lifetime),
         ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Laser` entities.
+pub fn laser_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Laser>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, la, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 0.25 * la.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            la,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_laser_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Teleport` entities.
+pub fn teleport_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Teleport>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, tp, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        // Leave room for the reverb tail past the dry sound.
+        let lifetime = (0.6 * tp.duration_scale + tp.reverb_mix) * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            tp,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_teleport_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `PowerUp` entities.
+pub fn power_up_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<PowerUp>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, pu, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = pu.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            pu,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_power_up_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `GlassBreak` entities.
+pub fn glass_break_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<GlassBreak>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, gb, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 0.8 * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            gb,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_glass_break_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Whoosh` entities.
+pub fn whoosh_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Whoosh>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, wh, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 0.35 * wh.duration_scale * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            wh,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_whoosh_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Splash` entities.
+pub fn splash_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Splash>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, sp, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = sp.duration() * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            sp,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_splash_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Bell` entities.
+pub fn bell_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Bell>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, bell, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = 3.0 * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            bell,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_bell_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Pluck` entities.
+pub fn pluck_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Pluck>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, pl, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = pl.duration() * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            pl,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_pluck_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Drum` entities.
+pub fn drum_build_system(
+    mut commands: Commands,
+    query: OneShotQuery<Drum>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
+) {
+    for (entity, dr, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = dr.duration() * jitter.max_scale();
+        let (handle, stages) = one_shot_audio(
+            dr,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_drum_graph,
+        );
+
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            AudioPlayer::<ProceduralAudio>(handle),
+            OneShotLifetime::new(lifetime),
+        ));
+        stages.insert(&mut entity);
     }
 }
 
 /// Build DSP graph for newly-added `ArcaneAttack` entities.
 pub fn arcane_attack_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &ArcaneAttack), Added<ArcaneAttack>>,
+    query: OneShotQuery<ArcaneAttack>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+    mut bake_cache: ResMut<BakeCache>,
+    mut rng: ResMut<ProcAudRng>,
 ) {
-    for (entity, aa) in &query {
-        let graph = build_arcane_attack_graph(aa);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
+    for (entity, aa, baked, variation, output, sharing) in &query {
+        let jitter = spawn_jitter(variation, &mut rng);
+        let lifetime = aa.duration_scale * jitter.max_scale();
+        let (handle, stages, params) = one_shot_audio_with_params(
+            aa,
+            baked,
+            jitter,
+            output,
+            sharing,
+            config.channels(),
+            lifetime,
+            &mut bake_cache,
+            &mut assets,
+            build_arcane_attack_graph,
+        );
 
-        commands.entity(entity).insert((
+        let mut entity = commands.entity(entity);
+        entity.insert((
             AudioPlayer::<ProceduralAudio>(handle),
-            OneShotLifetime::new(1.0),
+            OneShotLifetime::new(lifetime),
         ));
+        if let Some(params) = params {
+            entity.insert(params);
+        }
+        stages.insert(&mut entity);
     }
 }
 
 /// Build DSP graph for newly-added `EarRinging` entities.
 pub fn ear_ringing_build_system(
     mut commands: Commands,
-    query: Query<(Entity, &EarRinging), Added<EarRinging>>,
+    query: Query<(Entity, &EarRinging, OutputStageQuery, CrossfadeQuery), Added<EarRinging>>,
     mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
 ) {
-    for (entity, er) in &query {
+    for (entity, er, output, current) in &query {
         let (graph, params) = build_ear_ringing_graph(er);
-        let audio = ProceduralAudio::new(graph, SAMPLE_RATE, CHANNELS);
-        let handle = assets.add(audio);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
+    }
+}
 
-        commands.entity(entity).insert((
-            AudioPlayer::<ProceduralAudio>(handle),
-            params,
-        ));
+/// Build DSP graph for newly-added `Wind` entities.
+pub fn wind_build_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Wind, OutputStageQuery, CrossfadeQuery), Added<Wind>>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+) {
+    for (entity, wind, output, current) in &query {
+        let (graph, params) = build_wind_graph(wind);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `TestTone` entities.
+pub fn test_tone_build_system(
+    mut commands: Commands,
+    query: Query<(Entity, &TestTone, OutputStageQuery, CrossfadeQuery), Added<TestTone>>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+) {
+    for (entity, tone, output, current) in &query {
+        let graph = build_test_tone_graph(tone);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Rain` entities.
+pub fn rain_build_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Rain, OutputStageQuery, CrossfadeQuery), Added<Rain>>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+) {
+    for (entity, rain, output, current) in &query {
+        let (graph, params) = build_rain_graph(rain);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Fire` entities.
+pub fn fire_build_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Fire, OutputStageQuery, CrossfadeQuery), Added<Fire>>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+) {
+    for (entity, fire, output, current) in &query {
+        let (graph, params) = build_fire_graph(fire);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Engine` entities.
+pub fn engine_build_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Engine, OutputStageQuery, CrossfadeQuery), Added<Engine>>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+) {
+    for (entity, engine, output, current) in &query {
+        let (graph, params) = build_engine_graph(engine);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Alarm` entities.
+pub fn alarm_build_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Alarm, OutputStageQuery, CrossfadeQuery), Added<Alarm>>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+) {
+    for (entity, alarm, output, current) in &query {
+        let (graph, params) = build_alarm_graph(alarm);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
+    }
+}
+
+/// Build DSP graph for newly-added `Metronome` entities.
+pub fn metronome_build_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Metronome, OutputStageQuery, CrossfadeQuery), Added<Metronome>>,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    config: Res<AudioConfig>,
+) {
+    for (entity, mt, output, current) in &query {
+        let (graph, params) = build_metronome_graph(mt);
+        let (graph, stages) = OutputStages::build(graph, output, config.channels());
+        let mut entity = commands.entity(entity);
+        play_graph(&mut entity, graph, current, &mut assets, config.channels());
+        entity.insert(params);
+        stages.insert(&mut entity);
     }
 }
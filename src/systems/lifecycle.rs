@@ -1,40 +1,209 @@
 use bevy::prelude::*;
 
-use crate::components::lifetime::OneShotLifetime;
+use crate::components::fade::{Fade, StopRequest};
+use crate::components::level::AudioLevel;
+use crate::components::lifetime::{AutoStopOnSilence, OneShotLifetime, Retrigger, SilenceTimer};
+use crate::components::playback::PlaybackRate;
+use crate::config::VoiceLimit;
+use crate::dsp::bake::BakeCache;
+use crate::dsp::fade::FadeParams;
 use crate::dsp::graph_builder::SynthParams;
+use crate::dsp::playback::PlaybackParams;
 use crate::dsp::source::ProceduralAudio;
+use crate::presets::alarm::AlarmParams;
 use crate::presets::ear_ringing::EarRingingParams;
+use crate::presets::engine::EngineParams;
+use crate::presets::fire::FireParams;
 use crate::presets::heartbeat::HeartbeatParams;
+use crate::presets::metronome::MetronomeParams;
+use crate::presets::rain::RainParams;
+use crate::presets::wind::WindParams;
 
 /// Clean up audio when procedural audio param components are removed.
+///
+/// Entities with a `StopRequest` keep their player until their fade-out ends.
+#[allow(clippy::too_many_arguments)]
 pub fn audio_cleanup_system(
     mut removed_synth: RemovedComponents<SynthParams>,
     mut removed_heartbeat: RemovedComponents<HeartbeatParams>,
     mut removed_ear_ringing: RemovedComponents<EarRingingParams>,
+    mut removed_wind: RemovedComponents<WindParams>,
+    mut removed_rain: RemovedComponents<RainParams>,
+    mut removed_fire: RemovedComponents<FireParams>,
+    mut removed_engine: RemovedComponents<EngineParams>,
+    mut removed_alarm: RemovedComponents<AlarmParams>,
+    mut removed_metronome: RemovedComponents<MetronomeParams>,
+    stopping: Query<(), With<StopRequest>>,
     mut commands: Commands,
 ) {
-    for entity in removed_synth.read() {
-        commands.entity(entity).remove::<AudioPlayer<ProceduralAudio>>();
-    }
-    for entity in removed_heartbeat.read() {
-        commands.entity(entity).remove::<AudioPlayer<ProceduralAudio>>();
-    }
-    for entity in removed_ear_ringing.read() {
-        commands.entity(entity).remove::<AudioPlayer<ProceduralAudio>>();
+    let removed = removed_synth
+        .read()
+        .chain(removed_heartbeat.read())
+        .chain(removed_ear_ringing.read())
+        .chain(removed_wind.read())
+        .chain(removed_rain.read())
+        .chain(removed_fire.read())
+        .chain(removed_engine.read())
+        .chain(removed_alarm.read())
+        .chain(removed_metronome.read());
+    for entity in removed {
+        // A graceful stop is fading out; `fade_system` despawns it when done.
+        if stopping.contains(entity) {
+            continue;
+        }
+        // The entity may have been despawned, which also reports the removal.
+        commands.entity(entity).try_remove::<AudioPlayer<ProceduralAudio>>();
     }
 }
 
 /// Despawn one-shot audio entities after their sound has finished.
+///
+/// Lifetimes advance in the sound's own time, so a `PlaybackRate` below 1.0
+/// keeps a slowed one-shot alive until its tail has played.
 pub fn oneshot_lifetime_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut OneShotLifetime)>,
+    mut query: Query<(Entity, &mut OneShotLifetime, Option<&PlaybackRate>)>,
 ) {
     let dt = time.delta_secs();
-    for (entity, mut lifetime) in &mut query {
-        lifetime.elapsed += dt;
+    for (entity, mut lifetime, rate) in &mut query {
+        lifetime.elapsed += dt * rate.map_or(1.0, |rate| rate.speed());
         if lifetime.elapsed >= lifetime.duration {
             commands.entity(entity).despawn();
         }
     }
 }
+
+/// Despawn `AutoStopOnSilence` entities whose level has stayed below the
+/// threshold for the hold time.
+pub fn silence_stop_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &AutoStopOnSilence, &AudioLevel, &mut SilenceTimer)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, stop, level, mut timer) in &mut query {
+        if level.rms > stop.threshold {
+            timer.heard = true;
+            timer.silent_for = 0.0;
+            continue;
+        }
+        if !timer.heard {
+            continue;
+        }
+        timer.silent_for += dt;
+        if timer.silent_for >= stop.hold_secs {
+            // The lifetime system may despawn the same entity this frame.
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
+
+/// Replay one-shots that were given a `Retrigger`. One playing a shared
+/// baked asset gets its own copy from the start instead, leaving the other
+/// sharers alone.
+#[allow(clippy::type_complexity)]
+pub fn retrigger_system(
+    mut commands: Commands,
+    mut query: Query<
+        (Entity, &AudioPlayer<ProceduralAudio>, Option<&mut OneShotLifetime>),
+        Added<Retrigger>,
+    >,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    bake_cache: Res<BakeCache>,
+) {
+    for (entity, player, lifetime) in &mut query {
+        if let Some(handle) = bake_cache.unshare(&player.0, 0.0, &mut assets) {
+            commands
+                .entity(entity)
+                .insert(AudioPlayer(handle))
+                .remove::<AudioSink>();
+        } else if let Some(audio) = assets.get(&player.0) {
+            audio.retrigger();
+        }
+        if let Some(mut lifetime) = lifetime {
+            lifetime.elapsed = 0.0;
+        }
+        commands.entity(entity).try_remove::<Retrigger>();
+    }
+}
+
+/// Ramp fade gains toward their target and carry out `StopRequest`s.
+///
+/// Removing `Fade` requests a stop. Stopping entities with a fade stage ramp
+/// down over `out_secs` and despawn at silence; those without one are muted
+/// through their pause stage and despawned on the next frame.
+#[allow(clippy::type_complexity)]
+pub fn fade_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut removed: RemovedComponents<Fade>,
+    mut query: Query<(Entity, &mut FadeParams, Option<&Fade>, Has<StopRequest>)>,
+    mut unfaded: Query<
+        (Entity, &mut StopRequest, Option<&PlaybackParams>),
+        Without<FadeParams>,
+    >,
+) {
+    for entity in removed.read() {
+        if let Ok((_, mut params, _, _)) = query.get_mut(entity) {
+            params.stopping = true;
+            commands.entity(entity).insert(StopRequest::default());
+        }
+    }
+
+    let dt = time.delta_secs();
+    for (entity, mut params, fade, stop_requested) in &mut query {
+        if let Some(fade) = fade {
+            params.in_secs = fade.in_secs;
+            params.out_secs = fade.out_secs;
+        }
+        params.stopping |= stop_requested;
+        let (target, secs) = if params.stopping {
+            (0.0, params.out_secs)
+        } else {
+            (1.0, params.in_secs)
+        };
+        let step = if secs > 0.0 { dt / secs } else { 1.0 };
+        params.level = if target > params.level {
+            (params.level + step).min(target)
+        } else {
+            (params.level - step).max(target)
+        };
+        params.gain.set(params.level);
+
+        if params.stopping && params.level <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (entity, mut stop, playback) in &mut unfaded {
+        match playback {
+            // Give the pause stage's smoothing a frame to reach silence.
+            Some(playback) if !stop.muted => {
+                playback.gain.set(0.0);
+                stop.muted = true;
+            }
+            _ => commands.entity(entity).despawn(),
+        }
+    }
+}
+
+/// Steal the oldest one-shot voices once more than `VoiceLimit` are active.
+pub fn voice_limit_system(
+    mut commands: Commands,
+    limit: Res<VoiceLimit>,
+    query: Query<(Entity, &OneShotLifetime)>,
+) {
+    let active = query.iter().len();
+    if active <= limit.0 {
+        return;
+    }
+
+    // Oldest (longest elapsed) first.
+    let mut voices: Vec<_> = query.iter().collect();
+    voices.sort_by(|a, b| b.1.elapsed.total_cmp(&a.1.elapsed));
+    for (entity, _) in voices.into_iter().take(active - limit.0) {
+        // The lifetime system may despawn the same entity this frame.
+        commands.entity(entity).try_despawn();
+    }
+}
@@ -1,18 +1,60 @@
 use bevy::prelude::*;
 
-use crate::components::filter::{BandPass, HighPass, LowPass};
-use crate::components::synth::{Amplitude, Frequency};
+use crate::components::arpeggiator::{ArpClock, Arpeggiator};
+use crate::components::automation::{Automation, AutomationClock};
+use crate::components::burst::{Burst, BurstClock};
+use crate::components::effect::{Delay, Eq3, Gate, ReverbSend, RingMod};
+use crate::components::filter::{AllPass, BandPass, Formant, HighPass, LowPass, Resonator};
+use crate::components::level::{AudioLevel, TransientCount, TransientDetect, TransientDetected};
+use crate::components::lifetime::OneShotLifetime;
+use crate::components::mixer::{MixBus, SideChain};
+use crate::components::playback::{Playback, PlaybackRate};
+use crate::components::polyphony::{NoteEvent, NoteEvents, VoiceSlot, VoiceSlots};
+use crate::components::sequencer::{Sequencer, SequencerClock};
+use crate::components::spatial::{DopplerShift, Occlusion, Pan, SpatialEmitter};
+use crate::components::synth::{
+    Amplitude, Frequency, FrequencyFromTransform, NamedParam, OscSync, StereoSynth,
+};
+use crate::config::{AudioConfig, Mixer, ProcAudRng};
+use crate::dsp::bake::BakeCache;
 use crate::dsp::graph_builder::SynthParams;
+use crate::dsp::playback::PlaybackParams;
+use crate::dsp::reverb_bus::ReverbSendParams;
+use crate::dsp::side_chain::SideChainParams;
+use crate::dsp::source::{OutputSettings, ProceduralAudio};
+use crate::dsp::spatial::{OcclusionParams, PanParams, SpatialParams};
+use crate::presets::alarm::{Alarm, AlarmParams};
+use crate::presets::arcane_attack::{ArcaneAttack, ArcaneAttackParams};
 use crate::presets::ear_ringing::{EarRinging, EarRingingParams};
-use crate::presets::heartbeat::{Heartbeat, HeartbeatParams};
+use crate::presets::engine::{Engine, EngineParams};
+use crate::presets::explosion::{Explosion, ExplosionParams};
+use crate::presets::fire::{Fire, FireParams};
+use crate::presets::heartbeat::{
+    beat_phase, Heartbeat, HeartbeatClock, HeartbeatParams, HeartbeatPulse, HeartbeatState,
+};
+use crate::presets::metronome::{
+    beat_position, is_accent, Metronome, MetronomeClock, MetronomeParams, MetronomeTick,
+};
+use crate::presets::rain::{Rain, RainParams};
+use crate::presets::wind::{Wind, WindParams};
 
 /// Sync changed synth component values to the audio thread via `ParamHandle` atomics.
+#[allow(clippy::too_many_arguments)]
 pub fn param_sync_system(
     freq_query: Query<(&Frequency, &SynthParams), Changed<Frequency>>,
     amp_query: Query<(&Amplitude, &SynthParams), Changed<Amplitude>>,
     lp_query: Query<(&LowPass, &SynthParams), Changed<LowPass>>,
     hp_query: Query<(&HighPass, &SynthParams), Changed<HighPass>>,
     bp_query: Query<(&BandPass, &SynthParams), Changed<BandPass>>,
+    res_query: Query<(&Resonator, &SynthParams), Changed<Resonator>>,
+    ap_query: Query<(&AllPass, &SynthParams), Changed<AllPass>>,
+    formant_query: Query<(&Formant, &SynthParams), Changed<Formant>>,
+    ring_query: Query<(&RingMod, &SynthParams), Changed<RingMod>>,
+    sync_query: Query<(&OscSync, &SynthParams), Changed<OscSync>>,
+    stereo_query: Query<(&StereoSynth, &SynthParams), Changed<StereoSynth>>,
+    delay_query: Query<(&Delay, &SynthParams), Changed<Delay>>,
+    gate_query: Query<(&Gate, &SynthParams), Changed<Gate>>,
+    eq_query: Query<(&Eq3, &SynthParams), Changed<Eq3>>,
 ) {
     for (freq, params) in &freq_query {
         params.frequency.set(freq.0);
@@ -41,6 +83,251 @@ pub fn param_sync_system(
             bw.set(bp.bandwidth);
         }
     }
+    for (res, params) in &res_query {
+        if let Some(ref freq) = params.resonator_freq {
+            freq.set(res.freq_hz);
+        }
+        if let Some(ref q) = params.resonator_q {
+            q.set(res.q);
+        }
+    }
+    for (ap, params) in &ap_query {
+        if let Some(ref freq) = params.allpass_freq {
+            freq.set(ap.freq_hz);
+        }
+        if let Some(ref q) = params.allpass_q {
+            q.set(ap.q);
+        }
+    }
+    for (formant, params) in &formant_query {
+        if let Some(ref handles) = params.formant {
+            handles.set_vowel(formant.vowel);
+            handles.intensity.set(formant.intensity);
+        }
+    }
+    for (ring, params) in &ring_query {
+        if let Some(ref freq) = params.ring_mod_freq {
+            freq.set(ring.freq_hz);
+        }
+    }
+    for (sync, params) in &sync_query {
+        if let Some(ref ratio) = params.osc_sync_ratio {
+            ratio.set(sync.ratio);
+        }
+    }
+    for (stereo, params) in &stereo_query {
+        if let Some(ref spread) = params.stereo_spread {
+            spread.set(stereo.spread);
+        }
+    }
+    for (delay, params) in &delay_query {
+        if let Some(ref time) = params.delay_time {
+            time.set(delay.time_seconds);
+        }
+        if let Some(ref feedback) = params.delay_feedback {
+            feedback.set(delay.feedback);
+        }
+        if let (Some(bpm), Some(sync)) = (&params.delay_bpm, delay.sync) {
+            bpm.set(sync.bpm);
+        }
+    }
+    for (gate, params) in &gate_query {
+        if let Some(ref threshold) = params.gate_threshold {
+            threshold.set(gate.threshold);
+        }
+    }
+    for (eq, params) in &eq_query {
+        if let Some(ref low) = params.eq_low_gain {
+            low.set(eq.low_gain);
+        }
+        if let Some(ref mid) = params.eq_mid_gain {
+            mid.set(eq.mid_gain);
+        }
+        if let Some(ref high) = params.eq_high_gain {
+            high.set(eq.high_gain);
+        }
+    }
+}
+
+/// Sync changed `NamedParam` values to the synth parameter of that name.
+pub fn named_param_sync_system(
+    query: Query<(Entity, &NamedParam, &SynthParams), Changed<NamedParam>>,
+) {
+    for (entity, param, params) in &query {
+        if !params.set(&param.name, param.value) {
+            warn!("{entity}: no synth parameter named {:?}", param.name);
+        }
+    }
+}
+
+/// Allocate queued `NoteEvents` to the voices of built `Polyphony` synths.
+/// Notes wait in the queue until the graph exists.
+pub fn note_event_system(mut query: Query<(&SynthParams, &mut NoteEvents, &mut VoiceSlots)>) {
+    for (params, mut events, mut slots) in &mut query {
+        if events.0.is_empty() {
+            continue;
+        }
+        for event in std::mem::take(&mut events.0) {
+            match event {
+                NoteEvent::On {
+                    frequency,
+                    velocity,
+                } => {
+                    if params.voices.is_empty() {
+                        continue;
+                    }
+                    let index = slots.allocate(params.voices.len());
+                    let since = slots.tick();
+                    slots.slots[index] = VoiceSlot {
+                        frequency,
+                        held: true,
+                        since,
+                    };
+                    let voice = &params.voices[index];
+                    voice.frequency.set_value(frequency);
+                    voice.level.set_value(velocity.clamp(0.0, 1.0));
+                }
+                NoteEvent::Off { frequency } => {
+                    let since = slots.tick();
+                    for (slot, voice) in slots.slots.iter_mut().zip(&params.voices) {
+                        if slot.held && slot.frequency == frequency {
+                            slot.held = false;
+                            slot.since = since;
+                            voice.level.set_value(0.0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Step `Arpeggiator`s due this frame: start each one's next note, as a
+/// note-on for a polyphonic synth or a retune otherwise.
+pub fn arpeggiator_system(
+    time: Res<Time>,
+    mut rng: ResMut<ProcAudRng>,
+    mut query: Query<(
+        &Arpeggiator,
+        &mut ArpClock,
+        &SynthParams,
+        Option<&mut NoteEvents>,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (arp, mut clock, params, events) in &mut query {
+        if arp.notes.is_empty() || arp.rate_hz <= 0.0 {
+            continue;
+        }
+        // The first note starts at once; later ones every period.
+        let period = 1.0 / arp.rate_hz;
+        clock.elapsed += dt;
+        if clock.playing.is_some() && clock.elapsed < period {
+            continue;
+        }
+        clock.elapsed %= period;
+
+        let index = arp.mode.index(clock.step, arp.notes.len(), &mut rng.0);
+        clock.step += 1;
+        let frequency = arp.notes[index];
+        match events {
+            Some(mut events) if !params.voices.is_empty() => {
+                if let Some(previous) = clock.playing {
+                    events.note_off(previous);
+                }
+                events.note_on(frequency, 1.0);
+            }
+            _ => params.frequency.set(frequency),
+        }
+        clock.playing = Some(frequency);
+    }
+}
+
+/// Advance `Sequencer` clocks and spawn the preset of each step as it comes
+/// up. The first step plays at once. Runs every frame.
+pub fn sequencer_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(&Sequencer, &mut SequencerClock)>,
+) {
+    let dt = time.delta_secs();
+    for (sequencer, mut clock) in &mut query {
+        let len = sequencer.steps.len();
+        if len == 0 || (sequencer.once && clock.step >= len) {
+            continue;
+        }
+        let step_secs = sequencer.step_secs();
+        clock.elapsed += dt;
+        if clock.step > 0 && clock.elapsed < step_secs {
+            continue;
+        }
+        clock.elapsed %= step_secs;
+
+        if let Some(trigger) = &sequencer.steps[clock.step % len] {
+            trigger.spawn(&mut commands);
+        }
+        clock.step += 1;
+    }
+}
+
+/// Advance `Burst` clocks and spawn each copy as its time comes up, with its
+/// own `PlaybackRate`. Spent bursts are despawned.
+pub fn burst_system(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut rng: ResMut<ProcAudRng>,
+    mut query: Query<(Entity, &Burst, &mut BurstClock)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, burst, mut clock) in &mut query {
+        let clock = &mut *clock;
+        let times = clock.times.get_or_insert_with(|| burst.schedule(&mut rng.0));
+        while clock.spawned < times.len() && times[clock.spawned] <= clock.elapsed {
+            let mut copy = burst.preset.spawn(&mut commands);
+            if burst.pitch_jitter > 0.0 {
+                copy.insert(PlaybackRate(burst.rate(&mut rng.0)));
+            }
+            clock.spawned += 1;
+        }
+        if clock.spawned >= times.len() {
+            commands.entity(entity).despawn();
+        }
+        clock.elapsed += dt;
+    }
+}
+
+/// Advance `Automation` clocks and write the sampled values to their target
+/// synth parameters. Runs every frame.
+pub fn automation_system(
+    time: Res<Time>,
+    mut query: Query<(Entity, &Automation, &mut AutomationClock, &SynthParams)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, automation, mut clock, params) in &mut query {
+        clock.elapsed += dt;
+        let Some(value) = automation.sample(clock.elapsed) else {
+            continue;
+        };
+        if !params.set(&automation.target, value) {
+            warn_once!("{entity}: no synth parameter named {:?} to automate", automation.target);
+        }
+    }
+}
+
+/// Sync changed `ArcaneAttack` lowpass cutoffs to param handles.
+pub fn arcane_attack_sync_system(
+    query: Query<(&ArcaneAttack, &ArcaneAttackParams), Changed<ArcaneAttack>>,
+) {
+    for (aa, params) in &query {
+        params.lowpass.set(aa.lowpass);
+    }
+}
+
+/// Sync changed `Explosion` lowpass cutoffs to param handles.
+pub fn explosion_sync_system(query: Query<(&Explosion, &ExplosionParams), Changed<Explosion>>) {
+    for (ex, params) in &query {
+        params.lowpass.set(ex.lowpass);
+    }
 }
 
 /// Sync changed `Heartbeat` component values to param handles.
@@ -51,6 +338,37 @@ pub fn heartbeat_sync_system(
         params.rate.set(hb.heart_rate);
         params.intensity.set(hb.intensity);
         params.arrhythmia.set(hb.arrhythmic_strength);
+        params.breath_rate.set(hb.breath_rate);
+        params.state.set(hb.state.index() as f32);
+        params.reverb_mix.set(hb.reverb_mix);
+    }
+}
+
+/// Advance each playing `Heartbeat`'s clock and send a `HeartbeatPulse` on
+/// every S1 (every quiver while fibrillating, none while flatlined).
+///
+/// Mirrors the graph's phase math with the component's current values (the
+/// audio thread sees the same values once `heartbeat_sync_system` pushes
+/// them), so the messages track the audible beats.
+pub fn heartbeat_pulse_system(
+    time: Res<Time>,
+    mut query: Query<(Entity, &Heartbeat, &mut HeartbeatClock), With<HeartbeatParams>>,
+    mut pulses: MessageWriter<HeartbeatPulse>,
+) {
+    let dt = time.delta_secs();
+    for (entity, hb, mut clock) in &mut query {
+        let (bpm, arrhythmia) = hb.state.rhythm(
+            hb.heart_rate.clamp(30.0, 220.0),
+            hb.arrhythmic_strength.clamp(0.0, 1.0),
+        );
+        let phase = beat_phase(clock.elapsed, bpm, arrhythmia);
+        // Jitter can nudge the phase backwards a little; only a drop of more
+        // than half a cycle is a wrap into the next beat.
+        if phase < clock.phase - 0.5 && hb.state != HeartbeatState::Flatline {
+            pulses.write(HeartbeatPulse { entity });
+        }
+        clock.phase = phase;
+        clock.elapsed += dt;
     }
 }
 
@@ -60,5 +378,444 @@ pub fn ear_ringing_sync_system(
 ) {
     for (er, params) in &query {
         params.intensity.set(er.intensity);
+        params.reverb_mix.set(er.reverb_mix);
+    }
+}
+
+/// Sync changed `Wind` component values to param handles.
+pub fn wind_sync_system(query: Query<(&Wind, &WindParams), Changed<Wind>>) {
+    for (wind, params) in &query {
+        params.intensity.set(wind.intensity);
+        params.gustiness.set(wind.gustiness);
+    }
+}
+
+/// Sync changed `Rain` component values to param handles.
+pub fn rain_sync_system(query: Query<(&Rain, &RainParams), Changed<Rain>>) {
+    for (rain, params) in &query {
+        params.density.set(rain.density);
+        params.intensity.set(rain.intensity);
+    }
+}
+
+/// Sync changed `Fire` component values to param handles.
+pub fn fire_sync_system(query: Query<(&Fire, &FireParams), Changed<Fire>>) {
+    for (fire, params) in &query {
+        params.intensity.set(fire.intensity);
+    }
+}
+
+/// Sync changed `Engine` component values to param handles.
+pub fn engine_sync_system(query: Query<(&Engine, &EngineParams), Changed<Engine>>) {
+    for (engine, params) in &query {
+        params.rpm.set(engine.rpm);
+        params.load.set(engine.load);
+    }
+}
+
+/// Sync changed `Alarm` component values to param handles.
+pub fn alarm_sync_system(query: Query<(&Alarm, &AlarmParams), Changed<Alarm>>) {
+    for (alarm, params) in &query {
+        params.rate.set(alarm.rate_hz);
+        params.intensity.set(alarm.intensity);
+    }
+}
+
+/// Sync changed `Metronome` component values to param handles.
+pub fn metronome_sync_system(
+    query: Query<(&Metronome, &MetronomeParams), Changed<Metronome>>,
+) {
+    for (mt, params) in &query {
+        params.bpm.set(mt.bpm);
+        params.accent_every.set(mt.accent_every as f32);
     }
 }
+
+/// Advance each playing `Metronome`'s clock and send a `MetronomeTick` on
+/// every beat.
+///
+/// Uses the graph's beat math with the component's current values, like
+/// `heartbeat_pulse_system`, so the messages track the audible clicks.
+pub fn metronome_tick_system(
+    time: Res<Time>,
+    mut query: Query<(Entity, &Metronome, &mut MetronomeClock), With<MetronomeParams>>,
+    mut ticks: MessageWriter<MetronomeTick>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mt, mut clock) in &mut query {
+        let beat = beat_position(clock.elapsed, mt.bpm) as u64;
+        if clock.beat != Some(beat) {
+            ticks.write(MetronomeTick {
+                entity,
+                beat,
+                accent: is_accent(beat, mt.accent_every),
+            });
+            clock.beat = Some(beat);
+        }
+        clock.elapsed += dt;
+    }
+}
+
+/// Sync changed `Pan` values to the pan stage.
+pub fn pan_sync_system(query: Query<(&Pan, &PanParams), Changed<Pan>>) {
+    for (pan, params) in &query {
+        params.pan.set(pan.0);
+    }
+}
+
+/// Sync changed `Occlusion` amounts to the occlusion stage.
+pub fn occlusion_sync_system(query: Query<(&Occlusion, &OcclusionParams), Changed<Occlusion>>) {
+    for (occlusion, params) in &query {
+        params.set(occlusion.0);
+    }
+}
+
+/// Sync changed `Playback` state to the pause stage. Removing `Playback`
+/// resumes the sound.
+pub fn playback_sync_system(
+    query: Query<(&Playback, &PlaybackParams), Changed<Playback>>,
+    mut removed: RemovedComponents<Playback>,
+    params: Query<&PlaybackParams>,
+) {
+    for (playback, params) in &query {
+        params.gain.set(if playback.paused { 0.0 } else { 1.0 });
+    }
+    for entity in removed.read() {
+        if let Ok(params) = params.get(entity) {
+            params.gain.set(1.0);
+        }
+    }
+}
+
+/// Sync `ReverbSend` levels to the bus sends. Removing the component silences
+/// the send.
+pub fn reverb_send_sync_system(
+    query: Query<(&ReverbSend, &ReverbSendParams), Changed<ReverbSend>>,
+    mut removed: RemovedComponents<ReverbSend>,
+    params: Query<&ReverbSendParams>,
+) {
+    for (send, params) in &query {
+        params.level.set(send.0);
+    }
+    for entity in removed.read() {
+        if let Ok(params) = params.get(entity) {
+            params.level.set(0.0);
+        }
+    }
+}
+
+/// Copy each source's decoder meter into its `AudioLevel`.
+///
+/// Runs every frame: the level changes with the audio, not with any component.
+pub fn audio_level_system(
+    assets: Res<Assets<ProceduralAudio>>,
+    mut query: Query<(&AudioPlayer<ProceduralAudio>, &mut AudioLevel)>,
+) {
+    for (player, mut level) in &mut query {
+        if let Some(audio) = assets.get(&player.0) {
+            let meter = audio.meter();
+            level.rms = meter.rms();
+            level.peak = meter.peak();
+        }
+    }
+}
+
+/// Pass each `TransientDetect` sensitivity to its source's decoders and send
+/// a `TransientDetected` for every onset they found since the last frame.
+pub fn transient_system(
+    assets: Res<Assets<ProceduralAudio>>,
+    mut query: Query<(
+        Entity,
+        &AudioPlayer<ProceduralAudio>,
+        &TransientDetect,
+        &mut TransientCount,
+    )>,
+    mut detected: MessageWriter<TransientDetected>,
+) {
+    for (entity, player, detect, mut seen) in &mut query {
+        let Some(audio) = assets.get(&player.0) else {
+            continue;
+        };
+        let tap = audio.transients();
+        tap.set_sensitivity(detect.sensitivity);
+        let count = tap.count();
+        if seen.source != player.0.id() {
+            *seen = TransientCount {
+                source: player.0.id(),
+                seen: 0,
+            };
+        }
+        for _ in 0..count.saturating_sub(seen.seen) {
+            detected.write(TransientDetected { entity });
+        }
+        seen.seen = count;
+    }
+}
+
+/// Duck each `SideChain` target by its source's current output level.
+pub fn side_chain_system(
+    assets: Res<Assets<ProceduralAudio>>,
+    sources: Query<&AudioPlayer<ProceduralAudio>>,
+    targets: Query<(&SideChain, &SideChainParams)>,
+) {
+    for (side_chain, params) in &targets {
+        let rms = sources
+            .get(side_chain.source)
+            .ok()
+            .and_then(|player| assets.get(&player.0))
+            .map_or(0.0, |audio| audio.meter().rms());
+        params.set(rms, side_chain.amount);
+    }
+}
+
+/// Mirror `AudioConfig` decoder options to the audio thread when it changes.
+pub fn audio_config_system(config: Res<AudioConfig>, output: Res<OutputSettings>) {
+    if config.is_changed() {
+        output.apply_audio_config(&config);
+    }
+}
+
+/// Mirror `Mixer` bus volumes to the audio thread when it changes.
+pub fn mixer_sync_system(mixer: Res<Mixer>, output: Res<OutputSettings>) {
+    if mixer.is_changed() {
+        output.apply_mixer(&mixer);
+    }
+}
+
+/// Share the plugin's `OutputSettings` with each source's audio.
+///
+/// Runs in `PostUpdate` before Bevy creates decoders, like
+/// `mix_bus_sync_system`, so every new sound follows `AudioConfig` and
+/// `Mixer`.
+pub fn output_settings_system(
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    output: Res<OutputSettings>,
+    query: Query<&AudioPlayer<ProceduralAudio>, Changed<AudioPlayer<ProceduralAudio>>>,
+) {
+    for player in &query {
+        let shared = assets
+            .get(&player.0)
+            .is_none_or(|audio| audio.output_settings().is_shared_with(&output));
+        if shared {
+            continue;
+        }
+        if let Some(audio) = assets.get_mut(&player.0) {
+            audio.set_output_settings(output.clone());
+        }
+    }
+}
+
+/// Point each source's audio at its `MixBus`.
+///
+/// Runs in `PostUpdate` before Bevy creates decoders, so new sounds start on
+/// the right bus; later `MixBus` changes reach running decoders too.
+///
+/// A sound sharing a baked asset with identical spawns (see
+/// [`BakeCache`]) is moved to a copy of its own, picking up where it was,
+/// so the other sharers stay on their bus.
+#[allow(clippy::type_complexity)]
+pub fn mix_bus_sync_system(
+    mut commands: Commands,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    bake_cache: Res<BakeCache>,
+    query: Query<
+        (
+            Entity,
+            &MixBus,
+            &AudioPlayer<ProceduralAudio>,
+            Option<&OneShotLifetime>,
+        ),
+        Or<(Changed<MixBus>, Changed<AudioPlayer<ProceduralAudio>>)>,
+    >,
+) {
+    for (entity, bus, player, lifetime) in &query {
+        if assets.get(&player.0).is_none_or(|audio| audio.mix_bus() == *bus) {
+            continue;
+        }
+        let elapsed = lifetime.map_or(0.0, |lifetime| lifetime.elapsed);
+        let handle = match bake_cache.unshare(&player.0, elapsed, &mut assets) {
+            Some(handle) => {
+                play_unshared(&mut commands, entity, handle.clone());
+                handle
+            }
+            None => player.0.clone(),
+        };
+        if let Some(audio) = assets.get(&handle) {
+            audio.set_mix_bus(*bus);
+        }
+    }
+}
+
+/// Push `PlaybackRate` to each source's audio, like `mix_bus_sync_system`,
+/// including moving sounds off a shared baked asset. Removing the component
+/// returns to normal speed.
+#[allow(clippy::type_complexity)]
+pub fn playback_rate_sync_system(
+    mut commands: Commands,
+    mut assets: ResMut<Assets<ProceduralAudio>>,
+    bake_cache: Res<BakeCache>,
+    query: Query<
+        (
+            Entity,
+            &PlaybackRate,
+            &AudioPlayer<ProceduralAudio>,
+            Option<&OneShotLifetime>,
+        ),
+        Or<(Changed<PlaybackRate>, Changed<AudioPlayer<ProceduralAudio>>)>,
+    >,
+    mut removed: RemovedComponents<PlaybackRate>,
+    players: Query<&AudioPlayer<ProceduralAudio>>,
+) {
+    for (entity, rate, player, lifetime) in &query {
+        let elapsed = lifetime.map_or(0.0, |lifetime| lifetime.elapsed);
+        let handle = match bake_cache.unshare(&player.0, elapsed, &mut assets) {
+            Some(handle) => {
+                play_unshared(&mut commands, entity, handle.clone());
+                handle
+            }
+            None => player.0.clone(),
+        };
+        if let Some(audio) = assets.get(&handle) {
+            audio.set_playback_rate(rate.0);
+        }
+    }
+    for entity in removed.read() {
+        let Ok(player) = players.get(entity) else {
+            continue;
+        };
+        // Shared assets always play at normal speed.
+        if bake_cache.is_shared(player.0.id()) {
+            continue;
+        }
+        if let Some(audio) = assets.get(&player.0) {
+            audio.set_playback_rate(1.0);
+        }
+    }
+}
+
+/// Switch `entity` to its own copy of a shared asset; Bevy starts a new
+/// sink for it once the old one is gone.
+fn play_unshared(commands: &mut Commands, entity: Entity, handle: Handle<ProceduralAudio>) {
+    commands
+        .entity(entity)
+        .insert(AudioPlayer(handle))
+        .remove::<AudioSink>();
+}
+
+/// Write the `Frequency` of entities with a `FrequencyFromTransform` from
+/// their position, for `param_sync_system` to send on. Only touches
+/// `Frequency` when the mapped value changes.
+#[allow(clippy::type_complexity)]
+pub fn frequency_from_transform_system(
+    mut query: Query<
+        (&FrequencyFromTransform, &GlobalTransform, &mut Frequency),
+        Or<(Changed<GlobalTransform>, Changed<FrequencyFromTransform>)>,
+    >,
+) {
+    for (mapping, transform, mut frequency) in &mut query {
+        let hz = mapping.frequency(transform.translation());
+        if frequency.0 != hz {
+            frequency.0 = hz;
+        }
+    }
+}
+
+/// Speed of sound in world units per second, assuming 1 unit = 1 meter.
+const SPEED_OF_SOUND: f32 = 343.0;
+
+/// Write distance attenuation, equal-power pan, air absorption and Doppler
+/// for spatial emitters.
+///
+/// Runs every frame, as emitters and the listener move without touching any
+/// audio component. Doppler goes to the synth's separate pitch multiplier,
+/// leaving `Frequency` and automation in charge of the frequency itself.
+/// Does nothing when there is no `SpatialListener`.
+#[allow(clippy::type_complexity)]
+pub fn spatial_sync_system(
+    time: Res<Time>,
+    mut previous_listener: Local<Option<Vec3>>,
+    listener: Query<&GlobalTransform, With<SpatialListener>>,
+    mut emitters: Query<(
+        &SpatialEmitter,
+        &GlobalTransform,
+        &SpatialParams,
+        &mut DopplerShift,
+        Option<&SynthParams>,
+    )>,
+) {
+    let Ok(listener) = listener.single() else {
+        return;
+    };
+    let right = listener.right();
+    let dt = time.delta_secs();
+
+    let listener_position = listener.translation();
+    let listener_velocity = velocity(*previous_listener, listener_position, dt);
+    *previous_listener = Some(listener_position);
+
+    for (emitter, transform, params, mut doppler, synth) in &mut emitters {
+        let position = transform.translation();
+        let offset = position - listener_position;
+        let distance = offset.length();
+
+        let emitter_velocity = velocity(doppler.previous_position, position, dt);
+        doppler.previous_position = Some(position);
+        doppler.factor = match (emitter.doppler, emitter_velocity, listener_velocity) {
+            (true, Some(ev), Some(lv)) if distance > f32::EPSILON => {
+                // Radial speeds along the listener→emitter axis; positive = moving apart.
+                let direction = offset / distance;
+                let emitter_radial = ev.dot(direction);
+                let listener_radial = -lv.dot(direction);
+                ((SPEED_OF_SOUND - listener_radial) / (SPEED_OF_SOUND + emitter_radial))
+                    .clamp(0.5, 2.0)
+            }
+            _ => 1.0,
+        };
+        if let Some(synth_params) = synth {
+            if synth_params.doppler.value() != doppler.factor {
+                synth_params.doppler.set(doppler.factor);
+            }
+        }
+
+        let falloff = (1.0 - distance / emitter.max_distance.max(f32::EPSILON)).clamp(0.0, 1.0);
+        let gain = falloff.powf(emitter.rolloff.max(0.0));
+
+        // -1.0 = hard left, 1.0 = hard right. A source on top of the listener is centered.
+        let pan = if distance > f32::EPSILON {
+            (offset.dot(*right) / distance).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        };
+        // Equal-power law: map pan onto a quarter circle.
+        let angle = (pan + 1.0) * std::f32::consts::FRAC_PI_4;
+        params.left.set(gain * angle.cos());
+        params.right.set(gain * angle.sin());
+
+        if let Some(ref cutoff) = params.filter_cutoff {
+            cutoff.set(air_absorption_cutoff(distance, emitter.max_distance));
+        }
+    }
+}
+
+/// Per-frame velocity from the previous position, if there was one.
+fn velocity(previous: Option<Vec3>, current: Vec3, dt: f32) -> Option<Vec3> {
+    if dt > 0.0 {
+        previous.map(|p| (current - p) / dt)
+    } else {
+        None
+    }
+}
+
+/// Distance to low-pass cutoff for air absorption.
+///
+/// Sources within the first 10% of `max_distance` stay fully bright; beyond
+/// that the cutoff falls exponentially from 20 kHz to 2 kHz at `max_distance`.
+fn air_absorption_cutoff(distance: f32, max_distance: f32) -> f32 {
+    const NEAR: f32 = 0.1;
+    const OPEN_HZ: f32 = 20000.0;
+    const FAR_HZ: f32 = 2000.0;
+
+    let t = distance / max_distance.max(f32::EPSILON);
+    let t = ((t - NEAR) / (1.0 - NEAR)).clamp(0.0, 1.0);
+    OPEN_HZ * (FAR_HZ / OPEN_HZ).powf(t)
+}
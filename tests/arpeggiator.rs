@@ -0,0 +1,42 @@
+//! Checks for the arpeggiator's note orders.
+
+use bevy_proc_aud::components::arpeggiator::ArpMode;
+use bevy_proc_aud::config::ProcAudRng;
+
+fn pattern(mode: ArpMode, len: usize, steps: usize) -> Vec<usize> {
+    let mut rng = ProcAudRng::seeded(1);
+    (0..steps)
+        .map(|step| mode.index(step, len, &mut rng.0))
+        .collect()
+}
+
+#[test]
+fn up_and_down_wrap() {
+    assert_eq!(pattern(ArpMode::Up, 3, 7), [0, 1, 2, 0, 1, 2, 0]);
+    assert_eq!(pattern(ArpMode::Down, 3, 7), [2, 1, 0, 2, 1, 0, 2]);
+}
+
+#[test]
+fn up_down_turns_without_repeating_ends() {
+    assert_eq!(
+        pattern(ArpMode::UpDown, 4, 13),
+        [0, 1, 2, 3, 2, 1, 0, 1, 2, 3, 2, 1, 0]
+    );
+    assert_eq!(pattern(ArpMode::UpDown, 2, 5), [0, 1, 0, 1, 0]);
+}
+
+#[test]
+fn single_note_and_random_stay_in_range() {
+    for mode in [ArpMode::Up, ArpMode::Down, ArpMode::UpDown, ArpMode::Random] {
+        assert!(pattern(mode, 1, 10).iter().all(|&index| index == 0));
+        assert!(pattern(mode, 5, 100).iter().all(|&index| index < 5));
+    }
+}
+
+#[test]
+fn random_order_repeats_with_the_seed() {
+    assert_eq!(
+        pattern(ArpMode::Random, 8, 32),
+        pattern(ArpMode::Random, 8, 32)
+    );
+}
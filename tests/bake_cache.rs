@@ -0,0 +1,84 @@
+//! Checks for the bake cache's shared audio assets.
+
+use std::sync::Arc;
+
+use bevy::asset::Assets;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::World;
+use bevy_proc_aud::dsp::bake::BakeCache;
+use bevy_proc_aud::dsp::source::ProceduralAudio;
+use bevy_proc_aud::presets::reference_graphs;
+use bevy_proc_aud::systems::build::loudness_measure_system;
+
+fn silence() -> ProceduralAudio {
+    ProceduralAudio::baked(Arc::new(vec![0.0; 64]), 44100, 2)
+}
+
+#[test]
+fn identical_keys_share_one_asset() {
+    let mut cache = BakeCache::default();
+    let mut assets = Assets::<ProceduralAudio>::default();
+    let mut created = 0;
+    let mut get = |cache: &mut BakeCache, key: &str| {
+        cache.get_or_add_asset(key.to_string(), &mut assets, |_| {
+            created += 1;
+            silence()
+        })
+    };
+
+    let first = get(&mut cache, "impact");
+    let second = get(&mut cache, "impact");
+    let other = get(&mut cache, "impact/Ui");
+    assert_eq!(first.id(), second.id());
+    assert_ne!(first.id(), other.id());
+
+    cache.clear();
+    let fresh = get(&mut cache, "impact");
+    assert_ne!(first.id(), fresh.id());
+    assert_eq!(created, 3);
+}
+
+#[test]
+fn unshare_copies_only_shared_assets() {
+    let mut cache = BakeCache::default();
+    let mut assets = Assets::<ProceduralAudio>::default();
+    let shared = cache.get_or_add_asset("impact".to_string(), &mut assets, |_| silence());
+    let own = assets.add(silence());
+    assert!(cache.is_shared(shared.id()));
+    assert!(!cache.is_shared(own.id()));
+
+    let copy = cache.unshare(&shared, 0.0, &mut assets).unwrap();
+    assert_ne!(copy.id(), shared.id());
+    assert!(!cache.is_shared(copy.id()));
+    assert!(cache.unshare(&own, 0.0, &mut assets).is_none());
+}
+
+#[test]
+fn least_recently_used_renders_are_evicted_past_the_budget() {
+    use fundsp::prelude32::*;
+
+    // Each render is 100 mono samples; the budget holds two.
+    let mut cache = BakeCache::with_max_samples(200);
+    let bake = |cache: &mut BakeCache, key: &str| {
+        cache.get_or_bake(key.to_string(), 1.0, 100, || Box::new(dc(0.0)))
+    };
+    bake(&mut cache, "a");
+    bake(&mut cache, "b");
+    bake(&mut cache, "a");
+    bake(&mut cache, "c");
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains("a"));
+    assert!(!cache.contains("b"));
+    assert!(cache.contains("c"));
+}
+
+#[test]
+fn startup_measures_every_preset() {
+    let mut world = World::new();
+    world.init_resource::<BakeCache>();
+    world.run_system_once(loudness_measure_system).unwrap();
+    let mut cache = world.resource_mut::<BakeCache>();
+    for (preset, _) in reference_graphs() {
+        cache.normalization_gain(preset, || panic!("measured again on first spawn"));
+    }
+}
@@ -0,0 +1,33 @@
+//! Checks for the psychoacoustic bass enhancer stage.
+
+use bevy_proc_aud::dsp::bass::build_bass_enhancer_stage;
+use bevy_proc_aud::dsp::render::render_to_buffer;
+use fundsp::prelude32::*;
+
+const SAMPLE_RATE: u32 = 44100;
+
+/// Energy added by the enhancer to a stereo tone at `hz`, relative to the
+/// tone's own energy, measured after the filters settle.
+fn added_energy(hz: f32) -> f32 {
+    let tone = || -> Box<dyn AudioUnit> { Box::new((sine_hz(hz) * 0.5) >> split::<U2>()) };
+    let (dry, _) = render_to_buffer(&mut *tone(), 1.0, SAMPLE_RATE);
+    let (wet, _) = render_to_buffer(
+        &mut *build_bass_enhancer_stage(tone(), 1.0),
+        1.0,
+        SAMPLE_RATE,
+    );
+    let settled = dry.len() / 2;
+    let energy = |samples: &[f32]| samples.iter().map(|s| s * s).sum::<f32>();
+    let difference: Vec<f32> = wet[settled..]
+        .iter()
+        .zip(&dry[settled..])
+        .map(|(w, d)| w - d)
+        .collect();
+    energy(&difference) / energy(&dry[settled..])
+}
+
+#[test]
+fn sub_bass_gains_harmonics_and_mids_pass() {
+    assert!(added_energy(50.0) > 0.05);
+    assert!(added_energy(2000.0) < 1e-3);
+}
@@ -0,0 +1,21 @@
+//! Checks for the blunt impact preset's oscillator layers and graph shape.
+
+use bevy_proc_aud::components::variation::Jitter;
+use bevy_proc_aud::presets::blunt_impact::{
+    build_blunt_impact_graph, clang_layer, thud_layer, BluntImpact,
+};
+
+#[test]
+fn layers_are_single_output_generators() {
+    for layer in [thud_layer(1.0), clang_layer(1.0)] {
+        assert_eq!(layer.inputs(), 0);
+        assert_eq!(layer.outputs(), 1);
+    }
+}
+
+#[test]
+fn graph_is_a_stereo_generator() {
+    let graph = build_blunt_impact_graph(&BluntImpact::default(), Jitter::NONE);
+    assert_eq!(graph.inputs(), 0);
+    assert_eq!(graph.outputs(), 2);
+}
@@ -0,0 +1,98 @@
+//! Checks for the spawn schedule of a `Burst`.
+
+use std::time::Duration;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::{Assets, Entity, Time, World};
+use bevy_proc_aud::components::burst::Burst;
+use bevy_proc_aud::components::lifetime::OneShotLifetime;
+use bevy_proc_aud::components::playback::PlaybackRate;
+use bevy_proc_aud::components::sequencer::PresetTrigger;
+use bevy_proc_aud::config::{AudioConfig, ProcAudRng};
+use bevy_proc_aud::dsp::bake::BakeCache;
+use bevy_proc_aud::dsp::source::ProceduralAudio;
+use bevy_proc_aud::presets::ui_blip::UiBlip;
+use bevy_proc_aud::systems::build::ui_blip_build_system;
+use bevy_proc_aud::systems::lifecycle::oneshot_lifetime_system;
+use bevy_proc_aud::systems::sync::burst_system;
+
+#[test]
+fn schedule_starts_at_once_and_stays_in_spread() {
+    let burst = Burst {
+        count: 12,
+        spread_secs: 0.3,
+        ..Default::default()
+    };
+    let mut rng = ProcAudRng::seeded(7);
+    for _ in 0..20 {
+        let times = burst.schedule(&mut rng.0);
+        assert_eq!(times.len(), 12);
+        assert_eq!(times[0], 0.0);
+        assert!(times.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(times.iter().all(|t| (0.0..=0.3).contains(t)));
+    }
+}
+
+#[test]
+fn empty_or_unspread_bursts() {
+    let empty = Burst {
+        count: 0,
+        ..Default::default()
+    };
+    let mut rng = ProcAudRng::default();
+    assert!(empty.schedule(&mut rng.0).is_empty());
+    // A negative spread plays every copy together.
+    let stacked = Burst {
+        count: 3,
+        spread_secs: -1.0,
+        ..Default::default()
+    };
+    assert_eq!(stacked.schedule(&mut rng.0), vec![0.0; 3]);
+}
+
+#[test]
+fn seeded_schedules_repeat() {
+    let burst = Burst {
+        count: 8,
+        ..Default::default()
+    };
+    let (mut a, mut b) = (ProcAudRng::seeded(42), ProcAudRng::seeded(42));
+    assert_eq!(burst.schedule(&mut a.0), burst.schedule(&mut b.0));
+}
+
+#[test]
+fn pitched_copies_live_as_long_as_their_sound() {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<Assets<ProceduralAudio>>();
+    world.init_resource::<AudioConfig>();
+    world.init_resource::<BakeCache>();
+    world.insert_resource(ProcAudRng::seeded(3));
+    world.spawn(Burst {
+        preset: PresetTrigger::UiBlip(UiBlip::default()),
+        count: 8,
+        spread_secs: 0.0,
+        pitch_jitter: 0.5,
+    });
+    world.run_system_once(burst_system).unwrap();
+    world.run_system_once(ui_blip_build_system).unwrap();
+
+    let copies: Vec<(Entity, f32, f32)> = world
+        .query::<(Entity, &OneShotLifetime, &PlaybackRate)>()
+        .iter(&world)
+        .map(|(entity, lifetime, rate)| (entity, lifetime.duration, rate.0))
+        .collect();
+    assert_eq!(copies.len(), 8);
+    assert!(copies.iter().any(|&(_, _, rate)| rate < 0.9));
+
+    // Just past the nominal length, only the slowed copies are still playing.
+    let secs = copies[0].1 * 1.1;
+    world
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(secs));
+    world.run_system_once(oneshot_lifetime_system).unwrap();
+    for (entity, duration, rate) in copies {
+        let playing = secs * rate < duration;
+        assert_eq!(world.get_entity(entity).is_ok(), playing, "rate {rate}");
+    }
+}
@@ -0,0 +1,76 @@
+//! Checks for the partitioned FFT convolver.
+
+use bevy_proc_aud::dsp::convolution::{Convolver, ImpulseResponse, BLOCK};
+use fundsp::prelude32::*;
+
+const SAMPLE_RATE: f32 = 44100.0;
+
+/// Run `signal` through a convolver with `response`, returning the left and
+/// right outputs.
+fn convolve(response: &ImpulseResponse, signal: &[f32]) -> (Vec<f32>, Vec<f32>) {
+    let mut convolver = Convolver::new(response);
+    convolver.set_sample_rate(SAMPLE_RATE as f64);
+    signal
+        .iter()
+        .map(|&x| {
+            let out = convolver.tick(&[x, x].into());
+            (out[0], out[1])
+        })
+        .unzip()
+}
+
+fn test_signal(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| (i as f32 * 0.05).sin() + 0.5 * (i as f32 * 0.31).sin())
+        .collect()
+}
+
+#[test]
+fn unit_impulse_passes_the_signal_one_block_late() {
+    let signal = test_signal(6 * BLOCK);
+    let (left, right) = convolve(&ImpulseResponse::mono(vec![1.0], SAMPLE_RATE), &signal);
+    for i in BLOCK..signal.len() {
+        assert!(
+            (left[i] - signal[i - BLOCK]).abs() < 1e-4,
+            "left sample {i}"
+        );
+        assert!(
+            (right[i] - signal[i - BLOCK]).abs() < 1e-4,
+            "right sample {i}"
+        );
+    }
+}
+
+#[test]
+fn matches_direct_convolution_across_partitions() {
+    // A response spanning several partitions, normalized to unit energy as
+    // the convolver does.
+    let response: Vec<f32> = (0..3 * BLOCK + 17)
+        .map(|i| (-(i as f32) / 300.0).exp() * if i % 7 < 3 { 1.0 } else { -0.5 })
+        .collect();
+    let gain = response.iter().map(|s| s * s).sum::<f32>().sqrt().recip();
+    let signal = test_signal(8 * BLOCK);
+    let (left, _) = convolve(
+        &ImpulseResponse::mono(response.clone(), SAMPLE_RATE),
+        &signal,
+    );
+
+    for i in (BLOCK..signal.len()).step_by(97) {
+        let n = i - BLOCK;
+        let expected: f32 = (0..=std::cmp::min(n, response.len() - 1))
+            .map(|k| signal[n - k] * response[k] * gain)
+            .sum();
+        assert!((left[i] - expected).abs() < 1e-3, "sample {i}");
+    }
+}
+
+#[test]
+fn stereo_response_keeps_channels_apart() {
+    let response = ImpulseResponse::new(vec![1.0], vec![0.0, 0.0, 1.0], SAMPLE_RATE);
+    let signal = test_signal(4 * BLOCK);
+    let (left, right) = convolve(&response, &signal);
+    for i in BLOCK + 2..signal.len() {
+        assert!((left[i] - signal[i - BLOCK]).abs() < 1e-4);
+        assert!((right[i] - signal[i - BLOCK - 2]).abs() < 1e-4);
+    }
+}
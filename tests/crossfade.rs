@@ -0,0 +1,51 @@
+//! Checks for swapping a playing graph with `ProceduralAudio::crossfade_to`.
+
+use std::sync::Arc;
+
+use bevy::audio::Decodable;
+use bevy_proc_aud::dsp::source::ProceduralAudio;
+use fundsp::prelude32::*;
+
+const SAMPLE_RATE: u32 = 44100;
+
+/// Next `frames` stereo frames' left channel.
+fn left(decoder: &mut impl Iterator<Item = f32>, frames: usize) -> Vec<f32> {
+    decoder.take(frames * 2).step_by(2).collect()
+}
+
+#[test]
+fn swap_fades_from_old_to_new_graph() {
+    // The master high-pass passes a constant graph's first sample and then
+    // settles it to zero, so the first sample shows which graph is playing
+    // and a cut between graphs would show as a jump of 1. Levels stay under
+    // the limiter's knee so they pass unchanged.
+    let audio = ProceduralAudio::new(Box::new(dc((0.5, 0.5))), SAMPLE_RATE, 2);
+    let mut decoder = audio.decoder();
+    let settled = left(&mut decoder, 4096);
+    assert!((settled[0] - 0.5).abs() < 1e-6);
+    assert!(settled.last().unwrap().abs() < 1e-3);
+
+    assert!(audio.crossfade_to(Box::new(dc((-0.5, -0.5))), 0.1).is_ok());
+    // Equal-power fade across 0.1s: no step larger than a smooth ramp's.
+    let fade = left(&mut decoder, SAMPLE_RATE as usize / 5);
+    let largest_step = fade
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .fold(0.0_f32, f32::max);
+    assert!(largest_step < 0.01, "step {largest_step}");
+    let lowest = fade.iter().copied().fold(f32::MAX, f32::min);
+    assert!(lowest < -0.05, "the fade never moved toward the new graph");
+
+    // A decoder started after the swap plays the new graph from the start.
+    let mut fresh = audio.decoder();
+    assert!((left(&mut fresh, 1)[0] + 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn swap_is_refused_for_baked_audio_and_channel_mismatch() {
+    let baked = ProceduralAudio::baked(Arc::new(vec![0.0; 64]), SAMPLE_RATE, 2);
+    assert!(baked.crossfade_to(Box::new(dc((0.0, 0.0))), 0.1).is_err());
+
+    let live = ProceduralAudio::new(Box::new(dc((0.0, 0.0))), SAMPLE_RATE, 2);
+    assert!(live.crossfade_to(Box::new(dc(0.0)), 0.1).is_err());
+}
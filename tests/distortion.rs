@@ -0,0 +1,33 @@
+//! Checks for the distortion transfer curves.
+
+use bevy_proc_aud::components::effect::DistortionShape;
+
+const SHAPES: [DistortionShape; 5] = [
+    DistortionShape::SoftClip,
+    DistortionShape::HardClip,
+    DistortionShape::Foldback,
+    DistortionShape::Cubic,
+    DistortionShape::Diode,
+];
+
+#[test]
+fn every_shape_is_bounded_and_passes_zero() {
+    for shape in SHAPES {
+        assert_eq!(shape.apply(0.0), 0.0, "{shape:?}");
+        for i in -400..=400 {
+            let y = shape.apply(i as f32 * 0.05);
+            assert!(y.abs() <= 1.0 + 1e-6, "{shape:?} at {}", i as f32 * 0.05);
+        }
+    }
+}
+
+#[test]
+fn foldback_reflects_and_diode_is_asymmetric() {
+    let fold = DistortionShape::Foldback;
+    assert!((fold.apply(0.5) - 0.5).abs() < 1e-6);
+    assert!((fold.apply(1.5) - 0.5).abs() < 1e-6);
+    assert!((fold.apply(-1.5) + 0.5).abs() < 1e-6);
+
+    let diode = DistortionShape::Diode;
+    assert!(diode.apply(2.0) > -diode.apply(-2.0) * 2.0);
+}
@@ -0,0 +1,35 @@
+//! Checks for mapping a position onto a synth frequency.
+
+use bevy::math::Vec3;
+use bevy_proc_aud::components::synth::{FrequencyFromTransform, TransformAxis};
+
+#[test]
+fn position_maps_exponentially_and_clamps() {
+    let mapping = FrequencyFromTransform::default();
+    let at = |y: f32| mapping.frequency(Vec3::new(3.0, y, -2.0));
+    assert!((at(0.0) - 110.0).abs() < 1e-3);
+    assert!((at(10.0) - 880.0).abs() < 1e-2);
+    // Halfway up is halfway in octaves: 110Hz × 2^1.5.
+    assert!((at(5.0) - 311.127).abs() < 1e-2);
+    assert!((at(-4.0) - 110.0).abs() < 1e-3);
+    assert!((at(25.0) - 880.0).abs() < 1e-2);
+}
+
+#[test]
+fn axis_and_inverted_range() {
+    let mapping = FrequencyFromTransform {
+        axis: TransformAxis::X,
+        min_pos: 10.0,
+        max_pos: 0.0,
+        ..Default::default()
+    };
+    assert!((mapping.frequency(Vec3::new(10.0, 5.0, 5.0)) - 110.0).abs() < 1e-3);
+    assert!((mapping.frequency(Vec3::new(0.0, 5.0, 5.0)) - 880.0).abs() < 1e-2);
+
+    let flat = FrequencyFromTransform {
+        min_pos: 2.0,
+        max_pos: 2.0,
+        ..Default::default()
+    };
+    assert_eq!(flat.frequency(Vec3::splat(7.0)), 110.0);
+}
@@ -0,0 +1,36 @@
+//! Checks for the despawn timing of one-shots.
+
+use std::time::Duration;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::{Time, World};
+use bevy_proc_aud::components::lifetime::OneShotLifetime;
+use bevy_proc_aud::components::playback::PlaybackRate;
+use bevy_proc_aud::systems::lifecycle::oneshot_lifetime_system;
+
+/// Advance the clock by `secs` and run the lifetime system once.
+fn tick(world: &mut World, secs: f32) {
+    world
+        .resource_mut::<Time>()
+        .advance_by(Duration::from_secs_f32(secs));
+    world.run_system_once(oneshot_lifetime_system).unwrap();
+}
+
+#[test]
+fn slowed_one_shot_outlives_its_nominal_duration() {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    let normal = world.spawn(OneShotLifetime::new(1.0)).id();
+    let slowed = world
+        .spawn((OneShotLifetime::new(1.0), PlaybackRate(0.5)))
+        .id();
+
+    tick(&mut world, 1.2);
+    assert!(world.get_entity(normal).is_err());
+    // Half speed: 1.2s in, only 0.6s of the sound has played.
+    let lifetime = world.get::<OneShotLifetime>(slowed).unwrap();
+    assert!((lifetime.elapsed - 0.6).abs() < 1e-4);
+
+    tick(&mut world, 0.9);
+    assert!(world.get_entity(slowed).is_err());
+}
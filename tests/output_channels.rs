@@ -0,0 +1,37 @@
+//! Checks that sources honor channel counts other than stereo.
+
+use bevy::audio::{Decodable, Source};
+use bevy_proc_aud::config::AudioConfig;
+use bevy_proc_aud::dsp::source::ProceduralAudio;
+use fundsp::prelude32::*;
+
+#[test]
+fn config_channels() {
+    let mut config = AudioConfig::default();
+    assert_eq!(config.channels(), 2);
+    config.output_channels = 6;
+    assert_eq!(config.channels(), 6);
+    config.force_mono = true;
+    assert_eq!(config.channels(), 1);
+}
+
+#[test]
+fn decoder_writes_every_channel() {
+    let graph = Box::new(dc((0.1, 0.2, 0.3, 0.4)));
+    let audio = ProceduralAudio::new(graph, 44100, 4);
+    let decoder = audio.decoder();
+    assert_eq!(decoder.channels(), 4);
+    // The DC blocker decays every channel alike, so the levels keep their
+    // 1:2:3:4 ratio.
+    let frame: Vec<f32> = decoder.skip(4 * 64).take(4).collect();
+    assert!(frame[0] > 0.0);
+    for (c, sample) in frame.iter().enumerate() {
+        assert!((sample / frame[0] - (c + 1) as f32).abs() < 1e-3, "channel {c}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "channel count")]
+fn mismatched_graph_is_rejected() {
+    ProceduralAudio::new(Box::new(dc((0.1, 0.2))), 44100, 4);
+}
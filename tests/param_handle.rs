@@ -0,0 +1,32 @@
+//! Checks for reading a parameter's smoothed value back from its graph.
+
+use bevy_proc_aud::dsp::spatial::build_occlusion_stage;
+use fundsp::prelude32::*;
+
+#[test]
+fn current_lags_the_target_during_a_glide() {
+    let (mut graph, params) = build_occlusion_stage(Box::new(dc((1.0, 1.0))), 0.0);
+    graph.set_sample_rate(44100.0);
+    graph.allocate();
+    let mut frame = [0.0; 2];
+    let mut run = |samples: usize| {
+        for _ in 0..samples {
+            graph.tick(&[], &mut frame);
+        }
+    };
+    run(64);
+    assert_eq!(params.gain.current(), 1.0);
+
+    params.set(1.0);
+    let target = params.gain.get();
+    assert!(target < 0.2);
+    assert_eq!(params.gain.current(), 1.0);
+
+    // Partway through the ~20 ms glide.
+    run(64);
+    let gliding = params.gain.current();
+    assert!(gliding < 1.0 && gliding > target + 0.1);
+
+    run(44100);
+    assert!((params.gain.current() - target).abs() < 1e-3);
+}
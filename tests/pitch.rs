@@ -0,0 +1,51 @@
+//! Checks for the MIDI and note-name conversions.
+
+use bevy_proc_aud::components::synth::Frequency;
+use bevy_proc_aud::pitch::{hz_to_midi, midi_to_hz, midi_to_note_name, note_name_to_midi};
+
+#[test]
+fn reference_pitches() {
+    assert_eq!(midi_to_hz(69), 440.0);
+    assert!((midi_to_hz(60) - 261.63).abs() < 0.01);
+    assert!((Frequency::from_note_name("C3").unwrap().0 - 130.81).abs() < 0.01);
+    assert!((midi_to_hz(81) - 880.0).abs() < 1e-3);
+}
+
+#[test]
+fn midi_round_trips_through_hz() {
+    for note in 0..=127 {
+        assert_eq!(hz_to_midi(midi_to_hz(note)).round() as u8, note);
+        assert!((Frequency::from_midi(note).to_midi() - note as f32).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn names_round_trip_through_midi() {
+    for note in 0..=127 {
+        let name = midi_to_note_name(note);
+        assert_eq!(note_name_to_midi(&name), Some(note), "{name}");
+    }
+}
+
+#[test]
+fn parses_accidentals_and_octaves() {
+    assert_eq!(note_name_to_midi("A4"), Some(69));
+    assert_eq!(note_name_to_midi("a4"), Some(69));
+    assert_eq!(note_name_to_midi("C#4"), Some(61));
+    assert_eq!(note_name_to_midi("Db4"), Some(61));
+    assert_eq!(note_name_to_midi("D♭4"), Some(61));
+    assert_eq!(note_name_to_midi("F##2"), Some(43));
+    assert_eq!(note_name_to_midi("Bb2"), Some(46));
+    // Accidentals may cross the octave boundary.
+    assert_eq!(note_name_to_midi("B#3"), Some(60));
+    assert_eq!(note_name_to_midi("Cb4"), Some(59));
+    assert_eq!(note_name_to_midi("C-1"), Some(0));
+    assert_eq!(note_name_to_midi("G9"), Some(127));
+}
+
+#[test]
+fn rejects_invalid_names() {
+    for name in ["", "H4", "C", "C#", "Cx4", "4C", "G#9", "Cb-1", "C4.5"] {
+        assert_eq!(note_name_to_midi(name), None, "{name}");
+    }
+}
@@ -0,0 +1,56 @@
+//! Checks for polyphonic synth graphs.
+
+use bevy_proc_aud::components::polyphony::Polyphony;
+use bevy_proc_aud::components::synth::{Amplitude, Frequency, OscillatorType};
+use bevy_proc_aud::dsp::graph_builder::GraphSpec;
+use bevy_proc_aud::dsp::render::render_to_buffer;
+
+const SAMPLE_RATE: u32 = 44100;
+
+fn peak(samples: &[f32]) -> f32 {
+    samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()))
+}
+
+#[test]
+fn builds_one_handle_per_voice() {
+    let poly = Polyphony {
+        max_voices: 4,
+        ..Default::default()
+    };
+    let (_, params) = GraphSpec::new(&OscillatorType::Saw, &Frequency(220.0), &Amplitude(0.3))
+        .polyphony(&poly)
+        .build();
+    assert_eq!(params.voices.len(), 4);
+
+    let (_, params) =
+        GraphSpec::new(&OscillatorType::Saw, &Frequency(220.0), &Amplitude(0.3)).build();
+    assert!(params.voices.is_empty());
+}
+
+#[test]
+fn silent_until_a_voice_plays() {
+    let poly = Polyphony::default();
+    let (mut graph, params) =
+        GraphSpec::new(&OscillatorType::Sine, &Frequency(220.0), &Amplitude(0.5))
+            .polyphony(&poly)
+            .build();
+    let (samples, _) = render_to_buffer(graph.as_mut(), 0.1, SAMPLE_RATE);
+    assert_eq!(peak(&samples), 0.0);
+
+    // Two voices of a chord.
+    for (voice, frequency) in params.voices.iter().zip([261.63, 329.63]) {
+        voice.frequency.set_value(frequency);
+        voice.level.set_value(0.8);
+    }
+    let (samples, _) = render_to_buffer(graph.as_mut(), 0.2, SAMPLE_RATE);
+    assert!(samples.iter().all(|s| s.is_finite()));
+    assert!(peak(&samples) > 0.3);
+
+    // Released voices fade out within their release.
+    for voice in &params.voices {
+        voice.level.set_value(0.0);
+    }
+    let (samples, _) = render_to_buffer(graph.as_mut(), 3.0, SAMPLE_RATE);
+    let tail = &samples[samples.len() - 2 * SAMPLE_RATE as usize / 10..];
+    assert!(peak(tail) < 1e-3);
+}
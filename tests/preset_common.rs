@@ -0,0 +1,131 @@
+//! Checks for the shared preset envelope and reverb helpers.
+
+use bevy_proc_aud::dsp::param::ParamHandle;
+use bevy_proc_aud::dsp::render::render_to_buffer;
+use bevy_proc_aud::presets::common::{
+    ad_env, apply_live_reverb, apply_reverb, attack_decay, attack_ramp, stutter_env, stutter_gate,
+    ReverbShape, MIN_ATTACK_SECS,
+};
+use fundsp::prelude32::*;
+
+const SAMPLE_RATE: u32 = 44100;
+
+const ROOM: ReverbShape = ReverbShape {
+    room_size: 0.5,
+    time: 1.0,
+    diffusion: 0.5,
+    cutoff_hz: 4000.0,
+};
+
+fn peak(samples: &[f32]) -> f32 {
+    samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()))
+}
+
+#[test]
+fn attack_ramp_lasts_at_least_the_minimum() {
+    // A 5000/s ramp would take 0.2ms; it is slowed to the 1ms floor.
+    assert_eq!(attack_ramp(0.0, 5000.0), 0.0);
+    assert!((attack_ramp(MIN_ATTACK_SECS * 0.5, 5000.0) - 0.5).abs() < 1e-3);
+    assert_eq!(attack_ramp(MIN_ATTACK_SECS * 2.0, 5000.0), 1.0);
+    // Slower ramps keep their own length.
+    assert!((attack_ramp(0.025, 20.0) - 0.5).abs() < 1e-6);
+    assert_eq!(attack_ramp(1.0, 20.0), 1.0);
+}
+
+#[test]
+fn attack_decay_rises_then_falls() {
+    assert_eq!(attack_decay(0.0, 100.0, 10.0), 0.0);
+    let top = attack_decay(0.01, 100.0, 10.0);
+    assert!((top - (-0.1_f32).exp()).abs() < 1e-6);
+    assert!(attack_decay(0.5, 100.0, 10.0) < top * 0.01);
+}
+
+#[test]
+fn stutter_gate_is_rectified_and_bounded() {
+    let values: Vec<f32> = (0..1000)
+        .map(|i| stutter_gate(i as f32 / 1000.0, &[73.0, 113.0, 151.7]))
+        .collect();
+    assert!(values.iter().all(|v| (0.0..=1.0).contains(v)));
+    assert!(values.contains(&0.0));
+    assert!(values.iter().any(|v| *v > 0.1));
+}
+
+#[test]
+fn ad_env_is_silent_after_its_stretched_length() {
+    let mut env = ad_env(200.0, 5.0, 0.2, 2.0);
+    let (samples, _) = render_to_buffer(&mut env, 0.6, SAMPLE_RATE);
+    let at = |secs: f32| samples[(secs * SAMPLE_RATE as f32) as usize];
+    assert!(at(0.05) > 0.5);
+    // Still sounding at 0.3s: the 0.2s length is stretched to 0.4s.
+    assert!(at(0.3) > 0.0);
+    assert_eq!(peak(&samples[(0.45 * SAMPLE_RATE as f32) as usize..]), 0.0);
+}
+
+#[test]
+fn stutter_env_gates_and_ends() {
+    let mut env = stutter_env([73.0, 113.0], 4.0, 0.3, 1.0);
+    let (samples, _) = render_to_buffer(&mut env, 0.5, SAMPLE_RATE);
+    let body = &samples[..(0.3 * SAMPLE_RATE as f32) as usize];
+    assert!(body.iter().all(|s| (0.0..=1.0).contains(s)));
+    assert!(peak(body) > 0.1);
+    assert_eq!(peak(&samples[(0.35 * SAMPLE_RATE as f32) as usize..]), 0.0);
+}
+
+#[test]
+fn apply_reverb_dry_mix_is_unchanged() {
+    let dry = Net::wrap(Box::new(sine_hz(440.0) >> split::<U2>()));
+    let mut reference = dry.clone();
+    let mut mixed = apply_reverb(dry, 0.0, ROOM);
+    let (expected, _) = render_to_buffer(&mut reference, 0.1, SAMPLE_RATE);
+    let (actual, channels) = render_to_buffer(&mut mixed, 0.1, SAMPLE_RATE);
+    assert_eq!(channels, 2);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn apply_reverb_wet_mix_rings_on() {
+    // A 50ms burst, then silence: only the reverb tail is left at 0.3s.
+    let burst = lfo(|t: f32| -> f32 {
+        if t < 0.05 {
+            0.5
+        } else {
+            0.0
+        }
+    });
+    let source = Net::wrap(Box::new((sine_hz(440.0) * burst) >> split::<U2>()));
+    let mut mixed = apply_reverb(source, 0.5, ROOM);
+    let (samples, channels) = render_to_buffer(&mut mixed, 0.4, SAMPLE_RATE);
+    assert_eq!(channels, 2);
+    assert!(samples.iter().all(|s| s.is_finite()));
+    let tail = &samples[(0.3 * SAMPLE_RATE as f32) as usize * 2..];
+    assert!(peak(tail) > 1e-4);
+}
+
+#[test]
+fn apply_live_reverb_follows_its_mix() {
+    // A fixed phase, since a plain sine's phase is seeded from its place in the net.
+    let tone = dc(440.0) >> An(Sine::<f32>::with_phase(0.0));
+    let dry = Net::wrap(Box::new(tone >> split::<U2>()));
+    let mut mix = ParamHandle::new("reverb_mix", 0.0, 0.0, 1.0);
+    let mut reference = dry.clone();
+    let mut mixed = apply_live_reverb(dry.clone(), &mut mix, ROOM);
+    let (expected, _) = render_to_buffer(&mut reference, 0.1, SAMPLE_RATE);
+    let (actual, _) = render_to_buffer(&mut mixed, 0.1, SAMPLE_RATE);
+    assert_eq!(expected, actual);
+
+    // Raised after the graph is built, the tail rings on past the dry sound.
+    let burst = lfo(|t: f32| -> f32 {
+        if t < 0.05 {
+            0.5
+        } else {
+            0.0
+        }
+    });
+    let source = Net::wrap(Box::new((sine_hz(440.0) * burst) >> split::<U2>()));
+    let mut mixed = apply_live_reverb(source, &mut mix, ROOM);
+    mix.set(0.5);
+    let (samples, _) = render_to_buffer(&mut mixed, 0.4, SAMPLE_RATE);
+    assert!(samples.iter().all(|s| s.is_finite()));
+    let tail = &samples[(0.3 * SAMPLE_RATE as f32) as usize * 2..];
+    assert!(peak(tail) > 1e-4);
+}
@@ -0,0 +1,370 @@
+//! Offline-render sanity checks for every preset graph at default params.
+//!
+//! Each graph must produce finite output that is neither silent nor
+//! clipping, and one-shots must have died away by the time their entity is
+//! despawned.
+
+use bevy_proc_aud::components::variation::{Jitter, Variation};
+use bevy_proc_aud::dsp::render::render_to_buffer;
+use bevy_proc_aud::presets::alarm::{build_alarm_graph, Alarm};
+use bevy_proc_aud::presets::arcane_attack::{build_arcane_attack_graph, ArcaneAttack};
+use bevy_proc_aud::presets::bell::{build_bell_graph, Bell};
+use bevy_proc_aud::presets::blunt_impact::{build_blunt_impact_graph, BluntImpact};
+use bevy_proc_aud::presets::drum::{build_drum_graph, Drum, DrumKind};
+use bevy_proc_aud::presets::ear_ringing::{build_ear_ringing_graph, EarRinging};
+use bevy_proc_aud::presets::engine::{build_engine_graph, Engine};
+use bevy_proc_aud::presets::explosion::{build_explosion_graph, Explosion};
+use bevy_proc_aud::presets::fire::{build_fire_graph, Fire};
+use bevy_proc_aud::presets::footstep::{build_footstep_graph, Footstep};
+use bevy_proc_aud::presets::glass_break::{build_glass_break_graph, GlassBreak};
+use bevy_proc_aud::presets::gunshot::{build_gunshot_graph, Gunshot};
+use bevy_proc_aud::presets::heartbeat::{build_heartbeat_graph, Heartbeat, HeartbeatState};
+use bevy_proc_aud::presets::laser::{build_laser_graph, Laser};
+use bevy_proc_aud::presets::lightning::{
+    build_lightning_strike_graph, build_lightning_zap_graph, LightningStrike, LightningZap,
+};
+use bevy_proc_aud::presets::metronome::{build_metronome_graph, Metronome};
+use bevy_proc_aud::presets::pickup::{build_pickup_graph, Pickup};
+use bevy_proc_aud::presets::pluck::{build_pluck_graph, Pluck};
+use bevy_proc_aud::presets::power_up::{build_power_up_graph, PowerUp};
+use bevy_proc_aud::presets::rain::{build_rain_graph, Rain};
+use bevy_proc_aud::presets::splash::{build_splash_graph, Splash};
+use bevy_proc_aud::presets::sword_slash::{build_sword_slash_graph, SwordSlash};
+use bevy_proc_aud::presets::teleport::{build_teleport_graph, Teleport};
+use bevy_proc_aud::presets::test_tone::{build_test_tone_graph, TestKind, TestTone, TEST_TONE_DB};
+use bevy_proc_aud::presets::ui_blip::{build_ui_blip_graph, UiBlip};
+use bevy_proc_aud::presets::whoosh::{build_whoosh_graph, Whoosh};
+use bevy_proc_aud::presets::wind::{build_wind_graph, Wind};
+use fundsp::audiounit::AudioUnit;
+
+const SAMPLE_RATE: u32 = 44100;
+
+/// Below this RMS a graph is considered silent (-80 dBFS).
+const SILENCE_RMS: f32 = 1e-4;
+/// Above this RMS a graph is considered clipping; a full-scale square wave
+/// sits at 1.0.
+const CLIPPING_RMS: f32 = 0.7;
+/// Length of the window checked at the end of a one-shot, in seconds.
+const TAIL_SECS: f32 = 0.05;
+/// How far below its loudest window a one-shot's tail must have fallen.
+const TAIL_RATIO: f32 = 0.1;
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_sq: f32 = samples.iter().map(|s| s * s).sum();
+    (sum_sq / samples.len() as f32).sqrt()
+}
+
+/// Check that `samples` are finite, audible, and not clipping.
+fn check_levels(name: &str, samples: &[f32]) {
+    assert!(
+        samples.iter().all(|s| s.is_finite()),
+        "{name}: output contains NaN or infinity"
+    );
+    let level = rms(samples);
+    assert!(level > SILENCE_RMS, "{name}: silent (RMS {level})");
+    assert!(level < CLIPPING_RMS, "{name}: clipping (RMS {level})");
+}
+
+/// Check a one-shot over the first second (or its whole length, if
+/// shorter), then check its last `TAIL_SECS` before `end_secs` is quiet.
+fn check_one_shot(name: &str, mut graph: Box<dyn AudioUnit>, end_secs: f32) {
+    let (samples, channels) = render_to_buffer(graph.as_mut(), end_secs, SAMPLE_RATE);
+    let channels = channels as usize;
+    let first_second = (SAMPLE_RATE as usize * channels).min(samples.len());
+    check_levels(name, &samples[..first_second]);
+
+    let window = (TAIL_SECS * SAMPLE_RATE as f32) as usize * channels;
+    let peak = samples.chunks(window).map(rms).fold(0.0, f32::max);
+    let tail = rms(&samples[samples.len().saturating_sub(window)..]);
+    assert!(
+        tail < peak * TAIL_RATIO,
+        "{name}: still sounding at {end_secs}s (tail RMS {tail}, peak {peak})"
+    );
+}
+
+/// Check the first second of a continuous preset.
+fn check_continuous(name: &str, mut graph: Box<dyn AudioUnit>) {
+    let (samples, _) = render_to_buffer(graph.as_mut(), 1.0, SAMPLE_RATE);
+    check_levels(name, &samples);
+}
+
+#[test]
+fn one_shots_are_sane() {
+    // End times match the `OneShotLifetime`s set by the build systems.
+    let gunshot = Gunshot::default();
+    let gunshot_end = 0.5 * gunshot.caliber.max(1.0) + gunshot.reverb_mix;
+    let teleport = Teleport::default();
+    let teleport_end = 0.6 + teleport.reverb_mix;
+    let pickup = Pickup::default();
+    let splash = Splash::default();
+    let pluck = Pluck::default();
+
+    let cases: Vec<(&str, Box<dyn AudioUnit>, f32)> = vec![
+        (
+            "ArcaneAttack",
+            build_arcane_attack_graph(&ArcaneAttack::default(), Jitter::NONE).0,
+            1.0,
+        ),
+        (
+            "Bell",
+            build_bell_graph(&Bell::default(), Jitter::NONE),
+            3.0,
+        ),
+        (
+            "BluntImpact",
+            build_blunt_impact_graph(&BluntImpact::default(), Jitter::NONE),
+            0.5,
+        ),
+        (
+            "Explosion",
+            build_explosion_graph(&Explosion::default(), Jitter::NONE).0,
+            3.0,
+        ),
+        (
+            "Footstep",
+            build_footstep_graph(&Footstep::default(), Jitter::NONE),
+            0.4,
+        ),
+        (
+            "GlassBreak",
+            build_glass_break_graph(&GlassBreak::default(), Jitter::NONE),
+            0.8,
+        ),
+        (
+            "Gunshot",
+            build_gunshot_graph(&gunshot, Jitter::NONE),
+            gunshot_end,
+        ),
+        (
+            "Laser",
+            build_laser_graph(&Laser::default(), Jitter::NONE),
+            0.25,
+        ),
+        (
+            "LightningStrike",
+            build_lightning_strike_graph(&LightningStrike::default(), Jitter::NONE),
+            3.0,
+        ),
+        (
+            "LightningZap",
+            build_lightning_zap_graph(&LightningZap::default(), Jitter::NONE),
+            0.7,
+        ),
+        (
+            "Pickup",
+            build_pickup_graph(&pickup, Jitter::NONE),
+            pickup.duration(),
+        ),
+        (
+            "Pluck",
+            build_pluck_graph(&pluck, Jitter::NONE),
+            pluck.duration(),
+        ),
+        (
+            "PowerUp",
+            build_power_up_graph(&PowerUp::default(), Jitter::NONE),
+            1.0,
+        ),
+        (
+            "Splash",
+            build_splash_graph(&splash, Jitter::NONE),
+            splash.duration(),
+        ),
+        (
+            "SwordSlash",
+            build_sword_slash_graph(&SwordSlash::default(), Jitter::NONE),
+            1.5,
+        ),
+        (
+            "Teleport",
+            build_teleport_graph(&teleport, Jitter::NONE),
+            teleport_end,
+        ),
+        (
+            "UiBlip",
+            build_ui_blip_graph(&UiBlip::default(), Jitter::NONE),
+            0.15,
+        ),
+        (
+            "Whoosh",
+            build_whoosh_graph(&Whoosh::default(), Jitter::NONE),
+            0.35,
+        ),
+    ];
+    for (name, graph, end_secs) in cases {
+        check_one_shot(name, graph, end_secs);
+    }
+
+    for kind in [DrumKind::Kick, DrumKind::Snare, DrumKind::HiHat] {
+        let drum = Drum {
+            kind,
+            ..Default::default()
+        };
+        check_one_shot(
+            &format!("Drum::{kind:?}"),
+            build_drum_graph(&drum, Jitter::NONE),
+            drum.duration(),
+        );
+    }
+}
+
+#[test]
+fn varied_one_shots_are_sane() {
+    // The widest variation still has to die away within the stretched lifetime.
+    let variation = Variation(0.5);
+    for seed in 0..8 {
+        let jitter = variation.jitter(seed);
+        let impact = BluntImpact {
+            seed,
+            ..Default::default()
+        };
+        check_one_shot(
+            &format!("BluntImpact (variation seed {seed})"),
+            build_blunt_impact_graph(&impact, jitter),
+            0.5 * jitter.max_scale(),
+        );
+        let drum = Drum {
+            seed,
+            ..Default::default()
+        };
+        check_one_shot(
+            &format!("Drum (variation seed {seed})"),
+            build_drum_graph(&drum, jitter),
+            drum.duration() * jitter.max_scale(),
+        );
+    }
+}
+
+#[test]
+fn continuous_presets_are_sane() {
+    check_continuous("Alarm", build_alarm_graph(&Alarm::default()).0);
+    check_continuous(
+        "EarRinging",
+        build_ear_ringing_graph(&EarRinging::default()).0,
+    );
+    check_continuous("Engine", build_engine_graph(&Engine::default()).0);
+    check_continuous("Fire", build_fire_graph(&Fire::default()).0);
+    check_continuous("Heartbeat", build_heartbeat_graph(&Heartbeat::default()).0);
+    check_continuous("Metronome", build_metronome_graph(&Metronome::default()).0);
+    check_continuous("Rain", build_rain_graph(&Rain::default()).0);
+    check_continuous("Wind", build_wind_graph(&Wind::default()).0);
+}
+
+#[test]
+fn test_tones_are_calibrated() {
+    // A -18 dBFS sine, and noise at the same RMS.
+    let expected = 10.0_f32.powf(TEST_TONE_DB / 20.0) * std::f32::consts::FRAC_1_SQRT_2;
+    for kind in [
+        TestKind::Sine,
+        TestKind::WhiteNoise,
+        TestKind::PinkNoise,
+        TestKind::Sweep,
+    ] {
+        let mut graph = build_test_tone_graph(&TestTone {
+            kind,
+            ..Default::default()
+        });
+        let (samples, _) = render_to_buffer(graph.as_mut(), 2.0, SAMPLE_RATE);
+        let level = rms(&samples);
+        assert!(
+            (level / expected - 1.0).abs() < 0.15,
+            "{kind:?}: RMS {level}, expected {expected}"
+        );
+    }
+}
+
+#[test]
+fn heartbeat_states_are_sane() {
+    for state in HeartbeatState::ALL {
+        let heartbeat = Heartbeat {
+            state,
+            ..Default::default()
+        };
+        check_continuous(
+            &format!("Heartbeat ({state:?})"),
+            build_heartbeat_graph(&heartbeat).0,
+        );
+    }
+
+    // A flatline is a steady tone: every 50ms window after the fade-in is
+    // about as loud as the next.
+    let flatline = Heartbeat {
+        state: HeartbeatState::Flatline,
+        ..Default::default()
+    };
+    let (samples, channels) = render_to_buffer(
+        build_heartbeat_graph(&flatline).0.as_mut(),
+        1.0,
+        SAMPLE_RATE,
+    );
+    let window = (TAIL_SECS * SAMPLE_RATE as f32) as usize * channels as usize;
+    let levels: Vec<f32> = samples.chunks_exact(window).skip(4).map(rms).collect();
+    let (quietest, loudest) = levels
+        .iter()
+        .fold((f32::MAX, 0.0_f32), |(lo, hi), &l| (lo.min(l), hi.max(l)));
+    assert!(
+        loudest < quietest * 1.1,
+        "flatline RMS ranges {quietest}–{loudest}"
+    );
+}
+
+#[test]
+fn explosion_lowpass_applies_live() {
+    let explosion = Explosion {
+        seed: 7,
+        reverb_mix: 0.0,
+        ..Default::default()
+    };
+    // RMS of the left channel's sample-to-sample differences: high
+    // frequencies dominate it.
+    let brightness = |mut graph: Box<dyn AudioUnit>| {
+        let (samples, _) = render_to_buffer(graph.as_mut(), 0.5, SAMPLE_RATE);
+        let left: Vec<f32> = samples.iter().step_by(2).copied().collect();
+        let steps: Vec<f32> = left.windows(2).map(|w| w[1] - w[0]).collect();
+        rms(&steps)
+    };
+    let (open, _) = build_explosion_graph(&explosion, Jitter::NONE);
+    let (muffled, params) = build_explosion_graph(&explosion, Jitter::NONE);
+    params.lowpass.set(300.0);
+    assert!(brightness(muffled) < brightness(open) * 0.5);
+}
+
+#[test]
+fn soft_impacts_are_quieter_and_duller() {
+    let render = |graph: Box<dyn AudioUnit>| {
+        let mut graph = graph;
+        let (samples, _) = render_to_buffer(graph.as_mut(), 0.5, SAMPLE_RATE);
+        let left: Vec<f32> = samples.iter().step_by(2).copied().collect();
+        let steps: Vec<f32> = left.windows(2).map(|w| w[1] - w[0]).collect();
+        // Level, and high-frequency content relative to it.
+        (rms(&left), rms(&steps) / rms(&left))
+    };
+    for velocity in [0.3, 0.6] {
+        let hit = |velocity| BluntImpact {
+            velocity,
+            seed: 3,
+            ..Default::default()
+        };
+        let (hard, hard_bright) = render(build_blunt_impact_graph(&hit(1.0), Jitter::NONE));
+        let (soft, soft_bright) = render(build_blunt_impact_graph(&hit(velocity), Jitter::NONE));
+        assert!(
+            soft < hard * 0.7,
+            "BluntImpact at {velocity}: {soft} vs {hard}"
+        );
+        assert!(soft_bright < hard_bright, "BluntImpact at {velocity}");
+
+        let shot = |velocity| Gunshot {
+            velocity,
+            seed: 3,
+            reverb_mix: 0.0,
+            ..Default::default()
+        };
+        let (hard, hard_bright) = render(build_gunshot_graph(&shot(1.0), Jitter::NONE));
+        let (soft, soft_bright) = render(build_gunshot_graph(&shot(velocity), Jitter::NONE));
+        assert!(soft < hard * 0.7, "Gunshot at {velocity}: {soft} vs {hard}");
+        assert!(soft_bright < hard_bright, "Gunshot at {velocity}");
+    }
+}
@@ -0,0 +1,16 @@
+//! Checks for the step sequencer's timing.
+
+use bevy_proc_aud::components::effect::NoteDivision;
+use bevy_proc_aud::components::sequencer::Sequencer;
+
+#[test]
+fn steps_follow_tempo_and_division() {
+    let quarter = Sequencer::default();
+    assert!((quarter.step_secs() - 0.5).abs() < 1e-6);
+    let sixteenth = Sequencer {
+        bpm: 150.0,
+        division: NoteDivision::Sixteenth,
+        ..Default::default()
+    };
+    assert!((sixteenth.step_secs() - 0.1).abs() < 1e-6);
+}
@@ -0,0 +1,29 @@
+//! Checks for the side-chain ducking stage.
+
+use bevy_proc_aud::dsp::render::render_to_buffer;
+use bevy_proc_aud::dsp::side_chain::build_side_chain_stage;
+use fundsp::prelude32::*;
+
+const SAMPLE_RATE: u32 = 44100;
+
+/// Settled output level of a constant 0.5 signal through a side-chain stage
+/// fed a source at `source_rms`, ducking by up to `amount`.
+fn ducked_level(source_rms: f32, amount: f32) -> f32 {
+    let (mut graph, params) = build_side_chain_stage(Box::new(dc((0.5, 0.5))));
+    params.set(source_rms, amount);
+    let (samples, _) = render_to_buffer(&mut *graph, 1.0, SAMPLE_RATE);
+    samples[samples.len() - 1] / 0.5
+}
+
+#[test]
+fn silent_source_leaves_the_target_alone() {
+    assert!((ducked_level(0.0, 1.0) - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn loud_source_ducks_by_amount() {
+    assert!((ducked_level(0.5, 0.5) - 0.5).abs() < 1e-2);
+    assert!(ducked_level(0.5, 1.0) < 1e-2);
+    // Half the full-duck level dips half as deep.
+    assert!((ducked_level(0.125, 0.8) - 0.6).abs() < 1e-2);
+}
@@ -0,0 +1,21 @@
+//! Checks for exporting a live `Synth` entity back to a `SynthConfig`.
+
+use bevy::prelude::*;
+use bevy_proc_aud::prelude::*;
+
+#[test]
+fn from_entity_round_trips_a_bundle() {
+    let mut world = World::new();
+    let bundle = SynthBundle::new(OscillatorType::Saw, 330.0)
+        .amplitude(0.4)
+        .low_pass(1200.0, 0.9);
+    let entity = bundle.spawn(&mut world.commands()).id();
+    world.flush();
+
+    let config = SynthConfig::from_entity(world.entity(entity));
+    assert_eq!(config.oscillator, OscillatorType::Saw);
+    assert_eq!(config.frequency.0, 330.0);
+    assert_eq!(config.amplitude.0, 0.4);
+    assert!(config.low_pass.is_some());
+    assert!(config.reverb.is_none());
+}
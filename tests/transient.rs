@@ -0,0 +1,75 @@
+//! Checks for the decoder's transient detector.
+
+use bevy::audio::{AudioPlayer, Decodable};
+use bevy::ecs::message::Messages;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::{Assets, World};
+use bevy_proc_aud::components::level::{TransientDetect, TransientDetected};
+use bevy_proc_aud::dsp::source::ProceduralAudio;
+use bevy_proc_aud::systems::sync::transient_system;
+use fundsp::prelude32::*;
+
+/// A stereo tone that is silent until `onset`, decoded for `seconds` with
+/// detection at `sensitivity`.
+fn decoded(onset: f32, seconds: f32, sensitivity: f32) -> ProceduralAudio {
+    let gate = lfo(move |t: f32| -> f32 {
+        if t < onset {
+            0.0
+        } else {
+            0.5
+        }
+    });
+    let graph = Box::new((sine_hz(220.0) * gate) >> split::<U2>());
+    let audio = ProceduralAudio::new(graph, 44100, 2);
+    audio.transients().set_sensitivity(sensitivity);
+    audio
+        .decoder()
+        .take((seconds * 44100.0) as usize * 2)
+        .for_each(drop);
+    audio
+}
+
+/// Onsets counted by [`decoded`].
+fn onsets(onset: f32, seconds: f32, sensitivity: f32) -> u32 {
+    decoded(onset, seconds, sensitivity).transients().count()
+}
+
+#[test]
+fn onset_is_counted_once() {
+    assert_eq!(onsets(0.2, 0.6, 0.5), 1);
+}
+
+#[test]
+fn steady_tone_counts_only_its_start() {
+    // Sounding from the start: the first block jumps from silence, then the
+    // level holds.
+    assert_eq!(onsets(0.0, 0.6, 0.5), 1);
+    assert_eq!(onsets(0.2, 0.6, 0.0), 0);
+}
+
+#[test]
+fn rebuilt_source_restarts_the_count() {
+    let mut world = World::new();
+    world.init_resource::<Messages<TransientDetected>>();
+    let mut assets = Assets::<ProceduralAudio>::default();
+    let first = assets.add(decoded(0.2, 0.6, 0.5));
+    // Stopped short of its onset, so its count is below the first's.
+    let second = assets.add(decoded(0.2, 0.1, 0.5));
+    world.insert_resource(assets);
+    let entity = world
+        .spawn((AudioPlayer(first), TransientDetect::default()))
+        .id();
+    let run = |world: &mut World| {
+        world.run_system_once(transient_system).unwrap();
+        world.resource_mut::<Messages<TransientDetected>>().drain().count()
+    };
+    assert_eq!(run(&mut world), 1);
+    assert_eq!(run(&mut world), 0);
+
+    world.entity_mut(entity).insert(AudioPlayer(second.clone()));
+    assert_eq!(run(&mut world), 0);
+    // The rebuilt source's onsets are counted from its own zero.
+    let assets = world.resource::<Assets<ProceduralAudio>>();
+    assets.get(&second).unwrap().decoder().take(44100).for_each(drop);
+    assert_eq!(run(&mut world), 1);
+}
@@ -0,0 +1,70 @@
+//! Checks for the graph validation run on built graphs.
+
+use bevy_proc_aud::dsp::validate::{check_graph, check_net, GraphError};
+use bevy_proc_aud::presets::heartbeat::{build_heartbeat_graph, Heartbeat};
+use fundsp::prelude32::*;
+
+#[test]
+fn wired_stereo_generator_passes() {
+    let mut net = Net::new(0, 2);
+    let tone = net.push(Box::new(sine_hz(220.0)));
+    let split = net.push(Box::new(split::<U2>()));
+    net.connect(tone, 0, split, 0);
+    net.connect_output(split, 0, 0);
+    net.connect_output(split, 1, 1);
+    assert_eq!(check_net(&net), Ok(()));
+    assert_eq!(
+        check_graph(build_heartbeat_graph(&Heartbeat::default()).0.as_ref()),
+        Ok(())
+    );
+}
+
+#[test]
+fn wrong_arity_is_reported() {
+    let mono = Net::wrap(Box::new(sine_hz(220.0)));
+    assert_eq!(check_net(&mono), Err(GraphError::Outputs { found: 1 }));
+    let filter = Net::wrap(Box::new(lowpass_hz(1000.0, 1.0) >> split::<U2>()));
+    assert_eq!(check_net(&filter), Err(GraphError::Inputs { found: 1 }));
+}
+
+#[test]
+fn missing_connections_name_the_node() {
+    // The filter's input is never connected.
+    let mut net = Net::new(0, 2);
+    let filter = net.push(Box::new(lowpass_hz(1000.0, 1.0) >> split::<U2>()));
+    net.connect_output(filter, 0, 0);
+    net.connect_output(filter, 1, 1);
+    let error = check_net(&net).unwrap_err();
+    assert_eq!(
+        error,
+        GraphError::UnconnectedInput {
+            node: 0,
+            arity: (1, 2),
+            channel: 0
+        }
+    );
+    assert!(error.to_string().contains("node #0"));
+
+    // Only the left output is connected.
+    let mut net = Net::new(0, 2);
+    let tone = net.push(Box::new(sine_hz(220.0)));
+    net.connect_output(tone, 0, 0);
+    assert_eq!(
+        check_net(&net),
+        Err(GraphError::UnconnectedOutput { channel: 1 })
+    );
+
+    // A second tone pushed but never routed to the outputs.
+    let mut net = Net::new(0, 2);
+    let tone = net.push(Box::new(sine_hz(220.0) >> split::<U2>()));
+    net.push(Box::new(sine_hz(330.0)));
+    net.connect_output(tone, 0, 0);
+    net.connect_output(tone, 1, 1);
+    assert_eq!(
+        check_net(&net),
+        Err(GraphError::Unreachable {
+            node: 1,
+            arity: (0, 1)
+        })
+    );
+}